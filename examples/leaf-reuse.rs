@@ -0,0 +1,122 @@
+use clap::{Arg, Command};
+use hbs_lms::*;
+use std::{
+    error::Error,
+    fs::{read, read_dir, read_to_string},
+    path::{Path, PathBuf},
+    process::exit,
+};
+
+const KEYNAME_PARAMETER: &str = "keyname";
+const SIGNATURES_PARAMETER: &str = "signatures";
+const LOG_PARAMETER: &str = "log";
+
+type Hasher = Sha256_256;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let matches = Command::new("LMS Leaf Reuse Scanner")
+        .about("Scans signatures of a key for reused one-time keys")
+        .arg(Arg::new(KEYNAME_PARAMETER).required(true))
+        .arg(
+            Arg::new(SIGNATURES_PARAMETER)
+                .required(true)
+                .multiple_values(true)
+                .help("Signature files or directories, which are searched for `.sig` files"),
+        )
+        .arg(
+            Arg::new(LOG_PARAMETER)
+                .long(LOG_PARAMETER)
+                .takes_value(true)
+                .value_name("file")
+                .help("Log of issued signatures, one hex encoded signature per line"),
+        )
+        .get_matches();
+
+    let keyname = matches.value_of(KEYNAME_PARAMETER).unwrap();
+    let verifying_key = VerifyingKey::<Hasher>::from_bytes(&read(format!("{}.pub", keyname))?)
+        .map_err(|_| "Could not parse public key")?;
+
+    let mut detector = LeafReuseDetector::<Hasher, String>::new(&verifying_key)
+        .map_err(|_| "Could not parse public key")?;
+    let mut signatures = 0;
+
+    let mut files = Vec::new();
+    for path in matches.values_of(SIGNATURES_PARAMETER).unwrap() {
+        collect_signature_files(Path::new(path), &mut files)?;
+    }
+
+    for file in files {
+        let tag = file.display().to_string();
+        ingest(&mut detector, &read(&file)?, tag);
+        signatures += 1;
+    }
+
+    if let Some(log) = matches.value_of(LOG_PARAMETER) {
+        for (line_number, line) in read_to_string(log)?.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let tag = format!("{}:{}", log, line_number + 1);
+            match hex::decode(line.trim()) {
+                Ok(signature) => ingest(&mut detector, &signature, tag),
+                Err(_) => eprintln!("Skipping {}: not hex encoded", tag),
+            }
+            signatures += 1;
+        }
+    }
+
+    for finding in detector.findings() {
+        println!(
+            "Reused one-time key on level {} (I = {}, q = {}): {:?} in {} and {}",
+            finding.level,
+            hex::encode(finding.lms_tree_identifier),
+            finding.lms_leaf_identifier,
+            finding.kind,
+            finding.first,
+            finding.second,
+        );
+    }
+
+    println!(
+        "Scanned {} signatures, found {} reused one-time keys",
+        signatures,
+        detector.findings().len()
+    );
+
+    if !detector.findings().is_empty() {
+        exit(1);
+    }
+
+    Ok(())
+}
+
+fn ingest(detector: &mut LeafReuseDetector<Hasher, String>, signature: &[u8], tag: String) {
+    if detector.ingest(signature, tag.clone()).is_err() {
+        eprintln!("Skipping {}: not a signature of the key", tag);
+    }
+}
+
+fn collect_signature_files(path: &Path, files: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    if !path.is_dir() {
+        files.push(path.to_path_buf());
+        return Ok(());
+    }
+
+    let mut entries = read_dir(path)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    entries.sort();
+
+    for entry in entries {
+        if entry.is_dir()
+            || entry
+                .extension()
+                .map_or(false, |extension| extension == "sig")
+        {
+            collect_signature_files(&entry, files)?;
+        }
+    }
+
+    Ok(())
+}
@@ -63,7 +63,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             Command::new(GENKEY_COMMAND)
                 .arg(Arg::new(KEYNAME_PARAMETER).required(true))
                 .arg(Arg::new(PARAMETER_PARAMETER).required(false).help(
-                    "Specify LMS parameters (e.g. 15/4 (Treeheight 15 and Winternitz parameter 4))",
+                    "Specify LMS parameters (e.g. 15/4 (Treeheight 15 and Winternitz parameter 4) or LMS_SHA256_M32_H15/LMOTS_SHA256_N32_W4)",
                 ).default_value("5/1"))
                 .arg(Arg::new(SEED_PARAMETER).long(SEED_PARAMETER).required(true).takes_value(true).value_name("seed")),
         )
@@ -344,6 +344,12 @@ fn parse_genkey_parameter(parameter: &str) -> GenKeyParameter {
         parameter
     };
 
+    if parameter.starts_with("LMS_") {
+        let parameters =
+            HssParameter::parse_list(parameter).expect("LMS parameters not correct specified");
+        return GenKeyParameter::new(parameters.to_vec(), aux_data_size);
+    }
+
     let parameters = parameter.split(',');
 
     for parameter in parameters {
@@ -4,6 +4,7 @@ use tinyvec::ArrayVec;
 include!(concat!(env!("OUT_DIR"), "/constants.rs"));
 
 pub const ILEN: usize = 16;
+#[cfg(feature = "sign")]
 pub const MAX_SEED_LEN: usize = 32;
 
 pub type LmsTreeIdentifier = [u8; ILEN];
@@ -19,19 +20,31 @@ pub const D_MESG: [u8; 2] = [0x81, 0x81];
 pub const D_LEAF: [u8; 2] = [0x82, 0x82];
 pub const D_INTR: [u8; 2] = [0x83, 0x83];
 
+#[cfg(feature = "sign")]
 pub const TOPSEED_SEED: usize = 23;
+#[cfg(feature = "sign")]
 pub const TOPSEED_LEN: usize = TOPSEED_SEED + 32;
+#[cfg(feature = "sign")]
 pub const TOPSEED_D: usize = 20;
+#[cfg(feature = "sign")]
 pub const TOPSEED_WHICH: usize = 22;
+#[cfg(feature = "sign")]
 pub const D_TOPSEED: u16 = 0xfefe;
 
+#[cfg(feature = "sign")]
 pub const PRNG_I: usize = 0;
+#[cfg(feature = "sign")]
 pub const PRNG_Q: usize = 16;
+#[cfg(feature = "sign")]
 pub const PRNG_J: usize = 20;
+#[cfg(feature = "sign")]
 pub const PRNG_FF: usize = 22;
+#[cfg(feature = "sign")]
 pub const PRNG_SEED: usize = 23;
 
+#[cfg(feature = "sign")]
 pub const SEED_CHILD_SEED: u16 = !1;
+#[cfg(feature = "sign")]
 pub const SEED_SIGNATURE_RANDOMIZER_SEED: u16 = !2;
 
 pub const fn prng_len(seed_len: usize) -> usize {
@@ -40,26 +53,37 @@ pub const fn prng_len(seed_len: usize) -> usize {
 
 pub const LMS_LEAF_IDENTIFIERS_SIZE: usize = 8;
 pub const REF_IMPL_MAX_ALLOWED_HSS_LEVELS: usize = 8;
+#[cfg(feature = "sign")]
 pub const REF_IMPL_MAX_PRIVATE_KEY_SIZE: usize =
     LMS_LEAF_IDENTIFIERS_SIZE + REF_IMPL_MAX_ALLOWED_HSS_LEVELS + MAX_SEED_LEN;
+// Start and end of the signature range of a partitioned private key
+#[cfg(feature = "sign")]
+pub const SIGNATURE_RANGE_SIZE: usize = 2 * LMS_LEAF_IDENTIFIERS_SIZE;
+#[cfg(feature = "sign")]
+pub const MAX_PRIVATE_KEY_SIZE: usize = REF_IMPL_MAX_PRIVATE_KEY_SIZE + SIGNATURE_RANGE_SIZE;
 
 pub const MAX_HASH_SIZE: usize = 32;
+#[cfg(feature = "sign")]
 pub const MAX_HASH_BLOCK_SIZE: usize = 64;
 
+#[cfg(feature = "sign")]
 pub const PRNG_MAX_LEN: usize = prng_len(MAX_HASH_SIZE);
 
 pub const MAX_HASH_CHAIN_COUNT: usize =
     get_hash_chain_count(MIN_WINTERNITZ_PARAMETER, MAX_HASH_SIZE);
 
+#[cfg(feature = "sign")]
 pub const MAX_LMOTS_SIGNATURE_LENGTH: usize =
     lmots_signature_length(MAX_HASH_SIZE, MAX_HASH_CHAIN_COUNT);
 
 pub const MAX_LMS_PUBLIC_KEY_LENGTH: usize = lms_public_key_length(MAX_HASH_SIZE);
+#[cfg(feature = "sign")]
 pub const MAX_LMS_SIGNATURE_LENGTH: usize =
     lms_signature_length(MAX_HASH_SIZE, MAX_HASH_CHAIN_COUNT, MAX_TREE_HEIGHT);
 
 pub const MAX_HSS_PUBLIC_KEY_LENGTH: usize = size_of::<u32>()       // HSS Level
         + lms_public_key_length(MAX_HASH_SIZE); // Root LMS PublicKey
+#[cfg(feature = "sign")]
 pub const MAX_HSS_SIGNED_PUBLIC_KEY_LENGTH: usize =
     hss_signed_public_key_length(MAX_HASH_SIZE, MAX_HASH_CHAIN_COUNT, MAX_TREE_HEIGHT);
 pub const MAX_HSS_SIGNATURE_LENGTH: usize = get_hss_signature_length();
@@ -67,8 +91,18 @@ pub const MAX_HSS_SIGNATURE_LENGTH: usize = get_hss_signature_length();
 /// Calculated using the formula from RFC 8554 Appendix B
 /// https://datatracker.ietf.org/doc/html/rfc8554#appendix-B
 const HASH_CHAIN_COUNTS: [usize; 12] = [136, 200, 265, 68, 101, 133, 35, 51, 67, 18, 26, 34];
+/// Calculated using the same formula, matching SP 800-208 for the output sizes 24 and 32
+const CHECKSUM_LEFT_SHIFTS: [u8; 12] = [8, 8, 7, 8, 6, 6, 4, 4, 4, 0, 0, 0];
 
 pub const fn get_hash_chain_count(winternitz_parameter: usize, output_size: usize) -> usize {
+    HASH_CHAIN_COUNTS[lmots_parameter_index(winternitz_parameter, output_size)]
+}
+
+pub const fn get_checksum_left_shift(winternitz_parameter: usize, output_size: usize) -> u8 {
+    CHECKSUM_LEFT_SHIFTS[lmots_parameter_index(winternitz_parameter, output_size)]
+}
+
+const fn lmots_parameter_index(winternitz_parameter: usize, output_size: usize) -> usize {
     let w_i = match winternitz_parameter {
         1 => 0usize,
         2 => 1usize,
@@ -84,7 +118,7 @@ pub const fn get_hash_chain_count(winternitz_parameter: usize, output_size: usiz
         _ => panic!("Invalid Output Size. Allowed is: 16, 24 or 32"),
     };
 
-    HASH_CHAIN_COUNTS[w_i * 3 + o_i]
+    w_i * 3 + o_i
 }
 
 pub const fn lmots_signature_length(hash_size: usize, hash_chain_count: usize) -> usize {
@@ -141,10 +175,21 @@ pub const fn get_hss_signature_length() -> usize {
         )
 }
 
+/// Upper bound of the stack usage of [`crate::verify_with_scratch`] in bytes, not counting the
+/// stack used internally by the [`crate::HashChain`] implementation (release build).
+///
+/// The stack depth is sampled at every call into the hasher. On x86_64 it was measured between
+/// 1.6 and 2.7 KiB, depending on the hash family and the enabled features. The bound leaves room
+/// for other compiler versions and targets and is checked by a release build test.
+pub const VERIFY_MAX_STACK_USAGE: usize = 4096;
+
 pub const MIN_SUBTREE: usize = 2; /* All subtrees (other than the root subtree) have at least 2 levels */
 
+#[cfg(feature = "sign")]
 pub const DAUX_D: usize = 20;
+#[cfg(feature = "sign")]
 pub const DAUX_PREFIX_LEN: usize = 22; /* Not counting the seed value */
+#[cfg(feature = "sign")]
 pub const D_DAUX: u16 = 0xfdfd;
 
 pub mod winternitz_chain {
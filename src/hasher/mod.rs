@@ -7,10 +7,15 @@ use digest::{FixedOutput, Update};
 use tinyvec::ArrayVec;
 
 use crate::constants::{winternitz_chain::*, MAX_HASH_SIZE};
+#[cfg(any(all(test, feature = "sha256"), feature = "std"))]
+use crate::hss::cost::HashConstruction;
 
+#[cfg(feature = "sha256")]
 pub mod sha256;
+#[cfg(feature = "shake256")]
 pub mod shake256;
 
+#[derive(Default)]
 pub struct HashChainData {
     data: ArrayVec<[u8; ITER_MAX_LEN]>,
 }
@@ -29,6 +34,57 @@ impl DerefMut for HashChainData {
     }
 }
 
+/**
+ * Hash functions of RFC 8554 and SP 800-208, matching the [`HashChain`] implementations of this
+ * crate.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HashFamily {
+    Sha256_256,
+    Sha256_192,
+    Shake256_256,
+    Shake256_192,
+}
+
+impl HashFamily {
+    pub const ALL: [HashFamily; 4] = [
+        HashFamily::Sha256_256,
+        HashFamily::Sha256_192,
+        HashFamily::Shake256_256,
+        HashFamily::Shake256_192,
+    ];
+
+    /// The family of `H`, or `None` for hash functions not covered by SP 800-208, e.g. with an
+    /// output size of 16 bytes.
+    pub fn of<H: HashChain>() -> Option<Self> {
+        HashFamily::ALL.iter().copied().find(|hash_family| {
+            hash_family.output_size() == H::OUTPUT_SIZE && hash_family.iana_name() == H::IANA_NAME
+        })
+    }
+
+    pub const fn output_size(&self) -> u16 {
+        match self {
+            HashFamily::Sha256_256 | HashFamily::Shake256_256 => 32,
+            HashFamily::Sha256_192 | HashFamily::Shake256_192 => 24,
+        }
+    }
+
+    pub const fn iana_name(&self) -> &'static str {
+        match self {
+            HashFamily::Sha256_256 | HashFamily::Sha256_192 => "SHA256",
+            HashFamily::Shake256_256 | HashFamily::Shake256_192 => "SHAKE",
+        }
+    }
+
+    #[cfg(any(all(test, feature = "sha256"), feature = "std"))]
+    pub(crate) const fn construction(&self) -> HashConstruction {
+        match self {
+            HashFamily::Sha256_256 | HashFamily::Sha256_192 => HashConstruction::SHA256,
+            HashFamily::Shake256_256 | HashFamily::Shake256_192 => HashConstruction::SHAKE256,
+        }
+    }
+}
+
 /**
  *
  * This trait is used inside the library to generate hashes. Default implementations are available with [`sha256::Sha256`] and [`shake256::Shake256`].
@@ -44,6 +100,9 @@ pub trait HashChain:
 {
     const OUTPUT_SIZE: u16;
     const BLOCK_SIZE: u16;
+    /// Name of the hash function in the IANA algorithm names, e.g. `SHA256` or `SHAKE`. Selects the
+    /// hash family, e.g. for the cost model, so it must name the implemented hash function.
+    const IANA_NAME: &'static str;
 
     fn finalize(self) -> ArrayVec<[u8; MAX_HASH_SIZE]>;
     fn finalize_reset(&mut self) -> ArrayVec<[u8; MAX_HASH_SIZE]>;
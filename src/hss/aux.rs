@@ -16,7 +16,6 @@ use crate::{
 The implementation of aux data is mainly copied from the reference implementation in C (https://github.com/cisco/hash-sigs)
 For comments see the original source code.
 */
-
 type AuxLevel = u32;
 
 const AUX_DATA_MARKER: usize = 0;
@@ -205,7 +204,7 @@ pub fn hss_extract_aux_data<H: HashChain>(
     let start_index = lms_leaf_identifier as usize * hash_size;
     let end_index = start_index + hash_size;
 
-    if src[start_index..end_index] == [0u8; MAX_HASH_SIZE] {
+    if src[start_index..end_index].iter().all(|&byte| byte == 0) {
         return None;
     }
 
@@ -260,13 +259,13 @@ fn compute_hmac<H: HashChain>(key: &[u8], data: &[u8]) -> ArrayVec<[u8; MAX_HASH
     compute_hmac_opad::<H>(&mut hasher, key)
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "sha256"))]
 mod tests {
-    use crate::hasher::sha256::Sha256_256;
+    use crate::hasher::sha256::{Sha256_192, Sha256_256};
     use crate::util::helper::test_helper::gen_random_seed;
     use crate::{
         constants::MAX_HASH_SIZE,
-        hss::{aux::hss_expand_aux_data, hss_keygen},
+        hss::{aux::hss_expand_aux_data, hss_keygen, hss_verify},
         HssParameter, LmotsAlgorithm, LmsAlgorithm,
     };
 
@@ -291,4 +290,27 @@ mod tests {
         hss_expand_aux_data::<H>(Some(aux_slice), Some(seed.as_slice()))
             .expect("expand_aux_data should return None!");
     }
+
+    #[test]
+    fn sign_with_aux_data_of_shorter_hash() {
+        type H = Sha256_192;
+        let seed = gen_random_seed::<H>();
+
+        let lmots = LmotsAlgorithm::LmotsW2;
+        let lms = LmsAlgorithm::LmsH5;
+        let parameters = [HssParameter::new(lmots, lms), HssParameter::new(lmots, lms)];
+
+        let mut aux_data = [0u8; 1_000];
+        let aux_slice: &mut &mut [u8] = &mut &mut aux_data[..];
+
+        let (mut signing_key, verifying_key) =
+            hss_keygen::<H>(&parameters, &seed, Some(&mut *aux_slice))
+                .expect("Should generate HSS keys");
+
+        // Unset nodes of the aux data must not be taken for nodes of the tree.
+        let signature = signing_key
+            .try_sign_with_aux(b"message", Some(aux_slice))
+            .unwrap();
+        assert!(hss_verify::<H>(b"message", signature.as_ref(), verifying_key.as_slice()).is_ok());
+    }
 }
@@ -0,0 +1,377 @@
+use core::convert::TryFrom;
+
+use spki::{
+    der::{asn1::BitString, referenced::OwnedToRef, Encode},
+    AlgorithmIdentifierOwned,
+};
+use x509_cert::{request::CertReq, Certificate};
+#[cfg(feature = "sign")]
+use {
+    spki::SubjectPublicKeyInfoOwned,
+    x509_cert::{
+        attr::Attributes,
+        certificate::{TbsCertificate, Version},
+        ext::Extensions,
+        name::Name,
+        request::{self, CertReqInfo},
+        serial_number::SerialNumber,
+        time::Validity,
+    },
+};
+
+use crate::{
+    hasher::HashChain,
+    hss::{hss_verify, subject_public_key_info::ID_ALG_HSS_LMS_HASHSIG, VerifyingKey},
+};
+#[cfg(feature = "sign")]
+use crate::{signature::SignerMut, Signature};
+
+fn algorithm_identifier() -> AlgorithmIdentifierOwned {
+    AlgorithmIdentifierOwned {
+        oid: ID_ALG_HSS_LMS_HASHSIG,
+        parameters: None,
+    }
+}
+
+/**
+ * Signs `tbs_certificate`, whose signature field is set to id-alg-hss-lms-hashsig.
+ *
+ * The signer must update its private key state before it releases a signature, like
+ * [`crate::SigningKey`] and [`crate::FlashKeyStore`] do.
+ */
+#[cfg(feature = "sign")]
+pub fn sign_certificate<S: SignerMut<Signature>>(
+    signer: &mut S,
+    mut tbs_certificate: TbsCertificate,
+) -> Result<Certificate, ()> {
+    tbs_certificate.signature = algorithm_identifier();
+
+    let signature = signer
+        .try_sign(&tbs_certificate.to_der().map_err(|_| ())?)
+        .map_err(|_| ())?;
+
+    Ok(Certificate {
+        tbs_certificate,
+        signature_algorithm: algorithm_identifier(),
+        signature: BitString::from_bytes(signature.as_ref()).map_err(|_| ())?,
+    })
+}
+
+/**
+ * Issues a version 3 certificate for `verifying_key`, whose issuer is the subject itself.
+ *
+ * Returns an error, if the signature doesn't verify with `verifying_key`, i.e. the signer holds the
+ * private key of another public key. The leaf of the signature is used up nevertheless.
+ */
+#[cfg(feature = "sign")]
+pub fn sign_self_signed_certificate<H: HashChain, S: SignerMut<Signature>>(
+    signer: &mut S,
+    verifying_key: &VerifyingKey<H>,
+    serial_number: SerialNumber,
+    validity: Validity,
+    subject: Name,
+    extensions: Option<Extensions>,
+) -> Result<Certificate, ()> {
+    let tbs_certificate = TbsCertificate {
+        version: Version::V3,
+        serial_number,
+        signature: algorithm_identifier(),
+        issuer: subject.clone(),
+        validity,
+        subject,
+        subject_public_key_info: SubjectPublicKeyInfoOwned::from_key(verifying_key.clone())
+            .map_err(|_| ())?,
+        issuer_unique_id: None,
+        subject_unique_id: None,
+        extensions,
+    };
+
+    let certificate = sign_certificate(signer, tbs_certificate)?;
+    verify_certificate(verifying_key, &certificate)?;
+
+    Ok(certificate)
+}
+
+/**
+ * Creates a PKCS#10 certification request (RFC 2986) for `verifying_key`.
+ *
+ * Returns an error, if the signature doesn't verify with `verifying_key`, i.e. the signer holds the
+ * private key of another public key. The leaf of the signature is used up nevertheless.
+ */
+#[cfg(feature = "sign")]
+pub fn sign_certificate_request<H: HashChain, S: SignerMut<Signature>>(
+    signer: &mut S,
+    verifying_key: &VerifyingKey<H>,
+    subject: Name,
+    attributes: Attributes,
+) -> Result<CertReq, ()> {
+    let info = CertReqInfo {
+        version: request::Version::V1,
+        subject,
+        public_key: SubjectPublicKeyInfoOwned::from_key(verifying_key.clone()).map_err(|_| ())?,
+        attributes,
+    };
+
+    let info_der = info.to_der().map_err(|_| ())?;
+    let signature = signer.try_sign(&info_der).map_err(|_| ())?;
+    let signature = BitString::from_bytes(signature.as_ref()).map_err(|_| ())?;
+    verify_signature(verifying_key, &info_der, &signature)?;
+
+    Ok(CertReq {
+        info,
+        algorithm: algorithm_identifier(),
+        signature,
+    })
+}
+
+/**
+ * Verifies the signature of `certificate` with the public key of its issuer. Neither the validity
+ * nor the extensions are checked.
+ */
+pub fn verify_certificate<H: HashChain>(
+    issuer_key: &VerifyingKey<H>,
+    certificate: &Certificate,
+) -> Result<(), ()> {
+    let tbs_certificate = &certificate.tbs_certificate;
+    if certificate.signature_algorithm != algorithm_identifier()
+        || tbs_certificate.signature != certificate.signature_algorithm
+    {
+        return Err(());
+    }
+
+    verify_signature(
+        issuer_key,
+        &tbs_certificate.to_der().map_err(|_| ())?,
+        &certificate.signature,
+    )
+}
+
+/**
+ * Verifies the self-signature of `certificate_request` and returns the requested public key.
+ */
+pub fn verify_certificate_request<H: HashChain>(
+    certificate_request: &CertReq,
+) -> Result<VerifyingKey<H>, ()> {
+    if certificate_request.algorithm != algorithm_identifier() {
+        return Err(());
+    }
+
+    let verifying_key = VerifyingKey::try_from(certificate_request.info.public_key.owned_to_ref())
+        .map_err(|_| ())?;
+
+    verify_signature(
+        &verifying_key,
+        &certificate_request.info.to_der().map_err(|_| ())?,
+        &certificate_request.signature,
+    )?;
+
+    Ok(verifying_key)
+}
+
+fn verify_signature<H: HashChain>(
+    verifying_key: &VerifyingKey<H>,
+    message: &[u8],
+    signature: &BitString,
+) -> Result<(), ()> {
+    let signature = signature.as_bytes().ok_or(())?;
+    hss_verify::<H>(message, signature, verifying_key.as_slice()).map_err(|_| ())
+}
+
+#[cfg(all(test, feature = "sign", feature = "sha256"))]
+mod tests {
+    use super::{
+        sign_certificate, sign_certificate_request, sign_self_signed_certificate,
+        verify_certificate, verify_certificate_request,
+    };
+
+    use alloc::vec::Vec;
+    use core::{convert::TryFrom, str::FromStr, time::Duration};
+
+    use spki::{
+        der::{
+            asn1::{OctetString, UtcTime},
+            oid::AssociatedOid,
+            referenced::OwnedToRef,
+            Decode, Encode,
+        },
+        SubjectPublicKeyInfoOwned,
+    };
+    use x509_cert::{
+        attr::Attributes,
+        certificate::{TbsCertificate, Version},
+        ext::{pkix::BasicConstraints, Extension},
+        name::Name,
+        request::CertReq,
+        serial_number::SerialNumber,
+        time::{Time, Validity},
+        Certificate,
+    };
+
+    use crate::{
+        hasher::sha256::{Sha256_192, Sha256_256},
+        hss::{hss_keygen, SigningKey, VerifyingKey},
+        util::helper::test_helper::gen_random_seed,
+        HssParameter, LmotsAlgorithm, LmsAlgorithm,
+    };
+
+    type H = Sha256_256;
+
+    fn keygen() -> (SigningKey<H>, VerifyingKey<H>) {
+        let seed = gen_random_seed::<H>();
+        hss_keygen::<H>(
+            &[HssParameter::new(
+                LmotsAlgorithm::LmotsW4,
+                LmsAlgorithm::LmsH5,
+            )],
+            &seed,
+            None,
+        )
+        .unwrap()
+    }
+
+    fn validity() -> Validity {
+        let time = |seconds| Time::UtcTime(UtcTime::from_unix_duration(seconds).unwrap());
+        Validity {
+            not_before: time(Duration::from_secs(1_700_000_000)),
+            not_after: time(Duration::from_secs(2_000_000_000)),
+        }
+    }
+
+    fn ca_extensions() -> Vec<Extension> {
+        let basic_constraints = BasicConstraints {
+            ca: true,
+            path_len_constraint: Some(0),
+        };
+        [Extension {
+            extn_id: BasicConstraints::OID,
+            critical: true,
+            extn_value: OctetString::new(basic_constraints.to_der().unwrap()).unwrap(),
+        }]
+        .to_vec()
+    }
+
+    #[test]
+    fn self_signed_certificate() {
+        let (mut signing_key, verifying_key) = keygen();
+        let lifetime = signing_key.get_lifetime().unwrap();
+        let subject = Name::from_str("CN=HSS Root CA,O=Example").unwrap();
+
+        let certificate = sign_self_signed_certificate(
+            &mut signing_key,
+            &verifying_key,
+            SerialNumber::new(&[1]).unwrap(),
+            validity(),
+            subject.clone(),
+            Some(ca_extensions()),
+        )
+        .unwrap();
+        assert_eq!(signing_key.get_lifetime().unwrap(), lifetime - 1);
+
+        let decoded = Certificate::from_der(&certificate.to_der().unwrap()).unwrap();
+        assert_eq!(decoded, certificate);
+        assert_eq!(decoded.tbs_certificate.issuer, subject);
+        assert_eq!(
+            VerifyingKey::<H>::try_from(
+                decoded
+                    .tbs_certificate
+                    .subject_public_key_info
+                    .owned_to_ref()
+            )
+            .unwrap(),
+            verifying_key
+        );
+        assert!(verify_certificate(&verifying_key, &decoded).is_ok());
+
+        let (_, other_key) = keygen();
+        assert!(verify_certificate(&other_key, &decoded).is_err());
+
+        let mut modified = decoded.clone();
+        modified.tbs_certificate.serial_number = SerialNumber::new(&[2]).unwrap();
+        assert!(verify_certificate(&verifying_key, &modified).is_err());
+
+        let mut modified = decoded;
+        modified.signature_algorithm.oid = BasicConstraints::OID;
+        assert!(verify_certificate(&verifying_key, &modified).is_err());
+    }
+
+    #[test]
+    fn issue_certificate_from_request() {
+        let (mut ca_signing_key, ca_verifying_key) = keygen();
+        let (mut signing_key, verifying_key) = keygen();
+        let subject = Name::from_str("CN=Firmware Signer").unwrap();
+
+        let certificate_request =
+            sign_certificate_request(&mut signing_key, &verifying_key, subject, Attributes::new())
+                .unwrap();
+        let decoded = CertReq::from_der(&certificate_request.to_der().unwrap()).unwrap();
+        let requested_key = verify_certificate_request::<H>(&decoded).unwrap();
+        assert_eq!(requested_key, verifying_key);
+        assert!(verify_certificate_request::<Sha256_192>(&decoded).is_err());
+
+        let mut modified = decoded.clone();
+        modified.info.subject = Name::from_str("CN=Attacker").unwrap();
+        assert!(verify_certificate_request::<H>(&modified).is_err());
+
+        let tbs_certificate = TbsCertificate {
+            version: Version::V3,
+            serial_number: SerialNumber::new(&[0x10, 0x01]).unwrap(),
+            signature: decoded.algorithm.clone(),
+            issuer: Name::from_str("CN=HSS Root CA").unwrap(),
+            validity: validity(),
+            subject: decoded.info.subject.clone(),
+            subject_public_key_info: SubjectPublicKeyInfoOwned::from_key(requested_key).unwrap(),
+            issuer_unique_id: None,
+            subject_unique_id: None,
+            extensions: None,
+        };
+        let certificate = sign_certificate(&mut ca_signing_key, tbs_certificate).unwrap();
+
+        assert!(verify_certificate(&ca_verifying_key, &certificate).is_ok());
+        assert!(verify_certificate(&verifying_key, &certificate).is_err());
+    }
+
+    #[test]
+    fn reject_verifying_key_of_other_signer() {
+        let (mut signing_key, _) = keygen();
+        let (_, other_key) = keygen();
+
+        assert!(sign_self_signed_certificate(
+            &mut signing_key,
+            &other_key,
+            SerialNumber::new(&[1]).unwrap(),
+            validity(),
+            Name::default(),
+            None,
+        )
+        .is_err());
+        assert!(sign_certificate_request(
+            &mut signing_key,
+            &other_key,
+            Name::default(),
+            Attributes::new()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn exhausted_key_issues_nothing() {
+        let (mut signing_key, verifying_key) = keygen();
+        for _ in 0..signing_key.get_lifetime().unwrap() {
+            sign_certificate_request(
+                &mut signing_key,
+                &verifying_key,
+                Name::default(),
+                Attributes::new(),
+            )
+            .unwrap();
+        }
+
+        assert!(sign_certificate_request(
+            &mut signing_key,
+            &verifying_key,
+            Name::default(),
+            Attributes::new(),
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,313 @@
+use coset::{
+    iana, Algorithm, CoseKey, CoseKeyBuilder, CoseSign1, KeyType, Label, RegisteredLabelWithPrivate,
+};
+#[cfg(feature = "sign")]
+use {
+    alloc::vec::Vec,
+    coset::{Header, ProtectedHeader},
+};
+
+use crate::{
+    hasher::HashChain,
+    hss::{hss_verify, VerifyingKey},
+    signature::Error,
+};
+#[cfg(feature = "sign")]
+use crate::{hss::SigningKey, signature::SignerMut};
+
+const ALGORITHM: Algorithm = RegisteredLabelWithPrivate::Assigned(iana::Algorithm::HSS_LMS);
+
+/**
+ * Signs `payload` and returns the COSE_Sign1 structure. The `alg` parameter of the protected
+ * header is set to HSS-LMS (RFC 8778), further header parameters are taken over.
+ */
+#[cfg(feature = "sign")]
+pub fn sign<H: HashChain>(
+    signing_key: &mut SigningKey<H>,
+    mut protected: Header,
+    unprotected: Header,
+    payload: &[u8],
+    detached: bool,
+    aad: &[u8],
+) -> Result<CoseSign1, ()> {
+    match protected.alg {
+        None => protected.alg = Some(ALGORITHM),
+        Some(ref alg) if *alg == ALGORITHM => {}
+        Some(_) => return Err(()),
+    }
+    if unprotected.alg.is_some() {
+        return Err(());
+    }
+
+    let mut cose_sign1 = CoseSign1 {
+        protected: ProtectedHeader {
+            original_data: None,
+            header: protected,
+        },
+        unprotected,
+        payload: None,
+        signature: Vec::new(),
+    };
+
+    let tbs_data = if detached {
+        cose_sign1.tbs_detached_data(payload, aad)
+    } else {
+        cose_sign1.payload = Some(payload.to_vec());
+        cose_sign1.tbs_data(aad)
+    };
+
+    cose_sign1.signature = signing_key
+        .try_sign(&tbs_data)
+        .map_err(|_| ())?
+        .as_ref()
+        .to_vec();
+
+    Ok(cose_sign1)
+}
+
+/**
+ * Verifies a COSE_Sign1 structure, whose protected header must carry HSS-LMS as `alg`.
+ */
+pub fn verify<H: HashChain>(
+    verifying_key: &VerifyingKey<H>,
+    cose_sign1: &CoseSign1,
+    detached_payload: Option<&[u8]>,
+    aad: &[u8],
+) -> Result<(), ()> {
+    if cose_sign1.protected.header.alg != Some(ALGORITHM) || cose_sign1.unprotected.alg.is_some() {
+        return Err(());
+    }
+
+    let verifier = |signature: &[u8], tbs_data: &[u8]| {
+        hss_verify::<H>(tbs_data, signature, verifying_key.as_slice()).map_err(|_| ())
+    };
+
+    match (&cose_sign1.payload, detached_payload) {
+        (Some(_), None) => cose_sign1.verify_signature(aad, verifier),
+        (None, Some(payload)) => cose_sign1.verify_detached_signature(payload, aad, verifier),
+        _ => Err(()),
+    }
+}
+
+impl<H: HashChain> VerifyingKey<H> {
+    /**
+     * Returns the public key as COSE_Key of key type HSS-LMS (RFC 8778, Section 4).
+     */
+    pub fn to_cose_key(&self) -> CoseKey {
+        CoseKeyBuilder::new()
+            .key_type(iana::KeyType::HSS_LMS)
+            .algorithm(iana::Algorithm::HSS_LMS)
+            .param(
+                iana::HssLmsKeyParameter::Pub as i64,
+                coset::cbor::Value::Bytes(self.as_slice().to_vec()),
+            )
+            .build()
+    }
+
+    /**
+     * Takes the public key from a COSE_Key of key type HSS-LMS. If the `alg` parameter is present,
+     * it must be HSS-LMS.
+     */
+    pub fn from_cose_key(cose_key: &CoseKey) -> Result<Self, Error> {
+        let is_hss_lms_key = cose_key.kty == KeyType::Assigned(iana::KeyType::HSS_LMS)
+            && cose_key.alg.as_ref().map_or(true, |alg| *alg == ALGORITHM);
+        if !is_hss_lms_key {
+            return Err(Error::new());
+        }
+
+        let mut public_keys = cose_key.params.iter().filter_map(|(label, value)| {
+            if *label == Label::Int(iana::HssLmsKeyParameter::Pub as i64) {
+                Some(value.as_bytes())
+            } else {
+                None
+            }
+        });
+
+        match (public_keys.next(), public_keys.next()) {
+            (Some(Some(public_key)), None) => Self::from_checked_bytes(public_key),
+            _ => Err(Error::new()),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "sign", feature = "sha256"))]
+mod tests {
+    use super::{sign, verify};
+
+    use coset::{
+        cbor::Value, iana, CborSerializable, CoseKey, CoseSign1, HeaderBuilder, KeyType,
+        TaggedCborSerializable,
+    };
+
+    use crate::{
+        hasher::sha256::Sha256_256,
+        hss::{hss_keygen, SigningKey, VerifyingKey},
+        util::helper::test_helper::gen_random_seed,
+        HssParameter, LmotsAlgorithm, LmsAlgorithm,
+    };
+
+    type H = Sha256_256;
+
+    fn keygen() -> (SigningKey<H>, VerifyingKey<H>) {
+        let seed = gen_random_seed::<H>();
+        hss_keygen::<H>(
+            &[
+                HssParameter::new(LmotsAlgorithm::LmotsW8, LmsAlgorithm::LmsH5),
+                HssParameter::new(LmotsAlgorithm::LmotsW8, LmsAlgorithm::LmsH5),
+            ],
+            &seed,
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn sign_and_verify_cose_sign1() {
+        let (mut signing_key, verifying_key) = keygen();
+        let payload = b"update manifest";
+        let aad = b"external data";
+
+        let protected = HeaderBuilder::new()
+            .content_format(iana::CoapContentFormat::Cbor)
+            .build();
+        let unprotected = HeaderBuilder::new().key_id(b"key 1".to_vec()).build();
+
+        let cose_sign1 = sign(
+            &mut signing_key,
+            protected,
+            unprotected,
+            payload,
+            false,
+            aad,
+        )
+        .unwrap();
+        let encoded = cose_sign1.to_tagged_vec().unwrap();
+
+        let decoded = CoseSign1::from_tagged_slice(&encoded).unwrap();
+        assert_eq!(
+            decoded.protected.header.alg,
+            Some(coset::Algorithm::Assigned(iana::Algorithm::HSS_LMS))
+        );
+        assert_eq!(decoded.unprotected.key_id, b"key 1");
+        assert!(verify(&verifying_key, &decoded, None, aad).is_ok());
+        assert!(verify(&verifying_key, &decoded, None, b"other data").is_err());
+        assert!(verify(&verifying_key, &decoded, Some(payload), aad).is_err());
+
+        let mut modified = decoded.clone();
+        modified.payload = Some(b"update manifesT".to_vec());
+        assert!(verify(&verifying_key, &modified, None, aad).is_err());
+
+        let mut modified = decoded;
+        modified.protected = coset::ProtectedHeader {
+            original_data: None,
+            header: HeaderBuilder::new()
+                .algorithm(iana::Algorithm::ES256)
+                .build(),
+        };
+        assert!(verify(&verifying_key, &modified, None, aad).is_err());
+    }
+
+    #[test]
+    fn sign_and_verify_detached_cose_sign1() {
+        let (mut signing_key, verifying_key) = keygen();
+        let payload = b"firmware image";
+
+        let cose_sign1 = sign(
+            &mut signing_key,
+            Default::default(),
+            Default::default(),
+            payload,
+            true,
+            &[],
+        )
+        .unwrap();
+        assert!(cose_sign1.payload.is_none());
+
+        let decoded = CoseSign1::from_slice(&cose_sign1.to_vec().unwrap()).unwrap();
+        assert!(verify(&verifying_key, &decoded, Some(payload), &[]).is_ok());
+        assert!(verify(&verifying_key, &decoded, Some(b"firmware imagE"), &[]).is_err());
+        assert!(verify(&verifying_key, &decoded, None, &[]).is_err());
+    }
+
+    #[test]
+    fn reject_conflicting_algorithm() {
+        let (mut signing_key, _) = keygen();
+        let es256 = || {
+            HeaderBuilder::new()
+                .algorithm(iana::Algorithm::ES256)
+                .build()
+        };
+
+        assert!(sign(
+            &mut signing_key,
+            es256(),
+            Default::default(),
+            &[],
+            false,
+            &[]
+        )
+        .is_err());
+        assert!(sign(
+            &mut signing_key,
+            Default::default(),
+            es256(),
+            &[],
+            false,
+            &[]
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn cose_key_round_trip() {
+        let (_, verifying_key) = keygen();
+
+        let cose_key = verifying_key.to_cose_key();
+        let encoded = cose_key.to_vec().unwrap();
+
+        // {1: 5, 3: -46, -1: h'...'}
+        assert_eq!(&encoded[..6], &[0xa3, 0x01, 0x05, 0x03, 0x38, 0x2d]);
+
+        let decoded = CoseKey::from_slice(&encoded).unwrap();
+        assert_eq!(
+            VerifyingKey::from_cose_key(&decoded).unwrap(),
+            verifying_key
+        );
+    }
+
+    #[test]
+    fn reject_malformed_cose_key() {
+        let (_, verifying_key) = keygen();
+        let public_key = Value::Bytes(verifying_key.as_slice().to_vec());
+
+        let mut cose_key = verifying_key.to_cose_key();
+        cose_key.alg = None;
+        assert!(VerifyingKey::<H>::from_cose_key(&cose_key).is_ok());
+
+        let mut modified = cose_key.clone();
+        modified.kty = KeyType::Assigned(iana::KeyType::OKP);
+        assert!(VerifyingKey::<H>::from_cose_key(&modified).is_err());
+
+        let mut modified = cose_key.clone();
+        modified.alg = Some(coset::Algorithm::Assigned(iana::Algorithm::EdDSA));
+        assert!(VerifyingKey::<H>::from_cose_key(&modified).is_err());
+
+        let mut modified = cose_key.clone();
+        modified.params.clear();
+        assert!(VerifyingKey::<H>::from_cose_key(&modified).is_err());
+
+        let mut modified = cose_key.clone();
+        modified
+            .params
+            .push((coset::Label::Int(-1), public_key.clone()));
+        assert!(VerifyingKey::<H>::from_cose_key(&modified).is_err());
+
+        let mut modified = cose_key.clone();
+        modified.params[0].1 = Value::Text("public key".into());
+        assert!(VerifyingKey::<H>::from_cose_key(&modified).is_err());
+
+        let mut modified = cose_key;
+        modified.params[0].1 = Value::Bytes(verifying_key.as_slice()[..20].to_vec());
+        assert!(VerifyingKey::<H>::from_cose_key(&modified).is_err());
+    }
+}
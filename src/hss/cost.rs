@@ -0,0 +1,420 @@
+use core::mem::size_of;
+use tinyvec::ArrayVec;
+
+use crate::{
+    constants::{
+        get_hash_chain_count, lms_public_key_length, lms_signature_length, prng_len,
+        winternitz_chain::iter_len, LMS_LEAF_IDENTIFIERS_SIZE, MAX_ALLOWED_HSS_LEVELS, MIN_SUBTREE,
+        REF_IMPL_MAX_ALLOWED_HSS_LEVELS,
+    },
+    hasher::HashChain,
+    hss::parameter::HssParameter,
+    LmotsAlgorithm, LmsAlgorithm,
+};
+
+/// I || q || D_LEAF / D_INTR / D_PBLC / D_MESG
+const NODE_PREFIX_LEN: usize = 22;
+/// Four bytes of aux level, followed by the stored tree levels and the HMAC.
+const AUX_DATA_HEADER_LEN: usize = 4;
+
+/**
+ * Construction of a hash function, which determines how many invocations of its compression
+ * function (or permutation) an input takes.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HashConstruction {
+    /// Merkle-Damgard with the padding of SHA-256 (at least 9 bytes) and the given block size.
+    MerkleDamgard { block_size: u16 },
+    /// Sponge with the padding of SHAKE (at least 1 byte) and the given rate in bytes.
+    Sponge { rate: u16 },
+}
+
+impl HashConstruction {
+    pub const SHA256: Self = HashConstruction::MerkleDamgard { block_size: 64 };
+    pub const SHAKE256: Self = HashConstruction::Sponge { rate: 136 };
+
+    /**
+     * Returns the construction of `H`. Hash functions named `SHAKE` are sponges with the rate of
+     * SHAKE256, all others are modelled like SHA-256 with `H::BLOCK_SIZE`.
+     */
+    pub fn of<H: HashChain>() -> Self {
+        if H::IANA_NAME == "SHAKE" {
+            HashConstruction::SHAKE256
+        } else {
+            HashConstruction::MerkleDamgard {
+                block_size: H::BLOCK_SIZE,
+            }
+        }
+    }
+
+    /// Number of invocations of the compression function (or permutation) for `length` bytes.
+    const fn compressions(&self, length: usize) -> u64 {
+        let (block_size, padding) = match *self {
+            HashConstruction::MerkleDamgard { block_size } => (block_size as usize, 9),
+            HashConstruction::Sponge { rate } => (rate as usize, 1),
+        };
+
+        ((length + padding + block_size - 1) / block_size) as u64
+    }
+
+    const fn is_valid(&self) -> bool {
+        match *self {
+            HashConstruction::MerkleDamgard { block_size } => block_size > 0,
+            HashConstruction::Sponge { rate } => rate > 0,
+        }
+    }
+}
+
+/**
+ * Sizes and estimated costs of an HSS parameter set. The costs are counted in invocations of the
+ * compression function of the hash (or the permutation of a sponge), and leave the hashing of the
+ * message itself out.
+ *
+ * The sign costs are rough estimates of the work of this crate without any caching between
+ * signatures: Every tree below the top level is generated twice per signature, once for its
+ * public key, which is signed by the parent tree, and once for its authentication path.
+ *
+ * ```
+ * use hbs_lms::{HashChain, HashConstruction, LmotsAlgorithm, LmsAlgorithm, ParameterSetCost};
+ * use hbs_lms::Sha256_256;
+ *
+ * const COST: ParameterSetCost = match ParameterSetCost::new(
+ *     Sha256_256::OUTPUT_SIZE,
+ *     HashConstruction::SHA256,
+ *     &[(LmotsAlgorithm::LmotsW4, LmsAlgorithm::LmsH10)],
+ * ) {
+ *     Some(cost) => cost,
+ *     None => panic!("invalid parameter set"),
+ * };
+ *
+ * assert_eq!(COST.signature_length, 2512);
+ * assert_eq!(COST.lifetime, 1024);
+ * ```
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParameterSetCost {
+    pub signature_length: usize,
+    pub public_key_length: usize,
+    pub private_key_length: usize,
+    /// Number of signatures the parameter set allows for.
+    pub lifetime: u64,
+    pub keygen_hash_compressions: u64,
+    pub sign_hash_compressions: u64,
+    /// Signing with aux data of [`ParameterSetCost::recommended_aux_data_length`].
+    pub sign_with_aux_hash_compressions: u64,
+    pub verify_worst_hash_compressions: u64,
+    pub verify_average_hash_compressions: u64,
+    /// Aux data length at which every cacheable level of the top tree is stored. Longer aux data
+    /// does not speed up signing any further.
+    pub recommended_aux_data_length: usize,
+}
+
+impl ParameterSetCost {
+    /**
+     * Calculates the costs of `parameters`, one pair of algorithms for each HSS level starting
+     * with the top level, for a hash function with the given output size and construction (e.g.
+     * `Sha256_256::OUTPUT_SIZE` and [`HashConstruction::SHA256`]).
+     *
+     * Returns `None` for unsupported output sizes, reserved algorithms or an invalid number of
+     * levels.
+     */
+    pub const fn new(
+        output_size: u16,
+        construction: HashConstruction,
+        parameters: &[(LmotsAlgorithm, LmsAlgorithm)],
+    ) -> Option<Self> {
+        let n = output_size as usize;
+        let levels = parameters.len();
+
+        if !matches!(n, 16 | 24 | 32)
+            || !construction.is_valid()
+            || levels == 0
+            || levels > MAX_ALLOWED_HSS_LEVELS
+        {
+            return None;
+        }
+
+        let public_key_length = size_of::<u32>() + lms_public_key_length(n);
+
+        let mut cost = ParameterSetCost {
+            signature_length: size_of::<u32>(),
+            public_key_length,
+            private_key_length: LMS_LEAF_IDENTIFIERS_SIZE + REF_IMPL_MAX_ALLOWED_HSS_LEVELS + n,
+            lifetime: 1,
+            keygen_hash_compressions: 0,
+            sign_hash_compressions: 0,
+            sign_with_aux_hash_compressions: 0,
+            verify_worst_hash_compressions: 0,
+            verify_average_hash_compressions: 0,
+            recommended_aux_data_length: 0,
+        };
+
+        let seed_derive = construction.compressions(prng_len(n));
+        let chain_step = construction.compressions(iter_len(n));
+        let leaf = construction.compressions(NODE_PREFIX_LEN + n);
+        let interior = construction.compressions(NODE_PREFIX_LEN + 2 * n);
+
+        let mut level = 0;
+        while level < levels {
+            let (winternitz, tree_height) = match (
+                winternitz_parameter(parameters[level].0),
+                tree_height(parameters[level].1),
+            ) {
+                (Some(winternitz), Some(tree_height)) => (winternitz, tree_height),
+                _ => return None,
+            };
+
+            let p = get_hash_chain_count(winternitz, n);
+            let chain_length = (1u64 << winternitz) - 1;
+            let leaves = 1u64 << tree_height;
+
+            // The signed message is the public key of the child tree, except on the lowest level.
+            let message_length = if level + 1 < levels {
+                lms_public_key_length(n)
+            } else {
+                0
+            };
+            let message_hash = construction.compressions(NODE_PREFIX_LEN + n + message_length);
+            let ots_public_key = construction.compressions(NODE_PREFIX_LEN + p * n);
+
+            let ots_keygen =
+                (p as u64) * (seed_derive + chain_length * chain_step) + ots_public_key;
+            let tree = leaves * (ots_keygen + leaf) + (leaves - 1) * interior;
+            // On average, half of each chain is computed while signing, the other half while
+            // verifying.
+            let ots_sign = seed_derive
+                + message_hash
+                + (p as u64) * (seed_derive + chain_length * chain_step / 2);
+            let ots_verify_worst =
+                message_hash + (p as u64) * chain_length * chain_step + ots_public_key;
+            let ots_verify_average =
+                message_hash + (p as u64) * chain_length * chain_step / 2 + ots_public_key;
+            let lms_verify = leaf + (tree_height as u64) * interior;
+
+            cost.signature_length += lms_signature_length(n, p, tree_height);
+            if level + 1 < levels {
+                cost.signature_length += lms_public_key_length(n);
+            }
+            cost.lifetime = cost.lifetime.saturating_mul(leaves);
+
+            // Computing an authentication path takes about as long as generating the whole tree.
+            // The trees below the top level are generated a second time for their public keys.
+            // With aux data, the top authentication path is read from the aux data, which is
+            // authenticated with an HMAC.
+            let trees_per_signature = if level == 0 { 1 } else { 2 };
+            cost.sign_hash_compressions += trees_per_signature * tree + ots_sign;
+            if level == 0 {
+                let aux_data_length = aux_data_length(n, tree_height);
+                cost.recommended_aux_data_length = aux_data_length;
+                cost.keygen_hash_compressions = tree;
+                cost.sign_with_aux_hash_compressions += construction.compressions(aux_data_length)
+                    + (tree_height as u64 + 1) / 2 * interior
+                    + ots_sign;
+            } else {
+                cost.sign_with_aux_hash_compressions += trees_per_signature * tree + ots_sign;
+            }
+
+            cost.verify_worst_hash_compressions += ots_verify_worst + lms_verify;
+            cost.verify_average_hash_compressions += ots_verify_average + lms_verify;
+
+            level += 1;
+        }
+
+        Some(cost)
+    }
+
+    /**
+     * Calculates the costs of `parameters` for the hash function `H`.
+     */
+    pub fn from_parameters<H: HashChain>(parameters: &[HssParameter<H>]) -> Option<Self> {
+        let mut algorithms: ArrayVec<[(LmotsAlgorithm, LmsAlgorithm); MAX_ALLOWED_HSS_LEVELS]> =
+            ArrayVec::new();
+        for parameter in parameters {
+            if algorithms.try_push(parameter.get_algorithms()).is_some() {
+                return None;
+            }
+        }
+
+        Self::new(H::OUTPUT_SIZE, HashConstruction::of::<H>(), &algorithms)
+    }
+}
+
+const fn winternitz_parameter(lmots_algorithm: LmotsAlgorithm) -> Option<usize> {
+    match lmots_algorithm {
+        LmotsAlgorithm::LmotsReserved => None,
+        LmotsAlgorithm::LmotsW1 => Some(1),
+        LmotsAlgorithm::LmotsW2 => Some(2),
+        LmotsAlgorithm::LmotsW4 => Some(4),
+        LmotsAlgorithm::LmotsW8 => Some(8),
+    }
+}
+
+const fn tree_height(lms_algorithm: LmsAlgorithm) -> Option<usize> {
+    match lms_algorithm {
+        LmsAlgorithm::LmsReserved => None,
+        #[cfg(test)]
+        LmsAlgorithm::LmsH2 => Some(2),
+        LmsAlgorithm::LmsH5 => Some(5),
+        LmsAlgorithm::LmsH10 => Some(10),
+        LmsAlgorithm::LmsH15 => Some(15),
+        LmsAlgorithm::LmsH20 => Some(20),
+        LmsAlgorithm::LmsH25 => Some(25),
+    }
+}
+
+/// Length of the aux data, if every second level of the top tree starting with the leaves is
+/// stored (see `hss_optimal_aux_level`).
+const fn aux_data_length(n: usize, tree_height: usize) -> usize {
+    let mut length = AUX_DATA_HEADER_LEN + n;
+
+    let mut level = tree_height;
+    while level >= 1 {
+        length += n << level;
+        if level < MIN_SUBTREE {
+            break;
+        }
+        level -= MIN_SUBTREE;
+    }
+
+    length
+}
+
+#[cfg(all(test, feature = "sign", feature = "sha256"))]
+mod tests {
+    use super::{HashConstruction, ParameterSetCost};
+
+    use crate::{
+        hasher::{
+            sha256::{Sha256_192, Sha256_256},
+            HashChain,
+        },
+        hss::{aux::hss_get_aux_data_len, hss_keygen, hss_sign, reference_impl_private_key::Seed},
+        HssParameter, LmotsAlgorithm, LmsAlgorithm,
+    };
+
+    const COST: Option<ParameterSetCost> = ParameterSetCost::new(
+        Sha256_256::OUTPUT_SIZE,
+        HashConstruction::SHA256,
+        &[
+            (LmotsAlgorithm::LmotsW8, LmsAlgorithm::LmsH10),
+            (LmotsAlgorithm::LmotsW4, LmsAlgorithm::LmsH5),
+        ],
+    );
+
+    #[test]
+    fn usable_in_const_context() {
+        let cost = COST.unwrap();
+        assert_eq!(cost.lifetime, 1 << 15);
+        assert_eq!(cost.public_key_length, 60);
+        assert_eq!(cost.private_key_length, 48);
+    }
+
+    fn check_sizes<H: HashChain>(parameters: &[HssParameter<H>]) {
+        let cost = ParameterSetCost::from_parameters(parameters).unwrap();
+
+        let (signing_key, verifying_key) =
+            hss_keygen::<H>(parameters, &Seed::default(), None).unwrap();
+        assert_eq!(verifying_key.as_slice().len(), cost.public_key_length);
+        assert_eq!(signing_key.as_slice().len(), cost.private_key_length);
+        assert_eq!(signing_key.signature_range().unwrap().end, cost.lifetime);
+
+        let signature =
+            hss_sign::<H>(b"message", signing_key.as_slice(), &mut |_| Ok(()), None).unwrap();
+        assert_eq!(signature.as_ref().len(), cost.signature_length);
+
+        assert_eq!(
+            hss_get_aux_data_len(usize::MAX, *parameters[0].get_lms_parameter()),
+            cost.recommended_aux_data_length
+        );
+    }
+
+    #[test]
+    fn sizes_match_generated_keys_and_signatures() {
+        check_sizes::<Sha256_256>(&[HssParameter::new(
+            LmotsAlgorithm::LmotsW4,
+            LmsAlgorithm::LmsH5,
+        )]);
+        check_sizes::<Sha256_256>(&[
+            HssParameter::new(LmotsAlgorithm::LmotsW8, LmsAlgorithm::LmsH5),
+            HssParameter::new(LmotsAlgorithm::LmotsW2, LmsAlgorithm::LmsH5),
+        ]);
+        check_sizes::<Sha256_192>(&[
+            HssParameter::new(LmotsAlgorithm::LmotsW4, LmsAlgorithm::LmsH5),
+            HssParameter::new(LmotsAlgorithm::LmotsW8, LmsAlgorithm::LmsH5),
+        ]);
+    }
+
+    #[test]
+    fn costs_are_ordered() {
+        let cost = COST.unwrap();
+
+        assert!(cost.sign_with_aux_hash_compressions < cost.sign_hash_compressions);
+        assert!(cost.keygen_hash_compressions < cost.sign_hash_compressions);
+        assert!(cost.verify_average_hash_compressions < cost.verify_worst_hash_compressions);
+        assert!(cost.verify_worst_hash_compressions < cost.sign_with_aux_hash_compressions);
+
+        let w1 = ParameterSetCost::new(
+            32,
+            HashConstruction::SHA256,
+            &[(LmotsAlgorithm::LmotsW1, LmsAlgorithm::LmsH5)],
+        )
+        .unwrap();
+        let w8 = ParameterSetCost::new(
+            32,
+            HashConstruction::SHA256,
+            &[(LmotsAlgorithm::LmotsW8, LmsAlgorithm::LmsH5)],
+        )
+        .unwrap();
+        assert!(w1.signature_length > w8.signature_length);
+        assert!(w1.verify_worst_hash_compressions < w8.verify_worst_hash_compressions);
+    }
+
+    #[test]
+    fn model_hash_constructions() {
+        assert_eq!(HashConstruction::SHA256.compressions(55), 1);
+        assert_eq!(HashConstruction::SHA256.compressions(56), 2);
+        assert_eq!(HashConstruction::SHAKE256.compressions(135), 1);
+        assert_eq!(HashConstruction::SHAKE256.compressions(136), 2);
+
+        assert_eq!(
+            HashConstruction::of::<Sha256_192>(),
+            HashConstruction::SHA256
+        );
+        #[cfg(feature = "shake256")]
+        assert_eq!(
+            HashConstruction::of::<crate::hasher::shake256::Shake256_256>(),
+            HashConstruction::SHAKE256
+        );
+
+        // The larger rate of SHAKE256 absorbs the LM-OTS public key in fewer permutations.
+        let parameters = [(LmotsAlgorithm::LmotsW4, LmsAlgorithm::LmsH5)];
+        let sha256 = ParameterSetCost::new(32, HashConstruction::SHA256, &parameters).unwrap();
+        let shake256 = ParameterSetCost::new(32, HashConstruction::SHAKE256, &parameters).unwrap();
+        assert_eq!(sha256.signature_length, shake256.signature_length);
+        assert!(shake256.verify_worst_hash_compressions < sha256.verify_worst_hash_compressions);
+    }
+
+    #[test]
+    fn reject_invalid_parameters() {
+        let valid = (LmotsAlgorithm::LmotsW4, LmsAlgorithm::LmsH5);
+
+        assert!(ParameterSetCost::new(32, HashConstruction::SHA256, &[]).is_none());
+        assert!(ParameterSetCost::new(20, HashConstruction::SHA256, &[valid]).is_none());
+        assert!(
+            ParameterSetCost::new(32, HashConstruction::Sponge { rate: 0 }, &[valid]).is_none()
+        );
+        assert!(ParameterSetCost::new(
+            32,
+            HashConstruction::SHA256,
+            &[(LmotsAlgorithm::LmotsReserved, LmsAlgorithm::LmsH5)]
+        )
+        .is_none());
+        assert!(ParameterSetCost::new(
+            32,
+            HashConstruction::SHA256,
+            &[(LmotsAlgorithm::LmotsW4, LmsAlgorithm::LmsReserved)]
+        )
+        .is_none());
+        assert!(ParameterSetCost::new(32, HashConstruction::SHA256, &[valid; 9]).is_none());
+    }
+}
@@ -1,31 +1,31 @@
 use core::convert::TryInto;
 
+#[cfg(feature = "sign")]
 use tinyvec::ArrayVec;
 
+#[cfg(feature = "sign")]
 use crate::{
     constants::{MAX_ALLOWED_HSS_LEVELS, MAX_HSS_PUBLIC_KEY_LENGTH},
-    hasher::HashChain,
     hss::aux::{
-        hss_expand_aux_data, hss_finalize_aux_data, hss_optimal_aux_level, hss_store_aux_marker,
-    },
-    lms::{
-        self,
-        definitions::{InMemoryLmsPublicKey, LmsPrivateKey, LmsPublicKey},
-        generate_key_pair,
-        parameters::LmsParameter,
+        hss_expand_aux_data, hss_finalize_aux_data, hss_get_aux_data_len, hss_is_aux_data_used,
+        hss_optimal_aux_level, hss_store_aux_marker, MutableExpandedAuxData,
     },
-    util::helper::read_and_advance,
-};
-use crate::{hss::aux::hss_get_aux_data_len, lms::signing::LmsSignature};
-
-use super::{
-    aux::{hss_is_aux_data_used, MutableExpandedAuxData},
-    reference_impl_private_key::{
+    hss::reference_impl_private_key::{
         generate_child_seed_and_lms_tree_identifier, generate_signature_randomizer,
         ReferenceImplPrivateKey,
     },
+    lms::{
+        self, definitions::LmsPrivateKey, generate_key_pair, parameters::LmsParameter,
+        signing::LmsSignature,
+    },
+};
+use crate::{
+    hasher::HashChain,
+    lms::definitions::{InMemoryLmsPublicKey, LmsPublicKey},
+    util::helper::read_and_advance,
 };
 
+#[cfg(feature = "sign")]
 #[derive(Debug, Default, PartialEq)]
 pub struct HssPrivateKey<H: HashChain> {
     pub private_key: ArrayVec<[LmsPrivateKey<H>; MAX_ALLOWED_HSS_LEVELS]>,
@@ -33,6 +33,7 @@ pub struct HssPrivateKey<H: HashChain> {
     pub signatures: ArrayVec<[LmsSignature<H>; MAX_ALLOWED_HSS_LEVELS - 1]>, // Only L - 1 signatures needed
 }
 
+#[cfg(feature = "sign")]
 impl<H: HashChain> HssPrivateKey<H> {
     pub fn get_length(&self) -> usize {
         self.private_key.len()
@@ -49,7 +50,7 @@ impl<H: HashChain> HssPrivateKey<H> {
         let used_leafs_indexes = private_key.compressed_used_leafs_indexes.to(&parameters);
 
         let lms_private_key = LmsPrivateKey {
-            seed: current_seed.seed,
+            seed: current_seed.seed.clone(),
             lms_tree_identifier: current_seed.lms_tree_identifier,
             lmots_parameter: *parameters[0].get_lmots_parameter(),
             lms_parameter: *parameters[0].get_lms_parameter(),
@@ -58,8 +59,7 @@ impl<H: HashChain> HssPrivateKey<H> {
         hss_private_key.private_key.push(lms_private_key);
 
         for (i, parameter) in parameters.iter().enumerate().skip(1) {
-            let parent_used_leafs_index: u32 =
-                hss_private_key.private_key[i - 1].used_leafs_index as u32;
+            let parent_used_leafs_index: u32 = hss_private_key.private_key[i - 1].used_leafs_index;
 
             current_seed = generate_child_seed_and_lms_tree_identifier::<H>(
                 &current_seed,
@@ -71,6 +71,14 @@ impl<H: HashChain> HssPrivateKey<H> {
             let lms_keypair =
                 generate_key_pair(&current_seed, parameter, &used_leafs_indexes[i], &mut None);
 
+            // A fault during the computation of the child tree changes its root, which would be
+            // signed by the parent tree nevertheless. As the correct root is signed with the same
+            // one-time key after a restart, the parent one-time key would sign two messages.
+            #[cfg(feature = "fault_protection")]
+            if LmsPublicKey::new(&lms_keypair.private_key, &mut None) != lms_keypair.public_key {
+                return Err(());
+            }
+
             let signature = lms::signing::LmsSignature::sign(
                 &mut hss_private_key.private_key[i - 1],
                 lms_keypair.public_key.to_binary_representation().as_slice(),
@@ -152,6 +160,7 @@ impl<'a, H: HashChain> PartialEq<HssPublicKey<H>> for InMemoryHssPublicKey<'a, H
 }
 
 impl<H: HashChain> HssPublicKey<H> {
+    #[cfg(feature = "sign")]
     pub fn from(
         private_key: &ReferenceImplPrivateKey<H>,
         aux_data: Option<&mut &mut [u8]>,
@@ -195,6 +204,8 @@ impl<H: HashChain> HssPublicKey<H> {
             level: levels,
         })
     }
+
+    #[cfg(feature = "sign")]
     pub fn to_binary_representation(&self) -> ArrayVec<[u8; MAX_HSS_PUBLIC_KEY_LENGTH]> {
         let mut result = ArrayVec::new();
 
@@ -209,12 +220,13 @@ impl<'a, H: HashChain> InMemoryHssPublicKey<'a, H> {
     pub fn new(data: &'a [u8]) -> Option<Self> {
         let mut index = 0;
 
+        if data.len() < 4 {
+            return None;
+        }
+
         let level = u32::from_be_bytes(read_and_advance(data, 4, &mut index).try_into().unwrap());
 
-        let public_key = match InMemoryLmsPublicKey::new(&data[index..]) {
-            None => return None,
-            Some(x) => x,
-        };
+        let public_key = InMemoryLmsPublicKey::new(&data[index..])?;
 
         Some(Self {
             public_key,
@@ -223,7 +235,7 @@ impl<'a, H: HashChain> InMemoryHssPublicKey<'a, H> {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "sign", feature = "sha256"))]
 mod tests {
     use rand::{rngs::OsRng, RngCore};
 
@@ -0,0 +1,405 @@
+use core::{convert::TryFrom, convert::TryInto, fmt, marker::PhantomData};
+use tinyvec::ArrayVec;
+
+use crate::{
+    constants::{
+        lms_public_key_length, MAX_HSS_SIGNATURE_LENGTH, MAX_HSS_SIGNED_PUBLIC_KEY_LENGTH,
+    },
+    hasher::HashChain,
+    hss::{
+        aux::hss_is_aux_data_used,
+        definitions::{HssPrivateKey, InMemoryHssPublicKey},
+        reference_impl_private_key::{generate_signature_randomizer, ReferenceImplPrivateKey},
+        signing::InMemoryHssSignedPublicKey,
+        SigningKey, VerifyingKey,
+    },
+    lm_ots::verify::VerifyScratch,
+    lms::{self, definitions::LmsPrivateKey, signing::LmsSignature},
+    signature::{Error, SignerMut},
+    Signature,
+};
+
+/**
+ * Signature of a leaf of the top tree over the top tree of a delegate key, which has one level
+ * less than the delegating key. It is encoded like a `signed_public_key` of an HSS signature and
+ * contains no secrets.
+ *
+ * The holder of the top tree, e.g. an offline HSM, creates the delegation with
+ * [`SigningKey::delegate`]. The delegate signs with a [`DelegatedSigningKey`], which needs no
+ * secret of the top tree, and its signatures are verified with the original [`VerifyingKey`].
+ *
+ * ```
+ * use hbs_lms::{keygen, DelegatedSigningKey, HssParameter, LmotsAlgorithm, LmsAlgorithm};
+ * use hbs_lms::{signature::SignerMut, verify, Seed, Sha256_256};
+ *
+ * let parameter = HssParameter::new(LmotsAlgorithm::LmotsW4, LmsAlgorithm::LmsH5);
+ * let (mut top_signing_key, verifying_key) =
+ *     keygen::<Sha256_256>(&[parameter, parameter], &Seed::default(), None).unwrap();
+ *
+ * // The delegate generates its own key with the lower levels.
+ * let (delegate_signing_key, delegate_verifying_key) =
+ *     keygen::<Sha256_256>(&[parameter], &Seed::from([1u8; 32]), None).unwrap();
+ *
+ * let delegation = top_signing_key.delegate(&delegate_verifying_key, None).unwrap();
+ * let mut delegate =
+ *     DelegatedSigningKey::new(delegation, delegate_signing_key, &verifying_key).unwrap();
+ *
+ * let signature = delegate.try_sign(b"message").unwrap();
+ * assert!(verify::<Sha256_256>(b"message", signature.as_ref(), verifying_key.as_slice()).is_ok());
+ * ```
+ */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Delegation<H: HashChain> {
+    bytes: ArrayVec<[u8; MAX_HSS_SIGNED_PUBLIC_KEY_LENGTH]>,
+    phantom_data: PhantomData<H>,
+}
+
+impl<H: HashChain> Delegation<H> {
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let signature_length = lms::signature_length::<H>(bytes).ok_or_else(Error::new)?;
+        if bytes.len() != signature_length + lms_public_key_length(H::OUTPUT_SIZE as usize) {
+            return Err(Error::new());
+        }
+        InMemoryHssSignedPublicKey::<H>::new(bytes).ok_or_else(Error::new)?;
+
+        Ok(Self {
+            bytes: ArrayVec::try_from(bytes).map_err(|_| Error::new())?,
+            phantom_data: PhantomData,
+        })
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+
+    /**
+     * Verify the delegation against the verifying key of the delegating key.
+     *
+     * # Arguments
+     * * `verifying_key` - The verifying key of the delegating key.
+     */
+    pub fn verify(&self, verifying_key: &VerifyingKey<H>) -> Result<(), Error> {
+        let public_key = parse_public_key::<H>(verifying_key.as_slice()).ok_or_else(Error::new)?;
+        if public_key.level < 2 {
+            return Err(Error::new());
+        }
+
+        let signed_public_key =
+            InMemoryHssSignedPublicKey::<H>::new(self.as_slice()).ok_or_else(Error::new)?;
+
+        lms::verify::verify(
+            &signed_public_key.sig,
+            &public_key.public_key,
+            signed_public_key.public_key.as_slice(),
+            &mut VerifyScratch::default(),
+        )
+        .map_err(|_| Error::new())
+    }
+}
+
+/// Parses an HSS public key, after checking its length.
+fn parse_public_key<H: HashChain>(bytes: &[u8]) -> Option<InMemoryHssPublicKey<'_, H>> {
+    if bytes.len() != core::mem::size_of::<u32>() + lms_public_key_length(H::OUTPUT_SIZE as usize) {
+        return None;
+    }
+
+    InMemoryHssPublicKey::new(bytes)
+}
+
+/**
+ * Signs the top tree of `delegate_key` with the first leaf of the top tree of `private_key`, whose
+ * subtree is unused. The signatures of the skipped subtrees and of the subtree of the used leaf
+ * are removed from `private_key`.
+ */
+pub(crate) fn delegate<H: HashChain>(
+    private_key: &mut ReferenceImplPrivateKey<H>,
+    delegate_key: &VerifyingKey<H>,
+    aux_data: Option<&mut &mut [u8]>,
+) -> Result<Delegation<H>, ()> {
+    let parameters = private_key.compressed_parameter.to::<H>()?;
+    let delegate_key = parse_public_key::<H>(delegate_key.as_slice()).ok_or(())?;
+
+    if delegate_key.level == 0 || delegate_key.level + 1 != parameters.len() {
+        return Err(());
+    }
+
+    // The top leaf of a started subtree has already signed a child tree of this key.
+    let remaining = private_key.remaining_signatures()?;
+    let top_lms_parameter = parameters[0].get_lms_parameter();
+    let subtree_size = private_key.total_signatures()? >> top_lms_parameter.get_tree_height();
+    let leaf = (remaining.start + subtree_size - 1) / subtree_size;
+    let next = (leaf + 1) * subtree_size;
+
+    if next > remaining.end {
+        return Err(());
+    }
+
+    let is_aux_data_used = if let Some(ref aux_data) = aux_data {
+        hss_is_aux_data_used(aux_data)
+    } else {
+        false
+    };
+
+    let mut expanded_aux_data = HssPrivateKey::get_expanded_aux_data(
+        aux_data,
+        private_key,
+        top_lms_parameter,
+        is_aux_data_used,
+    );
+
+    // The seeds of the top tree are wiped at the end of the block with the `zeroize` feature.
+    let signature = {
+        let top_seed = private_key.generate_root_seed_and_lms_tree_identifier();
+        let mut top_private_key = LmsPrivateKey::new(
+            top_seed.seed.clone(),
+            top_seed.lms_tree_identifier,
+            leaf as u32,
+            *parameters[0].get_lmots_parameter(),
+            *top_lms_parameter,
+        );
+        let signature_randomizer = generate_signature_randomizer::<H>(&top_seed, &(leaf as u32));
+
+        LmsSignature::sign(
+            &mut top_private_key,
+            delegate_key.public_key.as_slice(),
+            &signature_randomizer,
+            &mut expanded_aux_data,
+        )?
+    };
+
+    private_key.skip_to(next)?;
+
+    let mut bytes = ArrayVec::new();
+    bytes.extend_from_slice(signature.to_binary_representation().as_slice());
+    bytes.extend_from_slice(delegate_key.public_key.as_slice());
+
+    Ok(Delegation {
+        bytes,
+        phantom_data: PhantomData,
+    })
+}
+
+/**
+ * Signer of a delegate, which holds the lower levels of an HSS key and a [`Delegation`] of the
+ * top tree. Its signatures are verified with the verifying key of the delegating key.
+ *
+ * The private key of the delegate advances with every signature like a [`SigningKey`] and must
+ * be persisted with [`DelegatedSigningKey::signing_key`] before a signature is released.
+ */
+pub struct DelegatedSigningKey<H: HashChain> {
+    delegation: Delegation<H>,
+    signing_key: SigningKey<H>,
+}
+
+impl<H: HashChain> DelegatedSigningKey<H> {
+    /**
+     * Combines the private key of the delegate with its delegation.
+     *
+     * Returns an error, if the delegation doesn't verify under `verifying_key`, if the private key
+     * doesn't have one level less than the delegating key or if it doesn't belong to the public key
+     * in the delegation. The latter is checked with the tree identifier and the parameters of the
+     * top tree, as recomputing its root costs as much as the key generation.
+     *
+     * # Arguments
+     * * `delegation` - The delegation of the delegate key.
+     * * `signing_key` - The private key of the delegate.
+     * * `verifying_key` - The verifying key of the delegating key.
+     */
+    pub fn new(
+        delegation: Delegation<H>,
+        signing_key: SigningKey<H>,
+        verifying_key: &VerifyingKey<H>,
+    ) -> Result<Self, Error> {
+        delegation.verify(verifying_key)?;
+
+        let private_key =
+            ReferenceImplPrivateKey::<H>::from_binary_representation(signing_key.as_slice())
+                .map_err(|_| Error::new())?;
+        let parameters = private_key
+            .compressed_parameter
+            .to::<H>()
+            .map_err(|_| Error::new())?;
+        let public_key = parse_public_key::<H>(verifying_key.as_slice()).ok_or_else(Error::new)?;
+
+        if parameters.len() + 1 != public_key.level {
+            return Err(Error::new());
+        }
+
+        let delegate_key = InMemoryHssSignedPublicKey::<H>::new(delegation.as_slice())
+            .ok_or_else(Error::new)?
+            .public_key;
+        let lms_tree_identifier = private_key
+            .generate_root_seed_and_lms_tree_identifier()
+            .lms_tree_identifier;
+
+        if delegate_key.lms_tree_identifier != lms_tree_identifier.as_slice()
+            || &delegate_key.lms_parameter != parameters[0].get_lms_parameter()
+            || &delegate_key.lmots_parameter != parameters[0].get_lmots_parameter()
+        {
+            return Err(Error::new());
+        }
+
+        Ok(Self {
+            delegation,
+            signing_key,
+        })
+    }
+
+    pub fn delegation(&self) -> &Delegation<H> {
+        &self.delegation
+    }
+
+    /**
+     * Returns the current private key of the delegate, e.g. to persist it after a signature.
+     */
+    pub fn signing_key(&self) -> &SigningKey<H> {
+        &self.signing_key
+    }
+
+    /**
+     * Generate a [`Signature`] with the private key of the delegate and prepend the delegation.
+     */
+    pub fn try_sign_with_aux(
+        &mut self,
+        msg: &[u8],
+        aux_data: Option<&mut &mut [u8]>,
+    ) -> Result<Signature, Error> {
+        let signature = self.signing_key.try_sign_with_aux(msg, aux_data)?;
+        let (level, lower_levels) = signature.as_ref().split_at(4);
+        let level = u32::from_be_bytes(level.try_into().unwrap()) + 1;
+
+        let mut bytes = ArrayVec::<[u8; MAX_HSS_SIGNATURE_LENGTH]>::new();
+        bytes.extend_from_slice(&level.to_be_bytes());
+        bytes.extend_from_slice(self.delegation.as_slice());
+        bytes.extend_from_slice(lower_levels);
+
+        Ok(Signature {
+            bytes,
+            #[cfg(feature = "verbose")]
+            hash_iterations: signature.hash_iterations,
+        })
+    }
+}
+
+impl<H: HashChain> SignerMut<Signature> for DelegatedSigningKey<H> {
+    fn try_sign(&mut self, msg: &[u8]) -> Result<Signature, Error> {
+        self.try_sign_with_aux(msg, None)
+    }
+}
+
+impl<H: HashChain> fmt::Debug for DelegatedSigningKey<H> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DelegatedSigningKey")
+            .field("delegation", &self.delegation)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(all(test, feature = "sha256"))]
+mod tests {
+    use super::{DelegatedSigningKey, Delegation};
+
+    use crate::{
+        hss::{hss_keygen, hss_verify, reference_impl_private_key::Seed},
+        signature::SignerMut,
+        util::helper::test_helper::gen_random_seed,
+        HssParameter, LmotsAlgorithm, LmsAlgorithm, Sha256_192, SignatureInfo,
+    };
+
+    type H = Sha256_192;
+
+    #[test]
+    fn delegate_lower_levels() {
+        let parameter = HssParameter::<H>::new(LmotsAlgorithm::LmotsW4, LmsAlgorithm::LmsH2);
+        let (mut top_signing_key, verifying_key) =
+            hss_keygen::<H>(&[parameter; 3], &Seed::default(), None).unwrap();
+        top_signing_key.try_sign(b"used").unwrap();
+
+        let (delegate_signing_key, delegate_verifying_key) =
+            hss_keygen::<H>(&[parameter; 2], &gen_random_seed::<H>(), None).unwrap();
+
+        // The first top leaf has already signed a child tree, so the second one is used.
+        let delegation = top_signing_key
+            .delegate(&delegate_verifying_key, None)
+            .unwrap();
+        assert_eq!(top_signing_key.signature_range().unwrap(), 32..64);
+        assert!(delegation.verify(&verifying_key).is_ok());
+        assert!(delegation.verify(&delegate_verifying_key).is_err());
+
+        let delegation = Delegation::from_bytes(delegation.as_slice()).unwrap();
+        let start = delegate_signing_key.signature_range().unwrap().start as u32;
+        let mut delegate =
+            DelegatedSigningKey::new(delegation, delegate_signing_key, &verifying_key).unwrap();
+
+        for expected_leaf in start..start + 2 {
+            let signature = delegate.try_sign(b"message").unwrap();
+            assert!(
+                hss_verify::<H>(b"message", signature.as_ref(), verifying_key.as_slice()).is_ok()
+            );
+            assert!(
+                hss_verify::<H>(b"other", signature.as_ref(), verifying_key.as_slice()).is_err()
+            );
+
+            let info = SignatureInfo::<H>::parse(signature.as_ref()).unwrap();
+            assert_eq!(info.levels().len(), 3);
+            assert_eq!(info.levels()[0].lms_leaf_identifier, 1);
+            assert_eq!(info.levels()[2].lms_leaf_identifier, expected_leaf);
+        }
+    }
+
+    #[test]
+    fn reject_mismatching_delegates() {
+        let parameter = HssParameter::<H>::new(LmotsAlgorithm::LmotsW4, LmsAlgorithm::LmsH2);
+        let (mut top_signing_key, verifying_key) =
+            hss_keygen::<H>(&[parameter; 2], &Seed::default(), None).unwrap();
+        let (delegate_signing_key, delegate_verifying_key) =
+            hss_keygen::<H>(&[parameter], &gen_random_seed::<H>(), None).unwrap();
+        let (other_signing_key, other_verifying_key) =
+            hss_keygen::<H>(&[parameter; 2], &gen_random_seed::<H>(), None).unwrap();
+
+        assert!(top_signing_key
+            .delegate(&other_verifying_key, None)
+            .is_err());
+        assert!(Delegation::<H>::from_bytes(&[0u8; 16]).is_err());
+
+        // The private key of the delegate must have one level less than the delegating key.
+        let delegation = top_signing_key
+            .delegate(&delegate_verifying_key, None)
+            .unwrap();
+        assert!(DelegatedSigningKey::new(delegation, other_signing_key, &verifying_key).is_err());
+
+        // The private key must belong to the public key in the delegation.
+        let (stranger_signing_key, _) =
+            hss_keygen::<H>(&[parameter], &gen_random_seed::<H>(), None).unwrap();
+        let delegation = top_signing_key
+            .delegate(&delegate_verifying_key, None)
+            .unwrap();
+        assert!(
+            DelegatedSigningKey::new(delegation.clone(), stranger_signing_key, &verifying_key)
+                .is_err()
+        );
+        assert!(
+            DelegatedSigningKey::new(delegation, delegate_signing_key.clone(), &verifying_key)
+                .is_ok()
+        );
+
+        // The delegation must be signed by the delegating key.
+        let (mut forger, _) =
+            hss_keygen::<H>(&[parameter; 2], &gen_random_seed::<H>(), None).unwrap();
+        let delegation = forger.delegate(&delegate_verifying_key, None).unwrap();
+        assert!(
+            DelegatedSigningKey::new(delegation, delegate_signing_key, &verifying_key).is_err()
+        );
+
+        // All leafs of the top tree are used afterwards.
+        let range = top_signing_key.signature_range().unwrap();
+        for _ in 0..(range.end - range.start) / 4 {
+            top_signing_key
+                .delegate(&delegate_verifying_key, None)
+                .unwrap();
+        }
+        assert!(top_signing_key
+            .delegate(&delegate_verifying_key, None)
+            .is_err());
+    }
+}
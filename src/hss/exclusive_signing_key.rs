@@ -0,0 +1,209 @@
+#[cfg(any(test, feature = "std"))]
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{
+    hasher::HashChain,
+    hss::{reference_impl_private_key::ReferenceImplPrivateKey, SigningKey},
+    signature::{Error, SignerMut},
+    Signature,
+};
+
+/**
+ * Signer, which owns a range of the signatures of a private key and can't be duplicated.
+ *
+ * A [`SigningKey`] can be cloned and its bytes can be overwritten, e.g. by an old backup, which
+ * both lead to the reuse of leafs. This type implements neither `Clone` nor any way to write its
+ * state. A private key is only shared by splitting the remaining signatures into disjoint ranges
+ * with [`ExclusiveSigningKey::split_off`], each owned by an independent handle. The raw private
+ * key can only be read with [`ExclusiveSigningKey::dangerous_export_signing_key`].
+ *
+ * ```
+ * use hbs_lms::{keygen, ExclusiveSigningKey, HssParameter, LmotsAlgorithm, LmsAlgorithm};
+ * use hbs_lms::{signature::SignerMut, verify, Seed, Sha256_256};
+ *
+ * let parameters = [HssParameter::new(LmotsAlgorithm::LmotsW4, LmsAlgorithm::LmsH5)];
+ * let (signing_key, verifying_key) =
+ *     keygen::<Sha256_256>(&parameters, &Seed::default(), None).unwrap();
+ *
+ * let mut first = ExclusiveSigningKey::new(signing_key).unwrap();
+ * let remaining = first.remaining();
+ * let mut second = first.split_off(16).unwrap();
+ * assert_eq!((first.remaining(), second.remaining()), (16, remaining - 16));
+ *
+ * let signature = second.try_sign(b"message").unwrap();
+ * assert!(verify::<Sha256_256>(b"message", signature.as_ref(), verifying_key.as_slice()).is_ok());
+ * ```
+ */
+pub struct ExclusiveSigningKey<H: HashChain> {
+    signing_key: SigningKey<H>,
+}
+
+impl<H: HashChain> ExclusiveSigningKey<H> {
+    /**
+     * Takes ownership of all remaining signatures of `signing_key`. Neither `signing_key` nor a
+     * copy of it must be used for signing afterwards.
+     */
+    pub fn new(signing_key: SigningKey<H>) -> Result<Self, Error> {
+        signing_key.signature_range()?;
+
+        Ok(Self { signing_key })
+    }
+
+    /**
+     * Returns the number of signatures left in the range of this handle.
+     */
+    pub fn remaining(&self) -> u64 {
+        // The private key is wiped after its last signature.
+        self.signing_key
+            .signature_range()
+            .map_or(0, |range| range.end - range.start)
+    }
+
+    /**
+     * Splits the remaining signatures at `at`. This handle keeps the first `at` signatures and the
+     * returned handle owns the others.
+     *
+     * Returns an error, if one of the handles would be left without signatures.
+     *
+     * # Arguments
+     * * `at` - The number of signatures kept by this handle.
+     */
+    pub fn split_off(&mut self, at: u64) -> Result<Self, Error> {
+        let mut private_key =
+            ReferenceImplPrivateKey::<H>::from_binary_representation(self.signing_key.as_slice())
+                .map_err(|_| Error::new())?;
+        let next = private_key.compressed_used_leafs_indexes.count();
+
+        let other = private_key
+            .split_off(next.checked_add(at).ok_or_else(Error::new)?)
+            .map_err(|_| Error::new())?;
+        let other = SigningKey::from_private_key(&other)?;
+        self.signing_key = SigningKey::from_private_key(&private_key)?;
+
+        Ok(Self { signing_key: other })
+    }
+
+    /**
+     * Splits the remaining signatures into `count` handles, in ascending order of their ranges.
+     * The ranges are aligned to subtrees, see [`SigningKey::partition`].
+     *
+     * # Arguments
+     * * `count` - The number of handles.
+     */
+    #[cfg(any(test, feature = "std"))]
+    pub fn split(self, count: usize) -> Result<Vec<Self>, Error> {
+        Ok(self
+            .signing_key
+            .partition(count)?
+            .into_iter()
+            .map(|signing_key| Self { signing_key })
+            .collect())
+    }
+
+    /**
+     * Returns a copy of the raw private key, including the range of this handle.
+     *
+     * Signing with the copy and with this handle, or with more than one copy, reuses leafs and
+     * breaks the security of the key. This is only meant for persisting the state of the handle,
+     * e.g. after each signature.
+     */
+    pub fn dangerous_export_signing_key(&self) -> SigningKey<H> {
+        self.signing_key.clone()
+    }
+
+    /**
+     * Generate a [`Signature`], if signatures are left in the range of this handle.
+     */
+    pub fn try_sign_with_aux(
+        &mut self,
+        msg: &[u8],
+        aux_data: Option<&mut &mut [u8]>,
+    ) -> Result<Signature, Error> {
+        self.signing_key.try_sign_with_aux(msg, aux_data)
+    }
+}
+
+impl<H: HashChain> SignerMut<Signature> for ExclusiveSigningKey<H> {
+    fn try_sign(&mut self, msg: &[u8]) -> Result<Signature, Error> {
+        self.try_sign_with_aux(msg, None)
+    }
+}
+
+impl<H: HashChain> fmt::Debug for ExclusiveSigningKey<H> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExclusiveSigningKey")
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(all(test, feature = "sha256"))]
+mod tests {
+    use super::ExclusiveSigningKey;
+
+    use crate::{
+        hss::{hss_keygen, reference_impl_private_key::Seed},
+        signature::SignerMut,
+        HssParameter, LmotsAlgorithm, LmsAlgorithm, Sha256_192, SignatureInfo,
+    };
+
+    type H = Sha256_192;
+
+    #[test]
+    fn split_into_disjoint_ranges() {
+        let parameters = [
+            HssParameter::<H>::new(LmotsAlgorithm::LmotsW4, LmsAlgorithm::LmsH2),
+            HssParameter::<H>::new(LmotsAlgorithm::LmotsW4, LmsAlgorithm::LmsH2),
+        ];
+        let (mut signing_key, _) = hss_keygen::<H>(&parameters, &Seed::default(), None).unwrap();
+        // Start with the second signature, unless the pairwise consistency test used the first.
+        if signing_key.signature_range().unwrap().start == 0 {
+            signing_key.try_sign(b"used").unwrap();
+        }
+
+        let signer = ExclusiveSigningKey::new(signing_key).unwrap();
+        assert_eq!(signer.remaining(), 15);
+
+        let mut handles = signer.split(4).unwrap();
+        // The ranges are aligned to the trees of the second level.
+        let sizes: [u64; 4] = [3, 4, 4, 4];
+        let mut expected_counter = 1;
+
+        for (handle, &size) in handles.iter_mut().zip(sizes.iter()) {
+            assert_eq!(handle.remaining(), size);
+
+            for _ in 0..size {
+                let signature = handle.try_sign(b"message").unwrap();
+                let info = SignatureInfo::<H>::parse(signature.as_ref()).unwrap();
+                assert_eq!(info.signature_counter(), Some(expected_counter));
+                expected_counter += 1;
+            }
+
+            assert_eq!(handle.remaining(), 0);
+            assert!(handle.try_sign(b"message").is_err());
+            assert!(handle.split_off(1).is_err());
+        }
+    }
+
+    #[test]
+    fn reject_empty_ranges() {
+        let parameters = [HssParameter::<H>::new(
+            LmotsAlgorithm::LmotsW4,
+            LmsAlgorithm::LmsH2,
+        )];
+        let (signing_key, _) = hss_keygen::<H>(&parameters, &Seed::default(), None).unwrap();
+        let mut signer = ExclusiveSigningKey::new(signing_key).unwrap();
+        let remaining = signer.remaining();
+
+        assert!(signer.split_off(0).is_err());
+        assert!(signer.split_off(remaining).is_err());
+        assert_eq!(signer.split_off(remaining - 1).unwrap().remaining(), 1);
+        assert_eq!(signer.remaining(), remaining - 1);
+
+        // The exported private key keeps the range of the handle.
+        let exported = ExclusiveSigningKey::new(signer.dangerous_export_signing_key()).unwrap();
+        assert_eq!(exported.remaining(), remaining - 1);
+
+        assert!(signer.split(remaining as usize).is_err());
+    }
+}
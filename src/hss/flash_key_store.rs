@@ -0,0 +1,666 @@
+use core::{
+    convert::{TryFrom, TryInto},
+    marker::PhantomData,
+    ops::Range,
+};
+
+use embedded_storage::nor_flash::NorFlash;
+use tinyvec::ArrayVec;
+#[cfg(feature = "zeroize")]
+use {crate::util::helper::zeroize_array_vec, zeroize::Zeroize};
+
+use crate::{
+    constants::REF_IMPL_MAX_PRIVATE_KEY_SIZE,
+    hasher::HashChain,
+    hss::{hss_sign, SigningKey},
+    signature::{Error, SignerMut},
+    Signature,
+};
+
+const RECORD_SIZE: usize = 64;
+const RECORD_MAGIC: [u8; 4] = *b"LMSK";
+const RECORD_SEQUENCE_NUMBER: usize = 4;
+const RECORD_KEY_LENGTH: usize = 8;
+const RECORD_KEY: usize = 12;
+const RECORD_CHECKSUM_SIZE: usize = 4;
+
+const ERASED: u8 = 0xff;
+
+/// Private key of a record, wiped on drop with the `zeroize` feature.
+struct PrivateKeyBytes(ArrayVec<[u8; REF_IMPL_MAX_PRIVATE_KEY_SIZE]>);
+
+#[cfg(feature = "zeroize")]
+impl Drop for PrivateKeyBytes {
+    fn drop(&mut self) {
+        zeroize_array_vec(&mut self.0);
+    }
+}
+
+/// Raw record as stored in a slot, wiped on drop with the `zeroize` feature.
+struct Record([u8; RECORD_SIZE]);
+
+#[cfg(feature = "zeroize")]
+impl Drop for Record {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+/**
+ * Private key state kept in a region of a NOR flash.
+ *
+ * Every state update is written as a new record into the next free slot of the region, instead of
+ * rewriting the previous one. Each record consists of a magic, a sequence number, the private key
+ * and a CRC-32 over all of these. When all slots of an erase sector are used, the next sector is
+ * erased and used. The sector holding the latest committed record is never erased, but skipped
+ * if the slots wrap around to it after failed writes.
+ *
+ * On [`FlashKeyStore::open`], the valid record with the highest sequence number is recovered. As the
+ * new state is committed before a signature is returned, a power cut at any point never leads to
+ * the reuse of a leaf of a released signature. Records which were torn by a power cut fail the
+ * checksum and are skipped.
+ *
+ * The region must be aligned to `ERASE_SIZE`, span at least two erase sectors, and `READ_SIZE` as
+ * well as `WRITE_SIZE` must divide 64. Partitioned private keys, which carry their range of the
+ * signature counter, don't fit into a record and are rejected.
+ */
+pub struct FlashKeyStore<F: NorFlash, H: HashChain> {
+    flash: F,
+    region: Range<u32>,
+    private_key: Option<PrivateKeyBytes>,
+    sequence_number: u32,
+    latest_slot: Option<u32>,
+    next_slot: u32,
+    phantom_data: PhantomData<H>,
+}
+
+impl<F: NorFlash, H: HashChain> FlashKeyStore<F, H> {
+    /**
+     * Open the key store in `region` of `flash` and recover the latest committed private key.
+     *
+     * An empty or blank region is accepted, use [`FlashKeyStore::format`] to store an initial key.
+     */
+    pub fn open(flash: F, region: Range<u32>) -> Result<Self, Error> {
+        let erase_size = F::ERASE_SIZE as u32;
+
+        if F::READ_SIZE == 0
+            || F::WRITE_SIZE == 0
+            || RECORD_SIZE % F::READ_SIZE != 0
+            || RECORD_SIZE % F::WRITE_SIZE != 0
+            || F::ERASE_SIZE % RECORD_SIZE != 0
+            || region.start % erase_size != 0
+            || region.end % erase_size != 0
+            || region.start >= region.end
+            || (region.end - region.start) / erase_size < 2
+            || region.end as usize > flash.capacity()
+        {
+            return Err(Error::new());
+        }
+
+        let mut key_store = Self {
+            flash,
+            next_slot: region.start,
+            region,
+            private_key: None,
+            sequence_number: 0,
+            latest_slot: None,
+            phantom_data: PhantomData,
+        };
+
+        key_store.recover().map_err(|_| Error::new())?;
+
+        Ok(key_store)
+    }
+
+    /**
+     * Erase the whole region and store `signing_key` as the initial private key.
+     *
+     * Any private key previously kept in the region is lost. This is meant for provisioning only.
+     */
+    pub fn format(&mut self, signing_key: &SigningKey<H>) -> Result<(), Error> {
+        self.flash
+            .erase(self.region.start, self.region.end)
+            .map_err(|_| Error::new())?;
+
+        self.private_key = None;
+        self.sequence_number = 0;
+        self.latest_slot = None;
+        self.next_slot = self.region.start;
+
+        self.store(signing_key.as_slice()).map_err(|_| Error::new())
+    }
+
+    /**
+     * Returns whether a private key was recovered or stored.
+     */
+    pub fn is_empty(&self) -> bool {
+        self.private_key.is_none()
+    }
+
+    /**
+     * Returns the sequence number of the latest committed record.
+     */
+    pub fn sequence_number(&self) -> u32 {
+        self.sequence_number
+    }
+
+    pub fn get_lifetime(&self) -> Result<u64, Error> {
+        let private_key = self.private_key.as_ref().ok_or_else(Error::new)?;
+
+        SigningKey::<H>::from_bytes(private_key.0.as_slice())?.get_lifetime()
+    }
+
+    /**
+     * Generate a [`Signature`]. The advanced private key is committed to the flash, before the
+     * signature is returned.
+     */
+    pub fn try_sign_with_aux(
+        &mut self,
+        msg: &[u8],
+        aux_data: Option<&mut &mut [u8]>,
+    ) -> Result<Signature, Error> {
+        let private_key = PrivateKeyBytes(self.private_key.as_ref().ok_or_else(Error::new)?.0);
+        let mut private_key_update_function = |new_key: &[u8]| self.store(new_key);
+
+        hss_sign::<H>(
+            msg,
+            private_key.0.as_slice(),
+            &mut private_key_update_function,
+            aux_data,
+        )
+    }
+
+    /**
+     * Returns the underlying flash.
+     */
+    pub fn release(self) -> F {
+        self.flash
+    }
+
+    fn recover(&mut self) -> Result<(), ()> {
+        let mut latest: Option<(u32, u32, PrivateKeyBytes)> = None;
+
+        let mut slot = self.region.start;
+        while slot < self.region.end {
+            let record = self.read_record(slot)?;
+
+            if let Some((sequence_number, private_key)) = parse_record(&record) {
+                if latest
+                    .as_ref()
+                    .map_or(true, |(latest_sequence_number, _, _)| {
+                        sequence_number > *latest_sequence_number
+                    })
+                {
+                    latest = Some((sequence_number, slot, private_key));
+                }
+            }
+            slot += RECORD_SIZE as u32;
+        }
+
+        let (sequence_number, latest_slot, private_key) = match latest {
+            Some(latest) => latest,
+            None => return Ok(()),
+        };
+
+        self.private_key = Some(private_key);
+        self.sequence_number = sequence_number;
+        self.latest_slot = Some(latest_slot);
+
+        // Skip slots after the latest record, which were written partially.
+        let mut next_slot = self.following_slot(latest_slot);
+        while !self.is_sector_start(next_slot) && !self.is_blank(next_slot)? {
+            next_slot = self.following_slot(next_slot);
+        }
+        self.next_slot = next_slot;
+
+        Ok(())
+    }
+
+    fn store(&mut self, private_key: &[u8]) -> Result<(), ()> {
+        let private_key = PrivateKeyBytes(ArrayVec::try_from(private_key).map_err(|_| ())?);
+        let sequence_number = self.sequence_number.checked_add(1).ok_or(())?;
+        let record = build_record(sequence_number, &private_key.0);
+
+        let slot = loop {
+            let slot = self.next_slot;
+
+            if self.is_sector_start(slot) {
+                let sector = slot..slot + F::ERASE_SIZE as u32;
+                if self
+                    .latest_slot
+                    .map_or(false, |latest_slot| sector.contains(&latest_slot))
+                {
+                    // Failed writes used up all other slots, the next sector is the oldest one.
+                    self.next_slot = if sector.end >= self.region.end {
+                        self.region.start
+                    } else {
+                        sector.end
+                    };
+                    continue;
+                }
+                self.flash.erase(sector.start, sector.end).map_err(|_| ())?;
+            } else if !self.is_blank(slot)? {
+                self.next_slot = self.following_slot(slot);
+                continue;
+            }
+
+            // A failed write leaves the slot in an undefined state, so it is never used again.
+            self.next_slot = self.following_slot(slot);
+            break slot;
+        };
+
+        self.flash.write(slot, &record.0).map_err(|_| ())?;
+        if self.read_record(slot)?.0 != record.0 {
+            return Err(());
+        }
+
+        self.private_key = Some(private_key);
+        self.sequence_number = sequence_number;
+        self.latest_slot = Some(slot);
+
+        Ok(())
+    }
+
+    fn read_record(&mut self, slot: u32) -> Result<Record, ()> {
+        let mut record = Record([0u8; RECORD_SIZE]);
+        self.flash.read(slot, &mut record.0).map_err(|_| ())?;
+        Ok(record)
+    }
+
+    fn is_blank(&mut self, slot: u32) -> Result<bool, ()> {
+        Ok(self.read_record(slot)?.0.iter().all(|&byte| byte == ERASED))
+    }
+
+    fn is_sector_start(&self, slot: u32) -> bool {
+        (slot - self.region.start) % F::ERASE_SIZE as u32 == 0
+    }
+
+    fn following_slot(&self, slot: u32) -> u32 {
+        let slot = slot + RECORD_SIZE as u32;
+        if slot >= self.region.end {
+            self.region.start
+        } else {
+            slot
+        }
+    }
+}
+
+impl<F: NorFlash, H: HashChain> SignerMut<Signature> for FlashKeyStore<F, H> {
+    fn try_sign(&mut self, msg: &[u8]) -> Result<Signature, Error> {
+        self.try_sign_with_aux(msg, None)
+    }
+}
+
+fn build_record(sequence_number: u32, private_key: &[u8]) -> Record {
+    let mut record = Record([ERASED; RECORD_SIZE]);
+    let checksum_start = RECORD_KEY + private_key.len();
+
+    record.0[..RECORD_SEQUENCE_NUMBER].copy_from_slice(&RECORD_MAGIC);
+    record.0[RECORD_SEQUENCE_NUMBER..RECORD_KEY_LENGTH]
+        .copy_from_slice(&sequence_number.to_be_bytes());
+    record.0[RECORD_KEY_LENGTH..RECORD_KEY]
+        .copy_from_slice(&(private_key.len() as u32).to_be_bytes());
+    record.0[RECORD_KEY..checksum_start].copy_from_slice(private_key);
+
+    let checksum = crc32(&record.0[..checksum_start]);
+    record.0[checksum_start..checksum_start + RECORD_CHECKSUM_SIZE]
+        .copy_from_slice(&checksum.to_be_bytes());
+
+    record
+}
+
+fn parse_record(record: &Record) -> Option<(u32, PrivateKeyBytes)> {
+    let record = &record.0;
+
+    if record[..RECORD_SEQUENCE_NUMBER] != RECORD_MAGIC {
+        return None;
+    }
+
+    let sequence_number = u32::from_be_bytes(
+        record[RECORD_SEQUENCE_NUMBER..RECORD_KEY_LENGTH]
+            .try_into()
+            .ok()?,
+    );
+    let key_length =
+        u32::from_be_bytes(record[RECORD_KEY_LENGTH..RECORD_KEY].try_into().ok()?) as usize;
+
+    if key_length > REF_IMPL_MAX_PRIVATE_KEY_SIZE {
+        return None;
+    }
+
+    let checksum_start = RECORD_KEY + key_length;
+    let checksum = u32::from_be_bytes(
+        record[checksum_start..checksum_start + RECORD_CHECKSUM_SIZE]
+            .try_into()
+            .ok()?,
+    );
+
+    if checksum != crc32(&record[..checksum_start]) {
+        return None;
+    }
+
+    ArrayVec::try_from(&record[RECORD_KEY..checksum_start])
+        .ok()
+        .map(|private_key| (sequence_number, PrivateKeyBytes(private_key)))
+}
+
+/// CRC-32 (IEEE 802.3), computed bitwise as only a few bytes are checked per record.
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xffff_ffffu32;
+
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xedb8_8320 & mask);
+        }
+    }
+
+    !crc
+}
+
+#[cfg(all(test, feature = "sha256"))]
+mod tests {
+    use super::{crc32, FlashKeyStore};
+
+    use embedded_storage::nor_flash::{
+        check_erase, check_read, check_write, ErrorType, NorFlash, NorFlashErrorKind, ReadNorFlash,
+    };
+
+    use crate::{
+        hasher::sha256::Sha256_256,
+        hss::{hss_keygen, hss_verify},
+        signature::SignerMut,
+        util::helper::test_helper::gen_random_seed,
+        HssParameter, LmotsAlgorithm, LmsAlgorithm,
+    };
+
+    type H = Sha256_256;
+
+    const SECTOR_SIZE: usize = 128;
+    const SECTOR_COUNT: usize = 3;
+    const FLASH_SIZE: usize = SECTOR_SIZE * SECTOR_COUNT;
+
+    /// Simulated NOR flash, which loses power after `power_budget` bytes were written or erased.
+    struct SimFlash {
+        data: [u8; FLASH_SIZE],
+        power_budget: Option<usize>,
+        power_lost: bool,
+    }
+
+    impl SimFlash {
+        fn new() -> Self {
+            Self {
+                data: [0u8; FLASH_SIZE],
+                power_budget: None,
+                power_lost: false,
+            }
+        }
+
+        fn power_cycle(&self) -> Self {
+            Self {
+                data: self.data,
+                power_budget: None,
+                power_lost: false,
+            }
+        }
+
+        /// Returns how many bytes of an operation can be performed before the power is lost.
+        fn consume(&mut self, len: usize) -> usize {
+            match self.power_budget {
+                Some(budget) => {
+                    let performed = budget.min(len);
+                    self.power_budget = Some(budget - performed);
+                    self.power_lost |= performed < len;
+                    performed
+                }
+                None => len,
+            }
+        }
+    }
+
+    impl ErrorType for SimFlash {
+        type Error = NorFlashErrorKind;
+    }
+
+    impl ReadNorFlash for SimFlash {
+        const READ_SIZE: usize = 4;
+
+        fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+            check_read(self, offset, bytes.len())?;
+            if self.power_lost {
+                return Err(NorFlashErrorKind::Other);
+            }
+            bytes.copy_from_slice(&self.data[offset as usize..offset as usize + bytes.len()]);
+            Ok(())
+        }
+
+        fn capacity(&self) -> usize {
+            FLASH_SIZE
+        }
+    }
+
+    impl NorFlash for SimFlash {
+        const WRITE_SIZE: usize = 4;
+        const ERASE_SIZE: usize = SECTOR_SIZE;
+
+        fn erase(&mut self, from: u32, to: u32) -> Result<(), Self::Error> {
+            check_erase(self, from, to)?;
+            let len = (to - from) as usize;
+            let performed = self.consume(len);
+            for byte in &mut self.data[from as usize..from as usize + performed] {
+                *byte = 0xff;
+            }
+            if performed < len {
+                return Err(NorFlashErrorKind::Other);
+            }
+            Ok(())
+        }
+
+        fn write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Self::Error> {
+            check_write(self, offset, bytes.len())?;
+            let performed = self.consume(bytes.len());
+            for (byte, &value) in self.data[offset as usize..]
+                .iter_mut()
+                .zip(bytes[..performed].iter())
+            {
+                *byte &= value;
+            }
+            if performed < bytes.len() {
+                return Err(NorFlashErrorKind::Other);
+            }
+            Ok(())
+        }
+    }
+
+    fn leaf_index(signature: &[u8]) -> u32 {
+        // Single level: Nspk followed by q of the LMS signature
+        u32::from_be_bytes([signature[4], signature[5], signature[6], signature[7]])
+    }
+
+    #[test]
+    fn crc32_check_value() {
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+    }
+
+    #[test]
+    fn reject_invalid_region() {
+        assert!(FlashKeyStore::<_, H>::open(SimFlash::new(), 0..SECTOR_SIZE as u32).is_err());
+        assert!(FlashKeyStore::<_, H>::open(SimFlash::new(), 4..FLASH_SIZE as u32).is_err());
+        assert!(
+            FlashKeyStore::<_, H>::open(SimFlash::new(), 0..(FLASH_SIZE + SECTOR_SIZE) as u32)
+                .is_err()
+        );
+
+        let key_store = FlashKeyStore::<_, H>::open(SimFlash::new(), 0..FLASH_SIZE as u32).unwrap();
+        assert!(key_store.is_empty());
+    }
+
+    #[test]
+    fn rotate_slots() {
+        let seed = gen_random_seed::<H>();
+        let (signing_key, verifying_key) = hss_keygen::<H>(
+            &[HssParameter::new(
+                LmotsAlgorithm::LmotsW2,
+                LmsAlgorithm::LmsH5,
+            )],
+            &seed,
+            None,
+        )
+        .unwrap();
+        let lifetime = signing_key.get_lifetime().unwrap();
+        let start = signing_key.signature_range().unwrap().start as u32;
+
+        let mut key_store = FlashKeyStore::open(SimFlash::new(), 0..FLASH_SIZE as u32).unwrap();
+        key_store.format(&signing_key).unwrap();
+
+        let message = [42u8; 16];
+        for index in 0..2 * (FLASH_SIZE / super::RECORD_SIZE) as u32 {
+            let signature = key_store.try_sign(&message).unwrap();
+
+            assert!(
+                hss_verify::<H>(&message, signature.as_ref(), verifying_key.as_slice()).is_ok()
+            );
+            assert_eq!(leaf_index(signature.as_ref()), start + index);
+
+            let flash = key_store.release().power_cycle();
+            key_store = FlashKeyStore::open(flash, 0..FLASH_SIZE as u32).unwrap();
+
+            assert_eq!(key_store.sequence_number(), index + 2);
+            assert_eq!(
+                key_store.get_lifetime().unwrap(),
+                lifetime - index as u64 - 1
+            );
+        }
+    }
+
+    #[test]
+    fn power_failure_never_reuses_leaf() {
+        let seed = gen_random_seed::<H>();
+        let (signing_key, verifying_key) = hss_keygen::<H>(
+            &[HssParameter::new(
+                LmotsAlgorithm::LmotsW2,
+                LmsAlgorithm::LmsH5,
+            )],
+            &seed,
+            None,
+        )
+        .unwrap();
+
+        let message = [42u8; 16];
+        let slots_per_sector = SECTOR_SIZE / super::RECORD_SIZE;
+        // The last signature before the cut moves the state into a new sector, so the cut hits
+        // the erase as well as the write.
+        let operation_size = SECTOR_SIZE + super::RECORD_SIZE;
+
+        for power_budget in (0..=operation_size).step_by(11).chain([operation_size]) {
+            let mut key_store = FlashKeyStore::open(SimFlash::new(), 0..FLASH_SIZE as u32).unwrap();
+            key_store.format(&signing_key).unwrap();
+
+            let mut released_leafs = [false; 32];
+            for _ in 0..slots_per_sector - 1 {
+                let signature = key_store.try_sign(&message).unwrap();
+                released_leafs[leaf_index(signature.as_ref()) as usize] = true;
+            }
+
+            key_store.flash.power_budget = Some(power_budget);
+            match key_store.try_sign(&message) {
+                Ok(signature) => {
+                    assert_eq!(power_budget, operation_size);
+                    released_leafs[leaf_index(signature.as_ref()) as usize] = true;
+                }
+                Err(_) => assert!(power_budget < operation_size),
+            }
+
+            let flash = key_store.release().power_cycle();
+            let mut key_store = FlashKeyStore::<_, H>::open(flash, 0..FLASH_SIZE as u32).unwrap();
+
+            for _ in 0..FLASH_SIZE / super::RECORD_SIZE {
+                let signature = key_store.try_sign(&message).unwrap();
+                let leaf_index = leaf_index(signature.as_ref()) as usize;
+
+                assert!(!released_leafs[leaf_index]);
+                released_leafs[leaf_index] = true;
+                assert!(
+                    hss_verify::<H>(&message, signature.as_ref(), verifying_key.as_slice()).is_ok()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn failed_write_is_skipped_without_power_cycle() {
+        let seed = gen_random_seed::<H>();
+        let (signing_key, _) = hss_keygen::<H>(
+            &[HssParameter::new(
+                LmotsAlgorithm::LmotsW2,
+                LmsAlgorithm::LmsH5,
+            )],
+            &seed,
+            None,
+        )
+        .unwrap();
+        let start = signing_key.signature_range().unwrap().start as u32;
+
+        let message = [42u8; 16];
+        let mut key_store = FlashKeyStore::open(SimFlash::new(), 0..FLASH_SIZE as u32).unwrap();
+        key_store.format(&signing_key).unwrap();
+
+        key_store.flash.power_budget = Some(super::RECORD_SIZE / 2);
+        assert!(key_store.try_sign(&message).is_err());
+        key_store.flash = key_store.flash.power_cycle();
+
+        let signature = key_store.try_sign(&message).unwrap();
+        assert_eq!(leaf_index(signature.as_ref()), start);
+
+        let flash = key_store.release().power_cycle();
+        let key_store = FlashKeyStore::<_, H>::open(flash, 0..FLASH_SIZE as u32).unwrap();
+        assert_eq!(key_store.sequence_number(), 2);
+        assert_eq!(key_store.next_slot, 3 * super::RECORD_SIZE as u32);
+    }
+
+    #[test]
+    fn skip_sector_of_latest_record_after_failed_writes() {
+        let seed = gen_random_seed::<H>();
+        let (signing_key, verifying_key) = hss_keygen::<H>(
+            &[HssParameter::new(
+                LmotsAlgorithm::LmotsW2,
+                LmsAlgorithm::LmsH5,
+            )],
+            &seed,
+            None,
+        )
+        .unwrap();
+        let start = signing_key.signature_range().unwrap().start as u32;
+
+        let message = [42u8; 16];
+        let mut key_store = FlashKeyStore::open(SimFlash::new(), 0..FLASH_SIZE as u32).unwrap();
+        key_store.format(&signing_key).unwrap();
+
+        // Fail the writes into all other slots, until the slots wrap around to the sector of the
+        // latest record.
+        for _ in 1..FLASH_SIZE / super::RECORD_SIZE {
+            let erase_size = if key_store.next_slot as usize % SECTOR_SIZE == 0 {
+                SECTOR_SIZE
+            } else {
+                0
+            };
+            key_store.flash.power_budget = Some(erase_size + super::RECORD_SIZE / 2);
+            assert!(key_store.try_sign(&message).is_err());
+            key_store.flash = key_store.flash.power_cycle();
+        }
+        assert_eq!(key_store.next_slot, 0);
+
+        let signature = key_store.try_sign(&message).unwrap();
+        assert_eq!(leaf_index(signature.as_ref()), start);
+        assert!(hss_verify::<H>(&message, signature.as_ref(), verifying_key.as_slice()).is_ok());
+        assert_eq!(key_store.latest_slot, Some(SECTOR_SIZE as u32));
+
+        let flash = key_store.release().power_cycle();
+        let mut key_store = FlashKeyStore::<_, H>::open(flash, 0..FLASH_SIZE as u32).unwrap();
+        assert_eq!(key_store.sequence_number(), 2);
+
+        let signature = key_store.try_sign(&message).unwrap();
+        assert_eq!(leaf_index(signature.as_ref()), start + 1);
+    }
+}
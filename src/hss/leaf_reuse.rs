@@ -0,0 +1,302 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::{
+    convert::{TryFrom, TryInto},
+    marker::PhantomData,
+};
+use tinyvec::ArrayVec;
+
+use crate::{
+    constants::{LmsTreeIdentifier, MAX_HASH_SIZE, MAX_LMS_PUBLIC_KEY_LENGTH},
+    hasher::HashChain,
+    hss::{definitions::InMemoryHssPublicKey, signature_info::SignatureInfo, VerifyingKey},
+    signature::Error,
+};
+
+/**
+ * How a one-time key was reused.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReuseKind {
+    /// The one-time key signed two distinct messages, or the same message with distinct
+    /// randomizers.
+    DistinctMessages,
+    /// The one-time key of an intermediate tree signed two distinct child public keys, i.e. the
+    /// child tree was regenerated from a reused leaf.
+    DistinctChildPublicKeys,
+}
+
+/**
+ * Two signatures using the same one-time key `(I, q)` in different ways.
+ */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LeafReuse<T> {
+    /// The HSS level of the tree, starting with the top level.
+    pub level: usize,
+    pub lms_tree_identifier: LmsTreeIdentifier,
+    pub lms_leaf_identifier: u32,
+    pub kind: ReuseKind,
+    /// The tag of the signature, which used the one-time key first.
+    pub first: T,
+    /// The tag of the conflicting signature.
+    pub second: T,
+}
+
+struct LeafUse<T> {
+    lmots_signature_hash: ArrayVec<[u8; MAX_HASH_SIZE]>,
+    child_public_key: Option<ArrayVec<[u8; MAX_LMS_PUBLIC_KEY_LENGTH]>>,
+    tag: T,
+}
+
+/**
+ * Detects the reuse of LM-OTS keys across the signatures of an HSS key.
+ *
+ * Every ingested signature is indexed by the one-time keys `(I, q)` of all its levels. A one-time
+ * key, that produced two distinct LM-OTS signatures, signed two distinct messages. As the LM-OTS
+ * signature is compared instead of the message, no messages are needed, and signatures from
+ * archives can be mixed with the log of the signer. Identical signatures are no reuse.
+ *
+ * The signatures are only parsed, not verified. A forged signature can thus raise a false alarm.
+ *
+ * ```
+ * use hbs_lms::{keygen, HssParameter, LmotsAlgorithm, LmsAlgorithm, Seed, Sha256_256};
+ * use hbs_lms::{signature::SignerMut, LeafReuseDetector};
+ *
+ * let parameters = [HssParameter::new(LmotsAlgorithm::LmotsW4, LmsAlgorithm::LmsH5)];
+ * let (mut signing_key, verifying_key) =
+ *     keygen::<Sha256_256>(&parameters, &Seed::default(), None).unwrap();
+ *
+ * // A restored backup of the private key reuses the next leaf.
+ * let leaf = signing_key.signature_range().unwrap().start;
+ * let mut backup = signing_key.clone();
+ * let first = signing_key.try_sign(b"first").unwrap();
+ * let second = backup.try_sign(b"second").unwrap();
+ *
+ * let mut detector = LeafReuseDetector::new(&verifying_key).unwrap();
+ * assert!(!detector.ingest(first.as_ref(), "first").unwrap());
+ * assert!(detector.ingest(second.as_ref(), "second").unwrap());
+ * assert_eq!(u64::from(detector.findings()[0].lms_leaf_identifier), leaf);
+ * ```
+ */
+pub struct LeafReuseDetector<H: HashChain, T> {
+    lms_tree_identifier: LmsTreeIdentifier,
+    levels: usize,
+    lms_type: u32,
+    lmots_type: u32,
+    leafs: BTreeMap<(LmsTreeIdentifier, u32), LeafUse<T>>,
+    findings: Vec<LeafReuse<T>>,
+    phantom_data: PhantomData<H>,
+}
+
+impl<H: HashChain, T: Clone> LeafReuseDetector<H, T> {
+    /// Creates a detector for the signatures of `verifying_key`.
+    pub fn new(verifying_key: &VerifyingKey<H>) -> Result<Self, Error> {
+        let public_key =
+            InMemoryHssPublicKey::<H>::new(verifying_key.as_slice()).ok_or_else(Error::new)?;
+
+        let mut lms_tree_identifier = LmsTreeIdentifier::default();
+        lms_tree_identifier.copy_from_slice(public_key.public_key.lms_tree_identifier);
+
+        Ok(Self {
+            lms_tree_identifier,
+            levels: public_key.level,
+            lms_type: public_key.public_key.lms_parameter.get_type_id(),
+            lmots_type: public_key.public_key.lmots_parameter.get_type_id(),
+            leafs: BTreeMap::new(),
+            findings: Vec::new(),
+            phantom_data: PhantomData,
+        })
+    }
+
+    /**
+     * Indexes the one-time keys of `signature`, which is identified by `tag` in the findings,
+     * e.g. by its file name.
+     *
+     * Returns whether the signature reused a one-time key of a previously ingested signature, or
+     * an error, if the signature can't be parsed or doesn't match the levels or the top tree of
+     * the verifying key.
+     */
+    pub fn ingest(&mut self, signature: &[u8], tag: T) -> Result<bool, Error> {
+        let info = SignatureInfo::<H>::parse(signature)?;
+        let findings = self.findings.len();
+
+        // The leafs of signatures of other keys would be recorded as leafs of the top tree.
+        let top_level = &info.levels()[0];
+        if info.levels().len() != self.levels
+            || top_level.lms_type != self.lms_type
+            || top_level.lmots_type != self.lmots_type
+        {
+            return Err(Error::new());
+        }
+
+        for (level, level_info) in info.levels().iter().enumerate() {
+            let lms_tree_identifier = match level_info.lms_tree_identifier {
+                Some(lms_tree_identifier) => lms_tree_identifier.try_into().unwrap(),
+                None => self.lms_tree_identifier,
+            };
+
+            let lmots_signature_hash = H::default().chain(level_info.lmots_signature).finalize();
+
+            let child_public_key = level_info
+                .child_public_key
+                .map(|public_key| ArrayVec::try_from(public_key.bytes).unwrap());
+
+            self.record(
+                level,
+                (lms_tree_identifier, level_info.lms_leaf_identifier),
+                LeafUse {
+                    lmots_signature_hash,
+                    child_public_key,
+                    tag: tag.clone(),
+                },
+            );
+        }
+
+        Ok(self.findings.len() > findings)
+    }
+
+    /// All reuses found so far, in the order of detection.
+    pub fn findings(&self) -> &[LeafReuse<T>] {
+        self.findings.as_slice()
+    }
+
+    fn record(&mut self, level: usize, key: (LmsTreeIdentifier, u32), leaf_use: LeafUse<T>) {
+        let first = match self.leafs.get(&key) {
+            Some(first) => first,
+            None => {
+                self.leafs.insert(key, leaf_use);
+                return;
+            }
+        };
+
+        let kind = if first.child_public_key != leaf_use.child_public_key {
+            ReuseKind::DistinctChildPublicKeys
+        } else if first.lmots_signature_hash != leaf_use.lmots_signature_hash {
+            ReuseKind::DistinctMessages
+        } else {
+            return;
+        };
+
+        self.findings.push(LeafReuse {
+            level,
+            lms_tree_identifier: key.0,
+            lms_leaf_identifier: key.1,
+            kind,
+            first: first.tag.clone(),
+            second: leaf_use.tag,
+        });
+    }
+}
+
+#[cfg(all(test, feature = "sign", feature = "sha256"))]
+mod tests {
+    use super::{LeafReuseDetector, ReuseKind};
+
+    use core::mem::size_of;
+
+    use crate::{
+        constants::{lms_public_key_length, lms_signature_length},
+        hasher::HashChain,
+        hss::{definitions::InMemoryHssPublicKey, hss_keygen, reference_impl_private_key::Seed},
+        signature::SignerMut,
+        HssParameter, LmotsAlgorithm, LmsAlgorithm, Sha256_192,
+    };
+
+    type H = Sha256_192;
+
+    #[test]
+    fn detect_reused_leafs() {
+        let parameters = [
+            HssParameter::<H>::new(LmotsAlgorithm::LmotsW4, LmsAlgorithm::LmsH5),
+            HssParameter::<H>::new(LmotsAlgorithm::LmotsW4, LmsAlgorithm::LmsH5),
+        ];
+        let (mut signing_key, verifying_key) =
+            hss_keygen::<H>(&parameters, &Seed::default(), None).unwrap();
+        let mut detector = LeafReuseDetector::new(&verifying_key).unwrap();
+
+        let start = signing_key.signature_range().unwrap().start as u32;
+        let backup = signing_key.clone();
+        let first = signing_key.try_sign(b"first").unwrap();
+        let second = signing_key.try_sign(b"second").unwrap();
+
+        assert!(!detector.ingest(first.as_ref(), 0).unwrap());
+        assert!(!detector.ingest(second.as_ref(), 1).unwrap());
+        // The same signature ingested again, e.g. from the log of the signer
+        assert!(!detector.ingest(first.as_ref(), 2).unwrap());
+        assert!(detector.findings().is_empty());
+
+        // The restored backup signs a different message with the first leaf of the child tree.
+        // The top tree signs the same child public key again, which is no reuse.
+        let reused = backup.clone().try_sign(b"third").unwrap();
+        assert!(detector.ingest(reused.as_ref(), 3).unwrap());
+
+        let finding = &detector.findings()[0];
+        assert_eq!(detector.findings().len(), 1);
+        assert_eq!(finding.level, 1);
+        assert_eq!(finding.lms_leaf_identifier, start);
+        assert_eq!(finding.kind, ReuseKind::DistinctMessages);
+        assert_eq!((finding.first, finding.second), (0, 3));
+
+        assert!(detector.ingest(&reused.as_ref()[..100], 4).is_err());
+    }
+
+    #[test]
+    fn detect_distinct_child_public_keys() {
+        let parameters = [
+            HssParameter::<H>::new(LmotsAlgorithm::LmotsW4, LmsAlgorithm::LmsH5),
+            HssParameter::<H>::new(LmotsAlgorithm::LmotsW4, LmsAlgorithm::LmsH5),
+        ];
+        let (mut signing_key, verifying_key) =
+            hss_keygen::<H>(&parameters, &Seed::default(), None).unwrap();
+        let mut detector = LeafReuseDetector::new(&verifying_key).unwrap();
+
+        let signature = signing_key.try_sign(b"message").unwrap();
+        assert!(!detector.ingest(signature.as_ref(), "original").unwrap());
+
+        // Flip the last byte of the child public key, which follows the top LMS signature.
+        let child_public_key_end = size_of::<u32>()
+            + lms_signature_length(
+                H::OUTPUT_SIZE as usize,
+                parameters[0].get_lmots_parameter().get_hash_chain_count() as usize,
+                parameters[0].get_lms_parameter().get_tree_height() as usize,
+            )
+            + lms_public_key_length(H::OUTPUT_SIZE as usize);
+        let mut modified = signature.as_ref().to_vec();
+        modified[child_public_key_end - 1] ^= 1;
+
+        assert!(detector.ingest(&modified, "modified").unwrap());
+        let finding = &detector.findings()[0];
+        assert_eq!(finding.level, 0);
+        assert_eq!(finding.kind, ReuseKind::DistinctChildPublicKeys);
+        assert_eq!(
+            &finding.lms_tree_identifier[..],
+            InMemoryHssPublicKey::<H>::new(verifying_key.as_slice())
+                .unwrap()
+                .public_key
+                .lms_tree_identifier
+        );
+    }
+
+    #[test]
+    fn reject_signatures_of_other_keys() {
+        let parameter = HssParameter::<H>::new(LmotsAlgorithm::LmotsW4, LmsAlgorithm::LmsH5);
+        let (_, verifying_key) =
+            hss_keygen::<H>(&[parameter, parameter], &Seed::default(), None).unwrap();
+        let mut detector = LeafReuseDetector::new(&verifying_key).unwrap();
+
+        // A single level key with the same parameter
+        let (mut signing_key, _) = hss_keygen::<H>(&[parameter], &Seed::default(), None).unwrap();
+        let signature = signing_key.try_sign(b"message").unwrap();
+        assert!(detector.ingest(signature.as_ref(), ()).is_err());
+
+        // A key with another top tree
+        let parameters = [
+            HssParameter::<H>::new(LmotsAlgorithm::LmotsW2, LmsAlgorithm::LmsH5),
+            parameter,
+        ];
+        let (mut signing_key, _) = hss_keygen::<H>(&parameters, &Seed::default(), None).unwrap();
+        let signature = signing_key.try_sign(b"message").unwrap();
+        assert!(detector.ingest(signature.as_ref(), ()).is_err());
+
+        assert!(detector.findings().is_empty());
+    }
+}
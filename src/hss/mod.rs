@@ -1,37 +1,90 @@
+#[cfg(feature = "sign")]
 pub mod aux;
+#[cfg(all(feature = "x509-cert", feature = "spki"))]
+pub mod certificate;
+#[cfg(feature = "coset")]
+pub mod cose;
+pub mod cost;
 pub mod definitions;
+#[cfg(feature = "sign")]
+pub mod delegation;
+#[cfg(feature = "sign")]
+pub mod exclusive_signing_key;
+#[cfg(all(feature = "sign", feature = "embedded-storage"))]
+pub mod flash_key_store;
+#[cfg(any(all(test, feature = "sign", feature = "sha256"), feature = "std"))]
+pub mod leaf_reuse;
 pub mod parameter;
+#[cfg(any(all(test, feature = "sha256"), feature = "std"))]
+pub mod recommend;
+#[cfg(feature = "sign")]
 pub mod reference_impl_private_key;
+#[cfg(feature = "sign")]
 mod seed_derive;
+#[cfg(all(feature = "sign", feature = "std"))]
+pub mod shared_signer;
+pub mod signature_info;
+#[cfg(all(feature = "cms", feature = "spki"))]
+pub mod signed_data;
 pub mod signing;
+#[cfg(feature = "spki")]
+pub mod subject_public_key_info;
+pub mod verification_policy;
 pub mod verify;
+#[cfg(feature = "embedded-storage")]
+pub mod verify_flash;
+pub mod verify_state;
 
+#[cfg(feature = "sign")]
+use core::ops::Range;
 use core::{convert::TryFrom, marker::PhantomData};
 use tinyvec::ArrayVec;
 
+#[cfg(all(feature = "sign", feature = "zeroize"))]
+use crate::util::helper::zeroize_array_vec;
 use crate::{
-    constants::{MAX_HSS_PUBLIC_KEY_LENGTH, REF_IMPL_MAX_PRIVATE_KEY_SIZE},
-    hss::{aux::hss_is_aux_data_used, reference_impl_private_key::Seed},
-    signature::{Error, SignerMut, Verifier},
+    constants::MAX_HSS_PUBLIC_KEY_LENGTH,
+    lm_ots::verify::VerifyScratch,
+    signature::{Error, Verifier},
     HashChain, Signature, VerifierSignature,
 };
+#[cfg(feature = "sign")]
+use crate::{
+    constants::MAX_PRIVATE_KEY_SIZE,
+    hss::{aux::hss_is_aux_data_used, reference_impl_private_key::Seed},
+    signature::SignerMut,
+};
 
 use self::{
-    definitions::{HssPrivateKey, HssPublicKey, InMemoryHssPublicKey},
+    definitions::InMemoryHssPublicKey, signature_info::SignatureInfo,
+    verification_policy::VerificationPolicy, verify_state::VerifyState,
+};
+#[cfg(feature = "sign")]
+use self::{
+    definitions::{HssPrivateKey, HssPublicKey},
+    delegation::Delegation,
     parameter::HssParameter,
     reference_impl_private_key::ReferenceImplPrivateKey,
-    signing::{HssSignature, InMemoryHssSignature},
+    signing::HssSignature,
 };
 
 /**
  * Implementation of [`SignerMut`] using [`Signature`].
+ *
+ * Every signature and [`SigningKey::delegate`] advances the private key in place. The advanced key
+ * must be persisted before the signature or delegation is released, as otherwise a restart would
+ * reuse the one-time keys. [`hss_sign`] calls a `private_key_update_function` for this purpose.
+ *
+ * The private key is redacted in the `Debug` output and wiped on drop with the `zeroize` feature.
  */
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg(feature = "sign")]
+#[derive(Clone, PartialEq, Eq)]
 pub struct SigningKey<H: HashChain> {
-    pub bytes: ArrayVec<[u8; REF_IMPL_MAX_PRIVATE_KEY_SIZE]>,
+    pub bytes: ArrayVec<[u8; MAX_PRIVATE_KEY_SIZE]>,
     phantom_data: PhantomData<H>,
 }
 
+#[cfg(feature = "sign")]
 impl<H: HashChain> SigningKey<H> {
     pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
         let bytes = ArrayVec::try_from(bytes).map_err(|_| Error::new())?;
@@ -42,6 +95,20 @@ impl<H: HashChain> SigningKey<H> {
         })
     }
 
+    /// Encodes `private_key` without leaving a copy of its bytes behind.
+    pub(crate) fn from_private_key(
+        private_key: &ReferenceImplPrivateKey<H>,
+    ) -> Result<Self, Error> {
+        #[allow(unused_mut)]
+        let mut bytes = private_key.to_binary_representation();
+        let signing_key = Self::from_bytes(bytes.as_slice());
+
+        #[cfg(feature = "zeroize")]
+        zeroize_array_vec(&mut bytes);
+
+        signing_key
+    }
+
     pub fn as_slice(&self) -> &[u8] {
         self.bytes.as_slice()
     }
@@ -54,31 +121,160 @@ impl<H: HashChain> SigningKey<H> {
         let rfc_sk = ReferenceImplPrivateKey::from_binary_representation(self.bytes.as_slice())
             .map_err(|_| Error::new())?;
 
+        if rfc_sk.signature_range.is_some() {
+            let remaining = rfc_sk.remaining_signatures().map_err(|_| Error::new())?;
+            return Ok(remaining.end - remaining.start);
+        }
+
         let parsed_sk = HssPrivateKey::<H>::from(&rfc_sk, &mut None).map_err(|_| Error::new())?;
 
         Ok(parsed_sk.get_lifetime())
     }
 
+    /**
+     * Returns the range of the signature counter left to this key, starting with the next
+     * signature. Without a partition, the range ends with the last signature of the key.
+     */
+    pub fn signature_range(&self) -> Result<Range<u64>, Error> {
+        ReferenceImplPrivateKey::<H>::from_binary_representation(self.bytes.as_slice())
+            .and_then(|private_key| private_key.remaining_signatures())
+            .map_err(|_| Error::new())
+    }
+
+    /**
+     * Partitions the remaining signatures into `count` private keys with disjoint ranges of the
+     * signature counter, in ascending order. The keys share the verifying key and can be used by
+     * different hosts concurrently, as each key refuses to sign beyond its range.
+     *
+     * The ranges are aligned to the largest subtrees that still allow `count` ranges, so no two
+     * keys use the same tree below that level. The ranges are stored in the private keys, which
+     * are thus longer than keys in the format of the reference implementation.
+     *
+     * # Arguments
+     * * `count` - The number of private keys.
+     */
+    #[cfg(any(test, feature = "std"))]
+    pub fn partition(self, count: usize) -> Result<alloc::vec::Vec<Self>, Error> {
+        let mut private_key =
+            ReferenceImplPrivateKey::<H>::from_binary_representation(self.bytes.as_slice())
+                .map_err(|_| Error::new())?;
+        let parameters = private_key
+            .compressed_parameter
+            .to::<H>()
+            .map_err(|_| Error::new())?;
+        let remaining = private_key
+            .remaining_signatures()
+            .map_err(|_| Error::new())?;
+        let count = count as u64;
+
+        if count == 0 || count > remaining.end - remaining.start {
+            return Err(Error::new());
+        }
+
+        // Find the largest subtrees, whose boundaries within the range split it into enough parts.
+        let mut subtree_size = private_key.total_signatures().map_err(|_| Error::new())?;
+        let mut first_boundary = 0;
+        let mut segments = 0;
+        for parameter in parameters.iter() {
+            subtree_size >>= parameter.get_lms_parameter().get_tree_height();
+            first_boundary = remaining.start / subtree_size + 1;
+            segments = (remaining.end - 1) / subtree_size + 2 - first_boundary;
+
+            if segments >= count {
+                break;
+            }
+        }
+
+        let mut split_points = alloc::vec::Vec::new();
+        let mut segment = 0;
+        for i in 0..count - 1 {
+            // The first `segments % count` keys get one segment more.
+            segment += segments / count + u64::from(i < segments % count);
+            split_points.push((first_boundary + segment - 1) * subtree_size);
+        }
+
+        let mut signing_keys = alloc::vec::Vec::new();
+        for &split_point in split_points.iter().rev() {
+            let other = private_key
+                .split_off(split_point)
+                .map_err(|_| Error::new())?;
+            signing_keys.push(Self::from_private_key(&other)?);
+        }
+        signing_keys.push(Self::from_private_key(&private_key)?);
+        signing_keys.reverse();
+
+        Ok(signing_keys)
+    }
+
+    /**
+     * Signs the top tree of `delegate_key` with the top tree of this key, see [`Delegation`].
+     *
+     * The next top leaf, whose subtree is unused, signs the delegate instead of a child tree of
+     * this key, so the signatures up to the end of that subtree are skipped. Like after a
+     * signature, the advanced private key must be persisted before the delegation is released,
+     * see [`SigningKey`]. A crash in between would sign another delegate with the same top leaf.
+     *
+     * # Arguments
+     * * `delegate_key` - The verifying key of the delegate, which has one level less than this key.
+     * * `aux_data` - Auxiliary data of the top tree to speedup the signature if available.
+     */
+    pub fn delegate(
+        &mut self,
+        delegate_key: &VerifyingKey<H>,
+        aux_data: Option<&mut &mut [u8]>,
+    ) -> Result<Delegation<H>, Error> {
+        let mut private_key =
+            ReferenceImplPrivateKey::from_binary_representation(self.bytes.as_slice())
+                .map_err(|_| Error::new())?;
+
+        let delegation = delegation::delegate(&mut private_key, delegate_key, aux_data)
+            .map_err(|_| Error::new())?;
+        *self = Self::from_private_key(&private_key)?;
+
+        Ok(delegation)
+    }
+
     pub fn try_sign_with_aux(
         &mut self,
         msg: &[u8],
         aux_data: Option<&mut &mut [u8]>,
     ) -> Result<Signature, Error> {
-        let private_key = self.bytes;
+        #[allow(unused_mut)]
+        let mut private_key = self.bytes;
         let mut private_key_update_function = |new_key: &[u8]| {
-            self.bytes.as_mut_slice().copy_from_slice(new_key);
+            self.bytes = ArrayVec::try_from(new_key).map_err(|_| ())?;
             Ok(())
         };
 
-        hss_sign::<H>(
+        let signature = hss_sign::<H>(
             msg,
             private_key.as_slice(),
             &mut private_key_update_function,
             aux_data,
-        )
+        );
+
+        #[cfg(feature = "zeroize")]
+        zeroize_array_vec(&mut private_key);
+
+        signature
+    }
+}
+
+#[cfg(feature = "sign")]
+impl<H: HashChain> core::fmt::Debug for SigningKey<H> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SigningKey").finish_non_exhaustive()
     }
 }
 
+#[cfg(all(feature = "sign", feature = "zeroize"))]
+impl<H: HashChain> Drop for SigningKey<H> {
+    fn drop(&mut self) {
+        zeroize_array_vec(&mut self.bytes);
+    }
+}
+
+#[cfg(feature = "sign")]
 impl<H: HashChain> SignerMut<Signature> for SigningKey<H> {
     fn try_sign(&mut self, msg: &[u8]) -> Result<Signature, Error> {
         self.try_sign_with_aux(msg, None)
@@ -104,9 +300,85 @@ impl<H: HashChain> VerifyingKey<H> {
         })
     }
 
+    /// Like [`VerifyingKey::from_bytes`], but also checks the length and the parameters of the
+    /// public key. Used for keys taken from encodings like SubjectPublicKeyInfo or COSE_Key.
+    #[cfg(any(feature = "spki", feature = "coset", feature = "serde"))]
+    pub(crate) fn from_checked_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let length = core::mem::size_of::<u32>()
+            + crate::constants::lms_public_key_length(H::OUTPUT_SIZE as usize);
+
+        if bytes.len() != length || InMemoryHssPublicKey::<H>::new(bytes).is_none() {
+            return Err(Error::new());
+        }
+
+        Self::from_bytes(bytes)
+    }
+
     pub fn as_slice(&self) -> &[u8] {
         self.bytes.as_slice()
     }
+
+    /**
+     * Verify a signature using caller provided scratch space, see [`hss_verify_with_scratch`].
+     *
+     * The scratch space only holds intermediate results during a call. It can be kept, e.g. in
+     * static memory, and reused for any number of verifications. Apart from `scratch` and the hash
+     * function internals, verification uses less than [`crate::VERIFY_MAX_STACK_USAGE`] bytes of
+     * stack (release build).
+     */
+    pub fn verify_with_scratch(
+        &self,
+        msg: &[u8],
+        signature: &[u8],
+        scratch: &mut VerifyScratch<H>,
+    ) -> Result<(), Error> {
+        hss_verify_with_scratch::<H>(msg, signature, &self.bytes, scratch)
+    }
+
+    /**
+     * Verify a signature and return its global index, see [`SignatureInfo::signature_counter`].
+     *
+     * If `last_accepted` is given, signatures whose index is not greater than it are rejected.
+     * Storing the returned index as `last_accepted` of the next verification prevents rollbacks
+     * to signatures of earlier leafs.
+     */
+    pub fn verify_with_counter(
+        &self,
+        msg: &[u8],
+        signature: &[u8],
+        last_accepted: Option<u64>,
+    ) -> Result<u64, Error> {
+        let counter = SignatureInfo::<H>::parse(signature)?
+            .signature_counter()
+            .ok_or_else(Error::new)?;
+
+        if last_accepted.map_or(false, |last_accepted| counter <= last_accepted) {
+            return Err(Error::new());
+        }
+
+        hss_verify::<H>(msg, signature, &self.bytes)?;
+
+        Ok(counter)
+    }
+
+    /// Verify a signature, if its parameters are allowed by `policy`, see
+    /// [`hss_verify_with_policy`].
+    pub fn verify_with_policy(
+        &self,
+        msg: &[u8],
+        signature: &[u8],
+        policy: &VerificationPolicy,
+    ) -> Result<(), Error> {
+        hss_verify_with_policy::<H>(msg, signature, &self.bytes, policy)
+    }
+
+    pub fn verify_state<'a>(
+        &'a self,
+        msg: &'a [u8],
+        signature: &'a [u8],
+    ) -> Result<VerifyState<'a, H>, Error> {
+        VerifyState::new(msg, signature, &self.bytes)
+    }
 }
 
 impl<H: HashChain> Verifier<Signature> for VerifyingKey<H> {
@@ -135,10 +407,283 @@ pub fn hss_verify<H: HashChain>(
     signature: &[u8],
     public_key: &[u8],
 ) -> Result<(), Error> {
-    let signature = InMemoryHssSignature::<H>::new(signature).ok_or_else(Error::new)?;
+    hss_verify_with_scratch::<H>(
+        message,
+        signature,
+        public_key,
+        &mut VerifyScratch::default(),
+    )
+}
+
+/**
+ * Verify a signature ([`Signature`] or [`VerifierSignature`]), if its parameters are allowed by
+ * `policy`. The policy is checked before any hash chain is computed.
+ *
+ * # Arguments
+ * * `HashChain` - The hasher implementation that should be used. ```Sha256``` is a standard software implementation.
+ * * `message` - The message that should be verified.
+ * * `signature` - The signature that should be used for verification.
+ * * `public_key` - The public key that should be used for verification.
+ * * `policy` - The allowed parameters and verification costs.
+ */
+pub fn hss_verify_with_policy<H: HashChain>(
+    message: &[u8],
+    signature: &[u8],
+    public_key: &[u8],
+    policy: &VerificationPolicy,
+) -> Result<(), Error> {
+    policy.check::<H>(signature, public_key)?;
+
+    hss_verify::<H>(message, signature, public_key)
+}
+
+/**
+ * Verify a signature ([`Signature`] or [`VerifierSignature`]) using caller provided scratch space.
+ *
+ * The signature is parsed and verified level by level, and the hash chain results are directly
+ * absorbed into the LM-OTS public key hash. Thus, the memory needed does not depend on the used
+ * parameters. Apart from `scratch` and the hash function internals, verification uses less than
+ * [`crate::VERIFY_MAX_STACK_USAGE`] bytes of stack (measured on x86_64 with a release build).
+ *
+ * # Arguments
+ * * `HashChain` - The hasher implementation that should be used. ```Sha256``` is a standard software implementation.
+ * * `message` - The message that should be verified.
+ * * `signature` - The signature that should be used for verification.
+ * * `public_key` - The public key that should be used for verification.
+ * * `scratch` - Scratch space holding the hashers, e.g. placed in static memory.
+ */
+pub fn hss_verify_with_scratch<H: HashChain>(
+    message: &[u8],
+    signature: &[u8],
+    public_key: &[u8],
+    scratch: &mut VerifyScratch<H>,
+) -> Result<(), Error> {
     let public_key = InMemoryHssPublicKey::<H>::new(public_key).ok_or_else(Error::new)?;
 
-    crate::hss::verify::verify(&signature, &public_key, message).map_err(|_| Error::new())
+    crate::hss::verify::verify_with_scratch(signature, &public_key, message, scratch)
+        .map_err(|_| Error::new())
+}
+
+/**
+ * Verify a signature, with the message, the signature and the public key residing in a NOR flash.
+ *
+ * The regions are read incrementally through [`embedded_storage::nor_flash::ReadNorFlash`], so
+ * neither the message nor the signature need to be copied into memory first. Reads are aligned to
+ * `READ_SIZE`, which must divide 64.
+ *
+ * # Arguments
+ * * `HashChain` - The hasher implementation that should be used. ```Sha256``` is a standard software implementation.
+ * * `flash` - The flash holding the message, the signature and the public key.
+ * * `message` - The flash region of the message that should be verified.
+ * * `signature` - The flash region of the signature that should be used for verification.
+ * * `public_key` - The flash region of the public key that should be used for verification.
+ * * `scratch` - Scratch space holding the hashers, e.g. placed in static memory.
+ */
+#[cfg(feature = "embedded-storage")]
+pub fn hss_verify_flash<F: embedded_storage::nor_flash::ReadNorFlash, H: HashChain>(
+    flash: &mut F,
+    message: core::ops::Range<u32>,
+    signature: core::ops::Range<u32>,
+    public_key: core::ops::Range<u32>,
+    scratch: &mut VerifyScratch<H>,
+) -> Result<(), Error> {
+    crate::hss::verify_flash::verify(flash, message, signature, public_key, scratch)
+        .map_err(|_| Error::new())
+}
+
+/**
+ * Verify a CMS SignedData (RFC 8708) wrapped into a ContentInfo.
+ *
+ * The message-digest and content-type signed attributes are checked against the content, before
+ * the HSS signature over the signed attributes is verified.
+ *
+ * # Arguments
+ * * `HashChain` - The hasher implementation that should be used. ```Sha256``` is a standard software implementation.
+ * * `Digest` - The digest algorithm of the message-digest attribute, e.g. `sha2::Sha256`.
+ * * `verifying_key` - The public key that should be used for verification.
+ * * `content_info` - The ContentInfo holding the SignedData.
+ * * `detached_content` - The content, if it is not encapsulated into the SignedData.
+ */
+#[cfg(all(feature = "cms", feature = "spki"))]
+pub fn hss_verify_signed_data<H: HashChain, D: digest::Digest + spki::der::oid::AssociatedOid>(
+    verifying_key: &VerifyingKey<H>,
+    content_info: &cms::content_info::ContentInfo,
+    detached_content: Option<&[u8]>,
+) -> Result<(), Error> {
+    crate::hss::signed_data::verify::<H, D>(verifying_key, content_info, detached_content)
+        .map_err(|_| Error::new())
+}
+
+/**
+ * Generate a CMS SignedData (RFC 8708) wrapped into a ContentInfo.
+ *
+ * # Arguments
+ * * `HashChain` - The hasher implementation that should be used. ```Sha256``` is a standard software implementation.
+ * * `Digest` - The digest algorithm of the message-digest attribute, e.g. `sha2::Sha256`.
+ * * `signing_key` - The private key that should be used. It is advanced by one signature.
+ * * `signer_identifier` - The identifier of the signer, which is placed into the SignerInfo.
+ * * `content` - The content that should be signed.
+ * * `detached` - Whether the content is left out of the SignedData.
+ */
+#[cfg(all(feature = "cms", feature = "spki", feature = "sign"))]
+pub fn hss_sign_signed_data<H: HashChain, D: digest::Digest + spki::der::oid::AssociatedOid>(
+    signing_key: &mut SigningKey<H>,
+    signer_identifier: cms::signed_data::SignerIdentifier,
+    content: &[u8],
+    detached: bool,
+) -> Result<cms::content_info::ContentInfo, Error> {
+    crate::hss::signed_data::sign::<H, D>(signing_key, signer_identifier, content, detached)
+        .map_err(|_| Error::new())
+}
+
+/**
+ * Verify a COSE_Sign1 structure (RFC 8778), whose protected header carries HSS-LMS as `alg`.
+ *
+ * # Arguments
+ * * `HashChain` - The hasher implementation that should be used. ```Sha256``` is a standard software implementation.
+ * * `verifying_key` - The public key that should be used for verification.
+ * * `cose_sign1` - The COSE_Sign1 structure that should be verified.
+ * * `detached_payload` - The payload, if it is not included in the COSE_Sign1 structure.
+ * * `aad` - The externally supplied data, which is empty if not used.
+ */
+#[cfg(feature = "coset")]
+pub fn hss_verify_cose_sign1<H: HashChain>(
+    verifying_key: &VerifyingKey<H>,
+    cose_sign1: &coset::CoseSign1,
+    detached_payload: Option<&[u8]>,
+    aad: &[u8],
+) -> Result<(), Error> {
+    crate::hss::cose::verify(verifying_key, cose_sign1, detached_payload, aad)
+        .map_err(|_| Error::new())
+}
+
+/**
+ * Generate a COSE_Sign1 structure (RFC 8778).
+ *
+ * # Arguments
+ * * `HashChain` - The hasher implementation that should be used. ```Sha256``` is a standard software implementation.
+ * * `signing_key` - The private key that should be used. It is advanced by one signature.
+ * * `protected` - The protected header. Its `alg` parameter is set to HSS-LMS.
+ * * `unprotected` - The unprotected header, e.g. holding the key identifier.
+ * * `payload` - The payload that should be signed.
+ * * `detached` - Whether the payload is left out of the COSE_Sign1 structure.
+ * * `aad` - The externally supplied data, which is empty if not used.
+ */
+#[cfg(all(feature = "coset", feature = "sign"))]
+pub fn hss_sign_cose_sign1<H: HashChain>(
+    signing_key: &mut SigningKey<H>,
+    protected: coset::Header,
+    unprotected: coset::Header,
+    payload: &[u8],
+    detached: bool,
+    aad: &[u8],
+) -> Result<coset::CoseSign1, Error> {
+    crate::hss::cose::sign(signing_key, protected, unprotected, payload, detached, aad)
+        .map_err(|_| Error::new())
+}
+
+/**
+ * Verify the signature of an X.509 certificate, which is signed with id-alg-hss-lms-hashsig.
+ * Neither the validity nor the extensions of the certificate are checked.
+ *
+ * # Arguments
+ * * `HashChain` - The hasher implementation that should be used. ```Sha256``` is a standard software implementation.
+ * * `issuer_key` - The public key of the issuer of the certificate.
+ * * `certificate` - The certificate that should be verified.
+ */
+#[cfg(all(feature = "x509-cert", feature = "spki"))]
+pub fn hss_verify_certificate<H: HashChain>(
+    issuer_key: &VerifyingKey<H>,
+    certificate: &x509_cert::Certificate,
+) -> Result<(), Error> {
+    crate::hss::certificate::verify_certificate(issuer_key, certificate).map_err(|_| Error::new())
+}
+
+/**
+ * Verify the self-signature of a PKCS#10 certification request and return its public key.
+ *
+ * # Arguments
+ * * `HashChain` - The hasher implementation that should be used. ```Sha256``` is a standard software implementation.
+ * * `certificate_request` - The certification request that should be verified.
+ */
+#[cfg(all(feature = "x509-cert", feature = "spki"))]
+pub fn hss_verify_certificate_request<H: HashChain>(
+    certificate_request: &x509_cert::request::CertReq,
+) -> Result<VerifyingKey<H>, Error> {
+    crate::hss::certificate::verify_certificate_request(certificate_request)
+        .map_err(|_| Error::new())
+}
+
+/**
+ * Sign an X.509 certificate. The signature algorithm of `tbs_certificate` is set to
+ * id-alg-hss-lms-hashsig.
+ *
+ * # Arguments
+ * * `signer` - The issuer, e.g. a [`SigningKey`] or a [`crate::FlashKeyStore`]. It must persist
+ *   its advanced private key before it releases the signature.
+ * * `tbs_certificate` - The certificate content that should be signed.
+ */
+#[cfg(all(feature = "x509-cert", feature = "spki", feature = "sign"))]
+pub fn hss_sign_certificate<S: SignerMut<Signature>>(
+    signer: &mut S,
+    tbs_certificate: x509_cert::TbsCertificate,
+) -> Result<x509_cert::Certificate, Error> {
+    crate::hss::certificate::sign_certificate(signer, tbs_certificate).map_err(|_| Error::new())
+}
+
+/**
+ * Generate a self-signed X.509 version 3 certificate, e.g. for an offline root CA.
+ *
+ * # Arguments
+ * * `HashChain` - The hasher implementation that should be used. ```Sha256``` is a standard software implementation.
+ * * `signer` - The private key, e.g. a [`SigningKey`] or a [`crate::FlashKeyStore`]. It must
+ *   persist its advanced private key before it releases the signature.
+ * * `verifying_key` - The public key belonging to `signer`.
+ * * `serial_number` - The serial number of the certificate.
+ * * `validity` - The validity period of the certificate.
+ * * `subject` - The subject, which is the issuer as well.
+ * * `extensions` - The extensions of the certificate, e.g. the basic constraints.
+ */
+#[cfg(all(feature = "x509-cert", feature = "spki", feature = "sign"))]
+pub fn hss_sign_self_signed_certificate<H: HashChain, S: SignerMut<Signature>>(
+    signer: &mut S,
+    verifying_key: &VerifyingKey<H>,
+    serial_number: x509_cert::serial_number::SerialNumber,
+    validity: x509_cert::time::Validity,
+    subject: x509_cert::name::Name,
+    extensions: Option<x509_cert::ext::Extensions>,
+) -> Result<x509_cert::Certificate, Error> {
+    crate::hss::certificate::sign_self_signed_certificate(
+        signer,
+        verifying_key,
+        serial_number,
+        validity,
+        subject,
+        extensions,
+    )
+    .map_err(|_| Error::new())
+}
+
+/**
+ * Generate a PKCS#10 certification request (RFC 2986).
+ *
+ * # Arguments
+ * * `HashChain` - The hasher implementation that should be used. ```Sha256``` is a standard software implementation.
+ * * `signer` - The private key, e.g. a [`SigningKey`] or a [`crate::FlashKeyStore`]. It must
+ *   persist its advanced private key before it releases the signature.
+ * * `verifying_key` - The public key that should be certified.
+ * * `subject` - The subject of the requested certificate.
+ * * `attributes` - The attributes of the request, e.g. requested extensions.
+ */
+#[cfg(all(feature = "x509-cert", feature = "spki", feature = "sign"))]
+pub fn hss_sign_certificate_request<H: HashChain, S: SignerMut<Signature>>(
+    signer: &mut S,
+    verifying_key: &VerifyingKey<H>,
+    subject: x509_cert::name::Name,
+    attributes: x509_cert::attr::Attributes,
+) -> Result<x509_cert::request::CertReq, Error> {
+    crate::hss::certificate::sign_certificate_request(signer, verifying_key, subject, attributes)
+        .map_err(|_| Error::new())
 }
 
 /**
@@ -150,8 +695,12 @@ pub fn hss_verify<H: HashChain>(
  * * `private_key` - The private key that should be used.
  * * `private_key_update_function` - The update function that is called with the new private key. This function should save the new private key.
  * * `aux_data` - Auxiliary data to speedup signature generation if available
+ *
+ * With the `fault_protection` feature, the roots of all child trees are computed twice and every
+ * signature of the HSS signature is verified, before the signature is returned. This roughly
+ * doubles the signing time of keys with more than one level.
  */
-
+#[cfg(feature = "sign")]
 pub fn hss_sign<H: HashChain>(
     message: &[u8],
     private_key: &[u8],
@@ -192,6 +741,7 @@ pub fn hss_sign_mut<H: HashChain>(
     )
 }
 
+#[cfg(feature = "sign")]
 fn hss_sign_core<H: HashChain>(
     message: Option<&[u8]>,
     message_mut: Option<&mut [u8]>,
@@ -232,8 +782,14 @@ fn hss_sign_core<H: HashChain>(
 
     // Advance private key
     rfc_private_key.increment(&private_key);
-    private_key_update_function(&rfc_private_key.to_binary_representation())
-        .map_err(|_| Error::new())?;
+    #[allow(unused_mut)]
+    let mut new_private_key = rfc_private_key.to_binary_representation();
+    let update_result = private_key_update_function(&new_private_key);
+
+    #[cfg(feature = "zeroize")]
+    zeroize_array_vec(&mut new_private_key);
+
+    update_result.map_err(|_| Error::new())?;
 
     let hash_iterations = {
         let mut hash_iterations: u32 = 0;
@@ -255,6 +811,9 @@ fn hss_sign_core<H: HashChain>(
  * * `seed` - An optional seed which will be used to generate the private key. It must be only used for testing purposes and not for production used key pairs.
  * * `aux_data` - The reference to a slice to auxiliary data. This can be used to speedup signature generation.
  *
+ * With the `pairwise_consistency` feature, the key pair signs and verifies a test message before
+ * it is returned, which uses up the first signature of the key pair.
+ *
  * # Example
  * ```
  * use rand::{rngs::OsRng, RngCore};
@@ -274,34 +833,67 @@ fn hss_sign_core<H: HashChain>(
  *      keygen::<Sha256_256>(&parameters, &seed, Some(aux_slice)).unwrap();
  * ```
  */
+#[cfg(feature = "sign")]
 pub fn hss_keygen<H: HashChain>(
     parameters: &[HssParameter<H>],
     seed: &Seed<H>,
-    aux_data: Option<&mut &mut [u8]>,
+    mut aux_data: Option<&mut &mut [u8]>,
 ) -> Result<(SigningKey<H>, VerifyingKey<H>), Error> {
     let private_key =
         ReferenceImplPrivateKey::generate(parameters, seed).map_err(|_| Error::new())?;
 
-    let hss_public_key = HssPublicKey::from(&private_key, aux_data).map_err(|_| Error::new())?;
+    // The aux data is used again by the pairwise consistency test.
+    #[cfg_attr(
+        not(feature = "pairwise_consistency"),
+        allow(clippy::needless_option_as_deref)
+    )]
+    let hss_public_key =
+        HssPublicKey::from(&private_key, aux_data.as_deref_mut()).map_err(|_| Error::new())?;
 
-    let signing_key = SigningKey::from_bytes(&private_key.to_binary_representation())?;
+    let signing_key = SigningKey::from_private_key(&private_key)?;
     let verifying_key = VerifyingKey::from_bytes(&hss_public_key.to_binary_representation())?;
+
+    #[cfg(feature = "pairwise_consistency")]
+    let signing_key = pairwise_consistency_test(signing_key, &verifying_key, aux_data)?;
+
     Ok((signing_key, verifying_key))
 }
 
-#[cfg(test)]
+/**
+ * Signs a fixed message with a freshly generated key pair and verifies the signature. The first
+ * leaf is consumed, so the returned signing key continues with the second leaf and the key pair
+ * is never returned, if the test fails.
+ */
+#[cfg(feature = "pairwise_consistency")]
+fn pairwise_consistency_test<H: HashChain>(
+    mut signing_key: SigningKey<H>,
+    verifying_key: &VerifyingKey<H>,
+    aux_data: Option<&mut &mut [u8]>,
+) -> Result<SigningKey<H>, Error> {
+    const MESSAGE: &[u8] = b"hbs-lms pairwise consistency test";
+
+    let signature = signing_key.try_sign_with_aux(MESSAGE, aux_data)?;
+    hss_verify::<H>(MESSAGE, signature.as_ref(), verifying_key.as_slice())?;
+
+    Ok(signing_key)
+}
+
+#[cfg(all(test, feature = "sign", feature = "sha256"))]
 mod tests {
     use crate::util::helper::test_helper::gen_random_seed;
     use crate::{
-        constants::{LMS_LEAF_IDENTIFIERS_SIZE, MAX_HASH_SIZE},
+        constants::{LMS_LEAF_IDENTIFIERS_SIZE, MAX_HASH_SIZE, REF_IMPL_MAX_PRIVATE_KEY_SIZE},
         hasher::{
             sha256::{Sha256_128, Sha256_192, Sha256_256},
-            shake256::{Shake256_128, Shake256_192, Shake256_256},
             HashChain,
         },
+        lms::LmsPrivateKey,
         LmotsAlgorithm, LmsAlgorithm,
     };
 
+    #[cfg(feature = "shake256")]
+    use crate::hasher::shake256::{Shake256_128, Shake256_192, Shake256_256};
+
     use super::*;
 
     #[test]
@@ -343,6 +935,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn redact_secrets_in_debug_output() {
+        type H = Sha256_256;
+        let seed = gen_random_seed::<H>();
+        let parameters = [HssParameter::new(
+            LmotsAlgorithm::LmotsW4,
+            LmsAlgorithm::LmsH5,
+        )];
+
+        let (signing_key, _) = hss_keygen::<H>(&parameters, &seed, None).unwrap();
+
+        assert_eq!(alloc::format!("{:?}", signing_key), "SigningKey { .. }");
+        assert_eq!(alloc::format!("{:?}", seed), "Seed { .. }");
+
+        let mut hss_private_key = HssPrivateKey::<H>::from(
+            &ReferenceImplPrivateKey::from_binary_representation(signing_key.as_slice()).unwrap(),
+            &mut None,
+        )
+        .unwrap();
+        let lms_private_key = LmsPrivateKey::try_from(signing_key).unwrap();
+        assert!(alloc::format!("{:?}", lms_private_key).contains("seed: Seed { .. }"));
+
+        let lmots_private_key = hss_private_key.private_key[0]
+            .use_lmots_private_key()
+            .unwrap();
+        assert!(!alloc::format!("{:?}", lmots_private_key).contains("key:"));
+    }
+
     #[test]
     fn exhaust_keypair() {
         let message = [
@@ -358,6 +978,7 @@ mod tests {
         let (mut signing_key, verifying_key) =
             hss_keygen::<H>(&parameters, &seed, None).expect("Should generate HSS keys");
         let keypair_lifetime = signing_key.get_lifetime().unwrap();
+        let start = signing_key.signature_range().unwrap().start;
 
         assert_ne!(
             signing_key.as_slice()[(REF_IMPL_MAX_PRIVATE_KEY_SIZE - H::OUTPUT_SIZE as usize)..],
@@ -367,7 +988,7 @@ mod tests {
         for index in 0..keypair_lifetime {
             assert_eq!(
                 signing_key.as_slice()[..LMS_LEAF_IDENTIFIERS_SIZE],
-                index.to_be_bytes(),
+                (start + index).to_be_bytes(),
             );
             assert_eq!(
                 keypair_lifetime - signing_key.get_lifetime().unwrap(),
@@ -469,6 +1090,103 @@ mod tests {
         assert_eq!(vk1, vk2);
     }
 
+    #[test]
+    fn verify_with_counter() {
+        type H = Sha256_256;
+        let seed = gen_random_seed::<H>();
+
+        let parameters = [
+            HssParameter::new(LmotsAlgorithm::LmotsW4, LmsAlgorithm::LmsH5),
+            HssParameter::new(LmotsAlgorithm::LmotsW2, LmsAlgorithm::LmsH5),
+        ];
+        let (mut signing_key, verifying_key) =
+            hss_keygen::<H>(&parameters, &seed, None).expect("Should generate HSS keys");
+        let start = signing_key.signature_range().unwrap().start;
+
+        let first = signing_key.try_sign(b"first").unwrap();
+        let second = signing_key.try_sign(b"second").unwrap();
+
+        assert_eq!(
+            verifying_key
+                .verify_with_counter(b"first", first.as_ref(), None)
+                .unwrap(),
+            start
+        );
+        assert_eq!(
+            verifying_key
+                .verify_with_counter(b"second", second.as_ref(), Some(start))
+                .unwrap(),
+            start + 1
+        );
+        assert!(verifying_key
+            .verify_with_counter(b"second", second.as_ref(), Some(start + 1))
+            .is_err());
+        assert!(verifying_key
+            .verify_with_counter(b"first", first.as_ref(), Some(start + 1))
+            .is_err());
+        assert!(verifying_key
+            .verify_with_counter(b"third", second.as_ref(), None)
+            .is_err());
+    }
+
+    #[test]
+    fn partition_into_aligned_ranges() {
+        type H = Sha256_192;
+        let seed = gen_random_seed::<H>();
+
+        let parameters = [
+            HssParameter::new(LmotsAlgorithm::LmotsW4, LmsAlgorithm::LmsH2),
+            HssParameter::new(LmotsAlgorithm::LmotsW4, LmsAlgorithm::LmsH2),
+        ];
+        let (mut signing_key, verifying_key) = hss_keygen::<H>(&parameters, &seed, None).unwrap();
+        // Start with the second signature, unless the pairwise consistency test used the first.
+        if signing_key.signature_range().unwrap().start == 0 {
+            signing_key.try_sign(b"used").unwrap();
+        }
+
+        // Aligned to the trees of the second level
+        let ranges = signing_key
+            .clone()
+            .partition(2)
+            .unwrap()
+            .iter()
+            .map(|signing_key| signing_key.signature_range().unwrap())
+            .collect::<alloc::vec::Vec<_>>();
+        assert_eq!(ranges, [1..8, 8..16]);
+
+        // Aligned to single signatures, as there are only four trees on the second level
+        let ranges = signing_key
+            .clone()
+            .partition(5)
+            .unwrap()
+            .iter()
+            .map(|signing_key| signing_key.signature_range().unwrap())
+            .collect::<alloc::vec::Vec<_>>();
+        assert_eq!(ranges, [1..4, 4..7, 7..10, 10..13, 13..16]);
+
+        assert!(signing_key.clone().partition(0).is_err());
+        assert!(signing_key.clone().partition(16).is_err());
+
+        for partition in signing_key.partition(2).unwrap() {
+            // Each partition is stored as its own private key.
+            let mut signing_key = SigningKey::<H>::from_bytes(partition.as_slice()).unwrap();
+            let range = signing_key.signature_range().unwrap();
+            assert_eq!(signing_key.get_lifetime().unwrap(), range.end - range.start);
+
+            for counter in range {
+                let signature = signing_key.try_sign(b"message").unwrap();
+                assert_eq!(
+                    verifying_key
+                        .verify_with_counter(b"message", signature.as_ref(), None)
+                        .unwrap(),
+                    counter
+                );
+            }
+
+            assert!(signing_key.try_sign(b"message").is_err());
+        }
+    }
+
     #[test]
     fn test_signing_sha256_128() {
         test_signing_core::<Sha256_128>();
@@ -484,16 +1202,19 @@ mod tests {
         test_signing_core::<Sha256_256>();
     }
 
+    #[cfg(feature = "shake256")]
     #[test]
     fn test_signing_shake256_128() {
         test_signing_core::<Shake256_128>();
     }
 
+    #[cfg(feature = "shake256")]
     #[test]
     fn test_signing_shake256_192() {
         test_signing_core::<Shake256_192>();
     }
 
+    #[cfg(feature = "shake256")]
     #[test]
     fn test_signing_shake256_256() {
         test_signing_core::<Shake256_256>();
@@ -586,4 +1307,28 @@ mod tests {
 
         assert!(hss_verify::<H>(&message, signature.as_ref(), verifying_key.as_slice()).is_ok());
     }
+
+    #[cfg(feature = "pairwise_consistency")]
+    #[test]
+    fn pairwise_consistency_test_consumes_first_leaf() {
+        type H = Sha256_192;
+        let parameters = [
+            HssParameter::<H>::new(LmotsAlgorithm::LmotsW2, LmsAlgorithm::LmsH5),
+            HssParameter::<H>::new(LmotsAlgorithm::LmotsW2, LmsAlgorithm::LmsH5),
+        ];
+        let mut aux_data = [0u8; 1000];
+        let aux_slice: &mut &mut [u8] = &mut &mut aux_data[..];
+
+        let (mut signing_key, verifying_key) =
+            hss_keygen::<H>(&parameters, &gen_random_seed(), Some(aux_slice)).unwrap();
+        assert_eq!(signing_key.get_lifetime().unwrap(), 32 * 32 - 1);
+
+        let signature = signing_key.try_sign(b"message").unwrap();
+        assert_eq!(
+            verifying_key
+                .verify_with_counter(b"message", signature.as_ref(), Some(0))
+                .unwrap(),
+            1
+        );
+    }
 }
@@ -1,6 +1,9 @@
+use core::{fmt, str::FromStr};
+use tinyvec::ArrayVec;
+
 use crate::{
-    hasher::HashChain, lm_ots::parameters::LmotsParameter, lms::parameters::LmsParameter,
-    LmotsAlgorithm, LmsAlgorithm,
+    constants::MAX_ALLOWED_HSS_LEVELS, hasher::HashChain, lm_ots::parameters::LmotsParameter,
+    lms::parameters::LmsParameter, signature::Error, LmotsAlgorithm, LmsAlgorithm,
 };
 
 /**
@@ -36,6 +39,110 @@ impl<H: HashChain> HssParameter<H> {
     pub fn get_lms_parameter(&self) -> &LmsParameter<H> {
         &self.lms_parameter
     }
+
+    /**
+     * Returns whether the name formatted by `Display` is the IANA name of the type codes in keys
+     * and signatures. This crate encodes all hash functions with the type codes of SHA-256 with an
+     * output size of 32 bytes, so this is only the case for this hash function.
+     */
+    pub fn has_iana_name(&self) -> bool {
+        H::IANA_NAME == "SHA256" && H::OUTPUT_SIZE == 32
+    }
+
+    pub(crate) fn get_algorithms(&self) -> (LmotsAlgorithm, LmsAlgorithm) {
+        (
+            LmotsAlgorithm::from(self.lmots_parameter.get_type_id()),
+            LmsAlgorithm::from(self.lms_parameter.get_type_id()),
+        )
+    }
+
+    /**
+     * Parses a comma separated list of parameters, one for each HSS level starting with the top
+     * level, e.g. `LMS_SHA256_M32_H10/LMOTS_SHA256_N32_W4,LMS_SHA256_M32_H5/LMOTS_SHA256_N32_W8`.
+     */
+    pub fn parse_list(list: &str) -> Result<ArrayVec<[Self; MAX_ALLOWED_HSS_LEVELS]>, Error> {
+        let mut parameters = ArrayVec::new();
+
+        for parameter in list.split(',') {
+            if parameters.try_push(parameter.parse()?).is_some() {
+                return Err(Error::new());
+            }
+        }
+
+        Ok(parameters)
+    }
+
+    /**
+     * Formats a list of parameters in the form accepted by [`HssParameter::parse_list`].
+     */
+    pub fn display_list(parameters: &[Self]) -> impl fmt::Display + '_ {
+        struct DisplayList<'a, H: HashChain>(&'a [HssParameter<H>]);
+
+        impl<'a, H: HashChain> fmt::Display for DisplayList<'a, H> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                for (level, parameter) in self.0.iter().enumerate() {
+                    if level > 0 {
+                        f.write_str(",")?;
+                    }
+                    write!(f, "{}", parameter)?;
+                }
+                Ok(())
+            }
+        }
+
+        DisplayList(parameters)
+    }
+}
+
+/**
+ * Formats the names of the LMS and the LM-OTS algorithm, e.g.
+ * `LMS_SHA256_M32_H10/LMOTS_SHA256_N32_W4`.
+ *
+ * The names follow the IANA names for the hash function `H`, e.g.
+ * `LMS_SHAKE_M24_H10/LMOTS_SHAKE_N24_W4` or `LMS_SHA256_M16_H5/LMOTS_SHA256_N16_W8`. Except for
+ * SHA-256 with an output size of 32 bytes, they don't match the type codes encoded by this crate
+ * and are only understood by this crate, see [`HssParameter::has_iana_name`].
+ */
+impl<H: HashChain> fmt::Display for HssParameter<H> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (lmots_algorithm, lms_algorithm) = self.get_algorithms();
+
+        lms_algorithm.fmt_name(f, H::IANA_NAME, H::OUTPUT_SIZE)?;
+        f.write_str("/")?;
+        lmots_algorithm.fmt_name(f, H::IANA_NAME, H::OUTPUT_SIZE)
+    }
+}
+
+/**
+ * Parses the form of [`HssParameter`]'s `Display`. Both names must match the hash function `H`.
+ */
+impl<H: HashChain> FromStr for HssParameter<H> {
+    type Err = Error;
+
+    fn from_str(parameter: &str) -> Result<Self, Self::Err> {
+        let mut names = parameter.splitn(2, '/');
+        let lms_name = names.next().ok_or_else(Error::new)?;
+        let lmots_name = names.next().ok_or_else(Error::new)?;
+
+        let matches_hash = |hash_name: &str, output_size: u16| {
+            hash_name == H::IANA_NAME && output_size == H::OUTPUT_SIZE
+        };
+
+        match (
+            LmsAlgorithm::from_name(lms_name),
+            LmotsAlgorithm::from_name(lmots_name),
+        ) {
+            (
+                Some((lms_algorithm, lms_hash_name, lms_output_size)),
+                Some((lmots_algorithm, lmots_hash_name, lmots_output_size)),
+            ) if matches_hash(lms_hash_name, lms_output_size)
+                && matches_hash(lmots_hash_name, lmots_output_size) =>
+            {
+                Ok(HssParameter::new(lmots_algorithm, lms_algorithm))
+            }
+            _ => Err(Error::new()),
+        }
+    }
 }
 
 impl<H: HashChain> HssParameter<H> {
@@ -55,3 +162,133 @@ impl<H: HashChain> Default for HssParameter<H> {
         HssParameter::new(lmots_parameter, lms_parameter)
     }
 }
+
+#[cfg(all(test, feature = "sha256"))]
+mod tests {
+    use super::HssParameter;
+
+    use core::{fmt::Write, str::FromStr};
+    use tinyvec::ArrayVec;
+
+    use crate::{
+        hasher::sha256::{Sha256_128, Sha256_192, Sha256_256},
+        LmotsAlgorithm, LmsAlgorithm,
+    };
+
+    struct Buffer(ArrayVec<[u8; 512]>);
+
+    impl Write for Buffer {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            self.0.extend_from_slice(s.as_bytes());
+            Ok(())
+        }
+    }
+
+    fn assert_display(value: impl core::fmt::Display, expected: &str) {
+        let mut buffer = Buffer(ArrayVec::new());
+        write!(buffer, "{}", value).unwrap();
+        assert_eq!(buffer.0.as_slice(), expected.as_bytes());
+    }
+
+    #[test]
+    fn algorithm_names() {
+        assert_display(LmsAlgorithm::LmsH10, "LMS_SHA256_M32_H10");
+        assert_display(LmotsAlgorithm::LmotsW4, "LMOTS_SHA256_N32_W4");
+        assert_display(LmotsAlgorithm::LmotsReserved, "LMOTS_RESERVED");
+
+        assert_eq!(
+            LmsAlgorithm::from_str("LMS_SHA256_M32_H25").unwrap(),
+            LmsAlgorithm::LmsH25
+        );
+        assert_eq!(
+            LmsAlgorithm::from_str("LMS_SHAKE_M24_H5").unwrap(),
+            LmsAlgorithm::LmsH5
+        );
+        assert_eq!(
+            LmotsAlgorithm::from_str("LMOTS_SHA256_N24_W8").unwrap(),
+            LmotsAlgorithm::LmotsW8
+        );
+
+        assert!(LmsAlgorithm::from_str("LMS_SHA256_M32_H2").is_err());
+        assert!(LmsAlgorithm::from_str("LMS_SHA256_M16_H5").is_err());
+        assert!(LmsAlgorithm::from_str("LMS_SHA512_M32_H5").is_err());
+        assert!(LmsAlgorithm::from_str("LMS_SHA256_M32_H5_").is_err());
+        assert!(LmsAlgorithm::from_str("LMOTS_SHA256_N32_W1").is_err());
+        assert!(LmotsAlgorithm::from_str("LMOTS_SHA256_N32_W3").is_err());
+        assert!(LmotsAlgorithm::from_str("lmots_sha256_n32_w1").is_err());
+        assert!(LmotsAlgorithm::from_str("").is_err());
+    }
+
+    #[test]
+    fn parameter_names() {
+        let parameter =
+            HssParameter::<Sha256_256>::new(LmotsAlgorithm::LmotsW4, LmsAlgorithm::LmsH10);
+        assert_display(parameter, "LMS_SHA256_M32_H10/LMOTS_SHA256_N32_W4");
+        assert!(parameter.has_iana_name());
+
+        let parameter =
+            HssParameter::<Sha256_192>::new(LmotsAlgorithm::LmotsW4, LmsAlgorithm::LmsH10);
+        assert_display(parameter, "LMS_SHA256_M24_H10/LMOTS_SHA256_N24_W4");
+        assert!(!parameter.has_iana_name());
+
+        let parameter =
+            HssParameter::<Sha256_128>::new(LmotsAlgorithm::LmotsW8, LmsAlgorithm::LmsH5);
+        assert_display(parameter, "LMS_SHA256_M16_H5/LMOTS_SHA256_N16_W8");
+        assert!(!parameter.has_iana_name());
+        assert!(
+            HssParameter::<Sha256_128>::from_str("LMS_SHA256_M16_H5/LMOTS_SHA256_N16_W8").unwrap()
+                == parameter
+        );
+
+        let name = "LMS_SHA256_M24_H5/LMOTS_SHA256_N24_W8";
+        assert!(HssParameter::<Sha256_192>::from_str(name).is_ok());
+        assert!(HssParameter::<Sha256_256>::from_str(name).is_err());
+        assert!(HssParameter::<Sha256_192>::from_str("LMS_SHA256_M24_H5").is_err());
+        assert!(
+            HssParameter::<Sha256_192>::from_str("LMOTS_SHA256_N24_W8/LMS_SHA256_M24_H5").is_err()
+        );
+    }
+
+    #[cfg(feature = "shake256")]
+    #[test]
+    fn shake_parameter_names() {
+        use crate::hasher::shake256::{Shake256_192, Shake256_256};
+
+        let parameter =
+            HssParameter::<Shake256_192>::new(LmotsAlgorithm::LmotsW2, LmsAlgorithm::LmsH15);
+        assert_display(parameter, "LMS_SHAKE_M24_H15/LMOTS_SHAKE_N24_W2");
+        assert!(!parameter.has_iana_name());
+        assert!(
+            HssParameter::<Shake256_192>::from_str("LMS_SHAKE_M24_H15/LMOTS_SHAKE_N24_W2").unwrap()
+                == parameter
+        );
+
+        let name = "LMS_SHA256_M24_H5/LMOTS_SHA256_N24_W8";
+        assert!(HssParameter::<Shake256_192>::from_str(name).is_err());
+        assert!(
+            HssParameter::<Shake256_256>::from_str("LMS_SHAKE_M32_H5/LMOTS_SHAKE_N24_W8").is_err()
+        );
+    }
+
+    #[test]
+    fn parameter_lists() {
+        let list = "LMS_SHA256_M32_H10/LMOTS_SHA256_N32_W4,LMS_SHA256_M32_H5/LMOTS_SHA256_N32_W8";
+
+        let parameters = HssParameter::<Sha256_256>::parse_list(list).unwrap();
+        assert_eq!(parameters.len(), 2);
+        assert!(parameters[0] == HssParameter::new(LmotsAlgorithm::LmotsW4, LmsAlgorithm::LmsH10));
+        assert!(parameters[1] == HssParameter::new(LmotsAlgorithm::LmotsW8, LmsAlgorithm::LmsH5));
+        assert_display(HssParameter::display_list(&parameters), list);
+
+        assert!(HssParameter::<Sha256_256>::parse_list("").is_err());
+        assert!(HssParameter::<Sha256_256>::parse_list(&list[..list.len() - 1]).is_err());
+
+        let mut too_many = Buffer(ArrayVec::new());
+        write!(too_many, "{}", list).unwrap();
+        for _ in 0..crate::constants::MAX_ALLOWED_HSS_LEVELS - 1 {
+            write!(too_many, ",LMS_SHA256_M32_H5/LMOTS_SHA256_N32_W8").unwrap();
+        }
+        let too_many = core::str::from_utf8(too_many.0.as_slice()).unwrap();
+        assert!(HssParameter::<Sha256_256>::parse_list(too_many).is_err());
+    }
+}
@@ -0,0 +1,387 @@
+use alloc::vec::Vec;
+use tinyvec::ArrayVec;
+
+use crate::{
+    constants::MAX_ALLOWED_HSS_LEVELS,
+    hasher::{HashChain, HashFamily},
+    hss::{cost::ParameterSetCost, parameter::HssParameter},
+    LmotsAlgorithm, LmsAlgorithm,
+};
+
+const LMS_ALGORITHMS: [(LmsAlgorithm, u32); 5] = [
+    (LmsAlgorithm::LmsH5, 5),
+    (LmsAlgorithm::LmsH10, 10),
+    (LmsAlgorithm::LmsH15, 15),
+    (LmsAlgorithm::LmsH20, 20),
+    (LmsAlgorithm::LmsH25, 25),
+];
+
+const LMOTS_ALGORITHMS: [LmotsAlgorithm; 4] = [
+    LmotsAlgorithm::LmotsW1,
+    LmotsAlgorithm::LmotsW2,
+    LmotsAlgorithm::LmotsW4,
+    LmotsAlgorithm::LmotsW8,
+];
+
+type Algorithms = ArrayVec<[(LmotsAlgorithm, LmsAlgorithm); MAX_ALLOWED_HSS_LEVELS]>;
+
+/**
+ * Requirements a parameter set has to fulfil.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DeploymentConstraints {
+    pub min_signatures: u64,
+    pub max_signature_length: usize,
+    /// Budget for the worst case verification, see [`ParameterSetCost`].
+    pub max_verify_hash_compressions: u64,
+    /// Budget for the private key and the aux data.
+    pub max_signer_memory: usize,
+}
+
+/**
+ * Criterion the recommendations are ranked by, the smallest value first.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Objective {
+    SignatureLength,
+    VerifyTime,
+    SignTime,
+    KeygenTime,
+    SignerMemory,
+}
+
+/**
+ * A parameter set fulfilling the [`DeploymentConstraints`].
+ */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Recommendation {
+    pub hash_family: HashFamily,
+    /// The algorithms of each HSS level, starting with the top level.
+    pub algorithms: Algorithms,
+    pub cost: ParameterSetCost,
+    /// Either [`ParameterSetCost::recommended_aux_data_length`] or zero, if the aux data does
+    /// not fit into the memory budget.
+    pub aux_data_length: usize,
+    /// Signing costs with the aux data of `aux_data_length`.
+    pub sign_hash_compressions: u64,
+    pub signer_memory: usize,
+}
+
+impl Recommendation {
+    /**
+     * Returns the parameters for [`crate::keygen`], if `H` belongs to the hash family of the
+     * recommendation.
+     */
+    pub fn parameters<H: HashChain>(
+        &self,
+    ) -> Option<ArrayVec<[HssParameter<H>; MAX_ALLOWED_HSS_LEVELS]>> {
+        if H::OUTPUT_SIZE != self.hash_family.output_size()
+            || H::IANA_NAME != self.hash_family.iana_name()
+        {
+            return None;
+        }
+
+        Some(
+            self.algorithms
+                .iter()
+                .map(|&(lmots_algorithm, lms_algorithm)| {
+                    HssParameter::new(lmots_algorithm, lms_algorithm)
+                })
+                .collect(),
+        )
+    }
+
+    fn objective(&self, objective: Objective) -> u64 {
+        match objective {
+            Objective::SignatureLength => self.cost.signature_length as u64,
+            Objective::VerifyTime => self.cost.verify_worst_hash_compressions,
+            Objective::SignTime => self.sign_hash_compressions,
+            Objective::KeygenTime => self.cost.keygen_hash_compressions,
+            Objective::SignerMemory => self.signer_memory as u64,
+        }
+    }
+
+    fn objectives(&self) -> [u64; 5] {
+        [
+            self.objective(Objective::SignatureLength),
+            self.objective(Objective::VerifyTime),
+            self.objective(Objective::SignTime),
+            self.objective(Objective::KeygenTime),
+            self.objective(Objective::SignerMemory),
+        ]
+    }
+
+    fn dominates(&self, other: &Self) -> bool {
+        let (own, other) = (self.objectives(), other.objectives());
+        own.iter()
+            .zip(other.iter())
+            .all(|(own, other)| own <= other)
+            && own.iter().zip(other.iter()).any(|(own, other)| own < other)
+    }
+}
+
+/**
+ * Enumerates parameter sets of the given hash families, which fulfil `constraints`, and returns
+ * the Pareto-optimal ones ranked by `objective`.
+ *
+ * Only parameter sets whose lifetime gets sufficient with the last level are considered, as
+ * further levels only add costs. The lower levels share one Winternitz parameter, which may
+ * differ from the one of the top level.
+ *
+ * ```
+ * use hbs_lms::{recommend_parameters, DeploymentConstraints, HashFamily, Objective, Sha256_256};
+ *
+ * let constraints = DeploymentConstraints {
+ *     min_signatures: 1 << 20,
+ *     max_signature_length: 6_000,
+ *     max_verify_hash_compressions: 20_000,
+ *     max_signer_memory: 100_000,
+ * };
+ *
+ * let recommendations =
+ *     recommend_parameters(&constraints, &[HashFamily::Sha256_256], Objective::SignTime);
+ * let parameters = recommendations[0].parameters::<Sha256_256>().unwrap();
+ * ```
+ */
+pub fn recommend_parameters(
+    constraints: &DeploymentConstraints,
+    hash_families: &[HashFamily],
+    objective: Objective,
+) -> Vec<Recommendation> {
+    let mut tree_heights = Vec::new();
+    collect_tree_heights(
+        constraints.min_signatures.max(1),
+        &mut Algorithms::new(),
+        1,
+        &mut tree_heights,
+    );
+
+    let mut front: Vec<Recommendation> = Vec::new();
+
+    for &hash_family in hash_families {
+        for lms_algorithms in &tree_heights {
+            for &top_lmots_algorithm in LMOTS_ALGORITHMS.iter() {
+                for &lower_lmots_algorithm in LMOTS_ALGORITHMS.iter() {
+                    if lms_algorithms.len() == 1 && lower_lmots_algorithm != top_lmots_algorithm {
+                        continue;
+                    }
+
+                    let algorithms: Algorithms = lms_algorithms
+                        .iter()
+                        .enumerate()
+                        .map(|(level, &(_, lms_algorithm))| {
+                            let lmots_algorithm = if level == 0 {
+                                top_lmots_algorithm
+                            } else {
+                                lower_lmots_algorithm
+                            };
+                            (lmots_algorithm, lms_algorithm)
+                        })
+                        .collect();
+
+                    if let Some(candidate) = evaluate(constraints, hash_family, algorithms) {
+                        if front.iter().all(|other| !other.dominates(&candidate)) {
+                            front.retain(|other| !candidate.dominates(other));
+                            front.push(candidate);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    front.sort_by_key(|recommendation| {
+        (
+            recommendation.objective(objective),
+            recommendation.objectives(),
+        )
+    });
+    front
+}
+
+/// Collects the sequences of tree heights, whose lifetime reaches `min_signatures` with the last
+/// level only. The Winternitz parameters are filled in later.
+fn collect_tree_heights(
+    min_signatures: u64,
+    prefix: &mut Algorithms,
+    lifetime: u64,
+    result: &mut Vec<Algorithms>,
+) {
+    if prefix.len() == MAX_ALLOWED_HSS_LEVELS {
+        return;
+    }
+
+    for &(lms_algorithm, tree_height) in LMS_ALGORITHMS.iter() {
+        let lifetime = lifetime.saturating_mul(1u64 << tree_height);
+        prefix.push((LmotsAlgorithm::LmotsReserved, lms_algorithm));
+
+        if lifetime >= min_signatures {
+            result.push(*prefix);
+        } else {
+            collect_tree_heights(min_signatures, prefix, lifetime, result);
+        }
+
+        prefix.pop();
+    }
+}
+
+fn evaluate(
+    constraints: &DeploymentConstraints,
+    hash_family: HashFamily,
+    algorithms: Algorithms,
+) -> Option<Recommendation> {
+    let cost = ParameterSetCost::new(
+        hash_family.output_size(),
+        hash_family.construction(),
+        &algorithms,
+    )?;
+
+    let memory_with_aux = cost
+        .private_key_length
+        .saturating_add(cost.recommended_aux_data_length);
+    let (aux_data_length, sign_hash_compressions, signer_memory) =
+        if memory_with_aux <= constraints.max_signer_memory {
+            (
+                cost.recommended_aux_data_length,
+                cost.sign_with_aux_hash_compressions,
+                memory_with_aux,
+            )
+        } else {
+            (0, cost.sign_hash_compressions, cost.private_key_length)
+        };
+
+    let is_feasible = cost.lifetime >= constraints.min_signatures
+        && cost.signature_length <= constraints.max_signature_length
+        && cost.verify_worst_hash_compressions <= constraints.max_verify_hash_compressions
+        && signer_memory <= constraints.max_signer_memory;
+
+    if !is_feasible {
+        return None;
+    }
+
+    Some(Recommendation {
+        hash_family,
+        algorithms,
+        cost,
+        aux_data_length,
+        sign_hash_compressions,
+        signer_memory,
+    })
+}
+
+#[cfg(all(test, feature = "sha256"))]
+mod tests {
+    use super::{recommend_parameters, DeploymentConstraints, HashFamily, Objective};
+
+    use crate::{
+        hasher::sha256::{Sha256_192, Sha256_256},
+        LmotsAlgorithm, LmsAlgorithm,
+    };
+
+    const CONSTRAINTS: DeploymentConstraints = DeploymentConstraints {
+        min_signatures: 1 << 20,
+        max_signature_length: 6_000,
+        max_verify_hash_compressions: 20_000,
+        max_signer_memory: 100_000,
+    };
+
+    #[test]
+    fn recommendations_fulfil_constraints() {
+        let recommendations =
+            recommend_parameters(&CONSTRAINTS, &HashFamily::ALL, Objective::SignatureLength);
+        assert!(!recommendations.is_empty());
+
+        for recommendation in recommendations.iter() {
+            let cost = &recommendation.cost;
+            assert!(cost.lifetime >= CONSTRAINTS.min_signatures);
+            assert!(cost.signature_length <= CONSTRAINTS.max_signature_length);
+            assert!(
+                cost.verify_worst_hash_compressions <= CONSTRAINTS.max_verify_hash_compressions
+            );
+            assert!(recommendation.signer_memory <= CONSTRAINTS.max_signer_memory);
+
+            for other in recommendations.iter() {
+                assert!(!other.dominates(recommendation));
+            }
+        }
+
+        for pair in recommendations.windows(2) {
+            assert!(pair[0].cost.signature_length <= pair[1].cost.signature_length);
+        }
+    }
+
+    #[test]
+    fn rank_by_objective() {
+        for &objective in [
+            Objective::SignatureLength,
+            Objective::VerifyTime,
+            Objective::SignTime,
+            Objective::KeygenTime,
+            Objective::SignerMemory,
+        ]
+        .iter()
+        {
+            let recommendations =
+                recommend_parameters(&CONSTRAINTS, &[HashFamily::Sha256_256], objective);
+            assert!(recommendations
+                .windows(2)
+                .all(|pair| pair[0].objective(objective) <= pair[1].objective(objective)));
+            assert!(recommendations
+                .iter()
+                .all(|recommendation| recommendation.hash_family == HashFamily::Sha256_256));
+        }
+
+        // With a smaller hash, the shortest signature is shorter.
+        let shortest = |hash_family| {
+            recommend_parameters(&CONSTRAINTS, &[hash_family], Objective::SignatureLength)[0]
+                .cost
+                .signature_length
+        };
+        assert!(shortest(HashFamily::Sha256_192) < shortest(HashFamily::Sha256_256));
+    }
+
+    #[test]
+    fn unsatisfiable_constraints() {
+        let constraints = DeploymentConstraints {
+            max_signature_length: 1_000,
+            ..CONSTRAINTS
+        };
+        assert!(
+            recommend_parameters(&constraints, &HashFamily::ALL, Objective::SignTime).is_empty()
+        );
+
+        assert!(recommend_parameters(&CONSTRAINTS, &[], Objective::SignTime).is_empty());
+    }
+
+    #[test]
+    fn single_tree_for_few_signatures() {
+        let constraints = DeploymentConstraints {
+            min_signatures: 32,
+            max_signature_length: usize::MAX,
+            max_verify_hash_compressions: u64::MAX,
+            max_signer_memory: usize::MAX,
+        };
+
+        let recommendations =
+            recommend_parameters(&constraints, &[HashFamily::Sha256_256], Objective::SignTime);
+        assert!(recommendations
+            .iter()
+            .all(|recommendation| recommendation.algorithms.len() == 1
+                && recommendation.algorithms[0].1 == LmsAlgorithm::LmsH5));
+
+        let recommendation = &recommendations[0];
+        let parameters = recommendation.parameters::<Sha256_256>().unwrap();
+        assert_eq!(parameters.len(), 1);
+        assert!(
+            parameters[0].get_algorithms() == (recommendation.algorithms[0].0, LmsAlgorithm::LmsH5)
+        );
+        assert!(recommendation.parameters::<Sha256_192>().is_none());
+        #[cfg(feature = "shake256")]
+        assert!(recommendation
+            .parameters::<crate::hasher::shake256::Shake256_256>()
+            .is_none());
+
+        // The fastest signer uses the smallest Winternitz parameter.
+        assert_eq!(recommendation.algorithms[0].0, LmotsAlgorithm::LmotsW1);
+    }
+}
@@ -1,8 +1,9 @@
 use crate::{
     constants::{
         LmsTreeIdentifier, D_TOPSEED, ILEN, LMS_LEAF_IDENTIFIERS_SIZE, MAX_ALLOWED_HSS_LEVELS,
-        MAX_HASH_SIZE, MAX_SEED_LEN, REF_IMPL_MAX_PRIVATE_KEY_SIZE, SEED_CHILD_SEED,
-        SEED_SIGNATURE_RANDOMIZER_SEED, TOPSEED_D, TOPSEED_LEN, TOPSEED_SEED, TOPSEED_WHICH,
+        MAX_HASH_SIZE, MAX_PRIVATE_KEY_SIZE, MAX_SEED_LEN, REF_IMPL_MAX_PRIVATE_KEY_SIZE,
+        SEED_CHILD_SEED, SEED_SIGNATURE_RANDOMIZER_SEED, SIGNATURE_RANGE_SIZE, TOPSEED_D,
+        TOPSEED_LEN, TOPSEED_SEED, TOPSEED_WHICH,
     },
     hasher::HashChain,
     hss::{definitions::HssPrivateKey, seed_derive::SeedDerive},
@@ -10,10 +11,16 @@ use crate::{
     HssParameter, LmotsAlgorithm, LmsAlgorithm,
 };
 
-use core::{convert::TryFrom, convert::TryInto, marker::PhantomData};
+use core::{convert::TryFrom, convert::TryInto, fmt, marker::PhantomData, ops::Range};
 use tinyvec::ArrayVec;
+#[cfg(feature = "zeroize")]
+use {crate::util::helper::zeroize_array_vec, zeroize::Zeroize};
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+/**
+ * Secret seed of a private key. Its contents are redacted in the `Debug` output and wiped on drop
+ * with the `zeroize` feature.
+ */
+#[derive(Clone, PartialEq, Eq)]
 pub struct Seed<H: HashChain> {
     data: ArrayVec<[u8; MAX_SEED_LEN]>,
     phantom: PhantomData<H>,
@@ -34,13 +41,24 @@ impl<H: HashChain> Seed<H> {
     }
 }
 
-impl<H: HashChain> Copy for Seed<H> {}
+impl<H: HashChain> fmt::Debug for Seed<H> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Seed").finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<H: HashChain> Drop for Seed<H> {
+    fn drop(&mut self) {
+        zeroize_array_vec(&mut self.data);
+    }
+}
 
 impl<H: HashChain> From<[u8; MAX_SEED_LEN]> for Seed<H> {
     fn from(data: [u8; MAX_SEED_LEN]) -> Self {
         Seed {
             data: ArrayVec::from_array_len(data, H::OUTPUT_SIZE as usize),
-            phantom: PhantomData::default(),
+            phantom: PhantomData,
         }
     }
 }
@@ -52,7 +70,7 @@ impl<H: HashChain> TryFrom<ArrayVec<[u8; MAX_SEED_LEN]>> for Seed<H> {
         if value.len() == H::OUTPUT_SIZE as usize {
             Ok(Seed {
                 data: value,
-                phantom: PhantomData::default(),
+                phantom: PhantomData,
             })
         } else {
             Err("Can only construct seed from data of the HashChain output length")
@@ -106,11 +124,17 @@ impl<H: HashChain> SeedAndLmsTreeIdentifier<H> {
     }
 }
 
+/**
+ * Private key in the format of the reference implementation. A partitioned private key
+ * additionally holds the range of the signature counter it may use, which is appended to the
+ * encoding as start and end (exclusive).
+ */
 #[derive(Default, PartialEq, Eq)]
 pub struct ReferenceImplPrivateKey<H: HashChain> {
     pub compressed_used_leafs_indexes: CompressedUsedLeafsIndexes,
     pub compressed_parameter: CompressedParameterSet,
     pub seed: Seed<H>,
+    pub signature_range: Option<Range<u64>>,
 }
 
 impl<H: HashChain> ReferenceImplPrivateKey<H> {
@@ -118,6 +142,70 @@ impl<H: HashChain> ReferenceImplPrivateKey<H> {
         self.seed = Seed::default();
         self.compressed_parameter = CompressedParameterSet::default();
         self.compressed_used_leafs_indexes = CompressedUsedLeafsIndexes::new(0);
+        // Keeps the length of the encoding, but is never accepted again.
+        self.signature_range = self.signature_range.as_ref().map(|_| 0..0);
+    }
+
+    /// Total number of signatures of the key, ignoring its signature range.
+    pub fn total_signatures(&self) -> Result<u64, ()> {
+        let total_tree_height: u32 = self
+            .compressed_parameter
+            .to::<H>()?
+            .iter()
+            .map(|parameter| u32::from(parameter.get_lms_parameter().get_tree_height()))
+            .sum();
+
+        1u64.checked_shl(total_tree_height).ok_or(())
+    }
+
+    /// Range of the signature counter left to this key, starting with the next signature.
+    pub fn remaining_signatures(&self) -> Result<Range<u64>, ()> {
+        let end = match self.signature_range {
+            Some(ref signature_range) => signature_range.end,
+            None => self.total_signatures()?,
+        };
+
+        Ok(self.compressed_used_leafs_indexes.count()..end)
+    }
+
+    /**
+     * Moves the signatures from `at` to the end of the remaining range into a new private key,
+     * which shares the seed and parameters. This key keeps the signatures before `at`.
+     */
+    pub fn split_off(&mut self, at: u64) -> Result<Self, ()> {
+        let remaining = self.remaining_signatures()?;
+        if at <= remaining.start || at >= remaining.end {
+            return Err(());
+        }
+
+        let other = Self {
+            compressed_used_leafs_indexes: CompressedUsedLeafsIndexes::new(at),
+            compressed_parameter: CompressedParameterSet(self.compressed_parameter.0),
+            seed: self.seed.clone(),
+            signature_range: Some(at..remaining.end),
+        };
+        self.signature_range = Some(remaining.start..at);
+
+        Ok(other)
+    }
+
+    /**
+     * Skips the signatures before `count`, e.g. of a subtree whose parent leaf signed another
+     * tree. The key is wiped, if no signatures are left.
+     */
+    pub fn skip_to(&mut self, count: u64) -> Result<(), ()> {
+        let remaining = self.remaining_signatures()?;
+        if count < remaining.start || count > remaining.end {
+            return Err(());
+        }
+
+        if count == remaining.end {
+            self.wipe();
+        } else {
+            self.compressed_used_leafs_indexes = CompressedUsedLeafsIndexes::new(count);
+        }
+
+        Ok(())
     }
 
     pub fn generate(parameters: &[HssParameter<H>], seed: &Seed<H>) -> Result<Self, ()> {
@@ -135,18 +223,24 @@ impl<H: HashChain> ReferenceImplPrivateKey<H> {
         Ok(private_key)
     }
 
-    pub fn to_binary_representation(&self) -> ArrayVec<[u8; REF_IMPL_MAX_PRIVATE_KEY_SIZE]> {
+    pub fn to_binary_representation(&self) -> ArrayVec<[u8; MAX_PRIVATE_KEY_SIZE]> {
         let mut result = ArrayVec::new();
 
         result.extend_from_slice(&self.compressed_used_leafs_indexes.count.to_be_bytes());
         result.extend_from_slice(&self.compressed_parameter.0);
         result.extend_from_slice(self.seed.as_slice());
 
+        if let Some(ref signature_range) = self.signature_range {
+            result.extend_from_slice(&signature_range.start.to_be_bytes());
+            result.extend_from_slice(&signature_range.end.to_be_bytes());
+        }
+
         result
     }
 
     pub fn from_binary_representation(data: &[u8]) -> Result<Self, ()> {
-        if data.len() != REF_IMPL_MAX_PRIVATE_KEY_SIZE - MAX_SEED_LEN + H::OUTPUT_SIZE as usize {
+        let length = REF_IMPL_MAX_PRIVATE_KEY_SIZE - MAX_SEED_LEN + H::OUTPUT_SIZE as usize;
+        if data.len() != length && data.len() != length + SIGNATURE_RANGE_SIZE {
             return Err(());
         }
 
@@ -167,6 +261,18 @@ impl<H: HashChain> ReferenceImplPrivateKey<H> {
             .as_mut_slice()
             .copy_from_slice(read_and_advance(data, seed_len, &mut index));
 
+        if index < data.len() {
+            let start = read_and_advance(data, LMS_LEAF_IDENTIFIERS_SIZE, &mut index);
+            let end = read_and_advance(data, LMS_LEAF_IDENTIFIERS_SIZE, &mut index);
+            let signature_range = u64::from_be_bytes(start.try_into().unwrap())
+                ..u64::from_be_bytes(end.try_into().unwrap());
+
+            if !signature_range.contains(&result.compressed_used_leafs_indexes.count) {
+                return Err(());
+            }
+            result.signature_range = Some(signature_range);
+        }
+
         Ok(result)
     }
 
@@ -200,6 +306,12 @@ impl<H: HashChain> ReferenceImplPrivateKey<H> {
         let mut lms_tree_identifier = LmsTreeIdentifier::default();
         lms_tree_identifier.copy_from_slice(&hasher.finalize_reset()[..ILEN]);
 
+        #[cfg(feature = "zeroize")]
+        {
+            hash_preimage.zeroize();
+            zeroize_array_vec(&mut hash_postimage);
+        }
+
         SeedAndLmsTreeIdentifier::new(&seed, &lms_tree_identifier)
     }
 
@@ -209,6 +321,15 @@ impl<H: HashChain> ReferenceImplPrivateKey<H> {
             .iter()
             .map(|pk| pk.lms_parameter.get_tree_height())
             .collect();
+
+        // The last signature of the range was used, and the next one belongs to another key.
+        if let Some(ref signature_range) = self.signature_range {
+            if self.compressed_used_leafs_indexes.count + 1 >= signature_range.end {
+                self.wipe();
+                return;
+            }
+        }
+
         self.compressed_used_leafs_indexes
             .increment(&tree_heights)
             .unwrap_or_else(|_| self.wipe());
@@ -300,6 +421,10 @@ impl CompressedParameterSet {
             let lms = LmsAlgorithm::from(lms_type as u32);
             let lmots = LmotsAlgorithm::from(lmots_type as u32);
 
+            if lms == LmsAlgorithm::LmsReserved || lmots == LmotsAlgorithm::LmotsReserved {
+                return Err(());
+            }
+
             result.extend_from_slice(&[HssParameter::new(lmots, lms)]);
         }
 
@@ -321,6 +446,11 @@ impl CompressedUsedLeafsIndexes {
         CompressedUsedLeafsIndexes { count }
     }
 
+    /// Index of the next signature across all levels.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
     pub fn from_slice(data: &[u8]) -> Self {
         CompressedUsedLeafsIndexes {
             count: u64::from_be_bytes(data.try_into().unwrap()),
@@ -358,12 +488,13 @@ impl CompressedUsedLeafsIndexes {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "sha256"))]
 mod tests {
     use super::{CompressedParameterSet, ReferenceImplPrivateKey};
     use crate::{
-        constants::MAX_ALLOWED_HSS_LEVELS, hss::definitions::HssPrivateKey, HssParameter,
-        LmotsAlgorithm, LmsAlgorithm, Sha256_256,
+        constants::{MAX_ALLOWED_HSS_LEVELS, SIGNATURE_RANGE_SIZE},
+        hss::definitions::HssPrivateKey,
+        HssParameter, LmotsAlgorithm, LmsAlgorithm, Sha256_256,
     };
 
     use crate::util::helper::test_helper::gen_random_seed;
@@ -382,7 +513,7 @@ mod tests {
 
         let hss_private_key = HssPrivateKey::from(&rfc_private_key, &mut None).unwrap();
 
-        let seed = rfc_private_key.seed;
+        let seed = rfc_private_key.seed.clone();
 
         let tree_heights = parameters
             .iter()
@@ -464,4 +595,69 @@ mod tests {
 
         assert!(key == deserialized);
     }
+
+    #[test]
+    fn split_off_signature_range() {
+        let parameters = [HssParameter::<Hasher>::construct_default_parameters()];
+
+        let seed = gen_random_seed::<Hasher>();
+        let mut first = ReferenceImplPrivateKey::generate(&parameters, &seed).unwrap();
+        let hss_private_key = HssPrivateKey::from(&first, &mut None).unwrap();
+
+        assert!(first.split_off(0).is_err());
+        assert!(first.split_off(32).is_err());
+        let second = first.split_off(10).unwrap();
+        assert_eq!(first.remaining_signatures(), Ok(0..10));
+        assert_eq!(second.remaining_signatures(), Ok(10..32));
+        assert_eq!(second.compressed_used_leafs_indexes.count(), 10);
+
+        let binary_representation = second.to_binary_representation();
+        assert_eq!(binary_representation.len(), 48 + SIGNATURE_RANGE_SIZE);
+        assert!(
+            ReferenceImplPrivateKey::<Hasher>::from_binary_representation(
+                binary_representation.as_slice()
+            )
+            .unwrap()
+                == second
+        );
+
+        // A counter outside of the range is rejected.
+        let mut binary_representation = binary_representation;
+        binary_representation[7] = 9;
+        assert!(
+            ReferenceImplPrivateKey::<Hasher>::from_binary_representation(
+                binary_representation.as_slice()
+            )
+            .is_err()
+        );
+
+        // The key is wiped after the last signature of its range.
+        for _ in 0..10 {
+            assert_eq!(first.seed, seed);
+            first.increment(&hss_private_key);
+        }
+        assert_ne!(first.seed, seed);
+
+        let binary_representation = first.to_binary_representation();
+        assert_eq!(binary_representation.len(), 48 + SIGNATURE_RANGE_SIZE);
+        assert!(
+            ReferenceImplPrivateKey::<Hasher>::from_binary_representation(
+                binary_representation.as_slice()
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn reject_more_than_64_bit_of_signatures() {
+        let parameter = HssParameter::<Hasher>::new(LmotsAlgorithm::LmotsW8, LmsAlgorithm::LmsH25);
+
+        let seed = gen_random_seed::<Hasher>();
+        let private_key = ReferenceImplPrivateKey::generate(&[parameter; 2], &seed).unwrap();
+        assert_eq!(private_key.total_signatures(), Ok(1 << 50));
+
+        let private_key = ReferenceImplPrivateKey::generate(&[parameter; 3], &seed).unwrap();
+        assert!(private_key.total_signatures().is_err());
+        assert!(private_key.remaining_signatures().is_err());
+    }
 }
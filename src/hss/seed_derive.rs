@@ -1,4 +1,6 @@
 use tinyvec::ArrayVec;
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
 
 use crate::{
     constants::{
@@ -48,6 +50,11 @@ impl<'a, H: HashChain> SeedDerive<'a, H> {
             self.child_seed += 1;
         }
 
-        H::default().chain(buffer).finalize()
+        let result = H::default().chain(buffer).finalize();
+
+        #[cfg(feature = "zeroize")]
+        buffer.zeroize();
+
+        result
     }
 }
@@ -0,0 +1,297 @@
+use core::{
+    fmt,
+    sync::atomic::{AtomicU64, Ordering},
+};
+use std::sync::Mutex;
+
+#[cfg(feature = "zeroize")]
+use crate::util::helper::zeroize_array_vec;
+use crate::{
+    hasher::HashChain,
+    hss::{hss_sign, reference_impl_private_key::ReferenceImplPrivateKey, SigningKey},
+    signature::{Error, Signer},
+    Signature,
+};
+
+/**
+ * Signer, which can be shared between threads, e.g. the request handlers of a signing service.
+ *
+ * Every signature takes the next signature counter from an atomic counter, so the one-time
+ * signature and the authentication paths of different leafs are computed in parallel. Only the
+ * update of the private key is serialized. The update function is called with a private key,
+ * which has used all leafs up to the leaf of the signature, and the signature is only returned
+ * after the update succeeded.
+ *
+ * A leaf of a signature, which fails or whose update fails, is not used again. The private key
+ * given to [`SharedSigner::new`] must not be used for signing afterwards.
+ *
+ * ```
+ * use std::{sync::Arc, thread};
+ *
+ * use hbs_lms::{keygen, HssParameter, LmotsAlgorithm, LmsAlgorithm, SharedSigner};
+ * use hbs_lms::{signature::Signer, verify, Seed, Sha256_256};
+ *
+ * let parameters = [HssParameter::new(LmotsAlgorithm::LmotsW4, LmsAlgorithm::LmsH5)];
+ * let (signing_key, verifying_key) =
+ *     keygen::<Sha256_256>(&parameters, &Seed::default(), None).unwrap();
+ *
+ * // The update function must store the private key durably, e.g. in a file.
+ * let signer = Arc::new(SharedSigner::new(signing_key, |_: &[u8]| Ok(())).unwrap());
+ *
+ * let handlers: Vec<_> = (0..2)
+ *     .map(|_| {
+ *         let signer = Arc::clone(&signer);
+ *         thread::spawn(move || signer.try_sign(b"message").unwrap())
+ *     })
+ *     .collect();
+ *
+ * for handler in handlers {
+ *     let signature = handler.join().unwrap();
+ *     assert!(verify::<Sha256_256>(b"message", signature.as_ref(), verifying_key.as_slice()).is_ok());
+ * }
+ * ```
+ */
+pub struct SharedSigner<H: HashChain, F: FnMut(&[u8]) -> Result<(), ()> + Send> {
+    signing_key: SigningKey<H>,
+    end: u64,
+    next: AtomicU64,
+    commit: Mutex<Commit<F>>,
+}
+
+/// State of the last update of the private key.
+struct Commit<F> {
+    count: u64,
+    private_key_update_function: F,
+}
+
+impl<H: HashChain, F: FnMut(&[u8]) -> Result<(), ()> + Send> SharedSigner<H, F> {
+    /**
+     * Takes ownership of all remaining signatures of `signing_key`.
+     *
+     * # Arguments
+     * * `signing_key` - The private key that should be used.
+     * * `private_key_update_function` - The update function that is called with the new private
+     *   key. This function should save the new private key.
+     */
+    pub fn new(signing_key: SigningKey<H>, private_key_update_function: F) -> Result<Self, Error> {
+        let remaining = signing_key.signature_range()?;
+
+        Ok(Self {
+            signing_key,
+            end: remaining.end,
+            next: AtomicU64::new(remaining.start),
+            commit: Mutex::new(Commit {
+                count: remaining.start,
+                private_key_update_function,
+            }),
+        })
+    }
+
+    /**
+     * Returns the number of signatures, which have not been handed out yet.
+     */
+    pub fn remaining(&self) -> u64 {
+        self.end.saturating_sub(self.next.load(Ordering::Relaxed))
+    }
+
+    /**
+     * Generate a [`Signature`] with the next signature counter.
+     *
+     * # Arguments
+     * * `msg` - The message that should be signed.
+     * * `aux_data` - Auxiliary data to speedup signature generation if available. It can't be
+     *   shared between concurrent signatures.
+     */
+    pub fn try_sign_with_aux(
+        &self,
+        msg: &[u8],
+        aux_data: Option<&mut &mut [u8]>,
+    ) -> Result<Signature, Error> {
+        let counter = self.next.fetch_add(1, Ordering::Relaxed);
+        if counter >= self.end {
+            return Err(Error::new());
+        }
+
+        let signature = hss_sign::<H>(
+            msg,
+            self.private_key_at(counter)?.as_slice(),
+            &mut |_| Ok(()),
+            aux_data,
+        )?;
+
+        self.commit(counter + 1)?;
+
+        Ok(signature)
+    }
+
+    /// Private key, whose next signature uses `counter`.
+    fn private_key_at(&self, counter: u64) -> Result<SigningKey<H>, Error> {
+        let mut private_key =
+            ReferenceImplPrivateKey::<H>::from_binary_representation(self.signing_key.as_slice())
+                .map_err(|_| Error::new())?;
+        private_key.skip_to(counter).map_err(|_| Error::new())?;
+
+        #[allow(unused_mut)]
+        let mut new_private_key = private_key.to_binary_representation();
+        let signing_key = SigningKey::from_bytes(&new_private_key);
+
+        #[cfg(feature = "zeroize")]
+        zeroize_array_vec(&mut new_private_key);
+
+        signing_key
+    }
+
+    /// Updates the private key to use the signature counters from `count` on, if the signature
+    /// counters before are not recorded yet.
+    fn commit(&self, count: u64) -> Result<(), Error> {
+        let mut commit = self.commit.lock().map_err(|_| Error::new())?;
+        if count <= commit.count {
+            return Ok(());
+        }
+
+        let mut private_key =
+            ReferenceImplPrivateKey::<H>::from_binary_representation(self.signing_key.as_slice())
+                .map_err(|_| Error::new())?;
+        private_key.skip_to(count).map_err(|_| Error::new())?;
+
+        #[allow(unused_mut)]
+        let mut new_private_key = private_key.to_binary_representation();
+        let update_result = (commit.private_key_update_function)(&new_private_key);
+
+        #[cfg(feature = "zeroize")]
+        zeroize_array_vec(&mut new_private_key);
+
+        update_result.map_err(|_| Error::new())?;
+        commit.count = count;
+
+        Ok(())
+    }
+}
+
+impl<H: HashChain, F: FnMut(&[u8]) -> Result<(), ()> + Send> Signer<Signature>
+    for SharedSigner<H, F>
+{
+    fn try_sign(&self, msg: &[u8]) -> Result<Signature, Error> {
+        self.try_sign_with_aux(msg, None)
+    }
+}
+
+impl<H: HashChain, F: FnMut(&[u8]) -> Result<(), ()> + Send> fmt::Debug for SharedSigner<H, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedSigner")
+            .field("remaining", &self.remaining())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(all(test, feature = "sha256"))]
+mod tests {
+    use super::SharedSigner;
+
+    use std::{
+        collections::BTreeSet,
+        sync::{
+            atomic::{AtomicBool, Ordering},
+            Arc, Mutex,
+        },
+        thread,
+        vec::Vec,
+    };
+
+    use crate::{
+        hss::{hss_keygen, hss_verify, reference_impl_private_key::Seed, SigningKey},
+        signature::{Signer, SignerMut},
+        HssParameter, LmotsAlgorithm, LmsAlgorithm, Sha256_192, SignatureInfo,
+    };
+
+    type H = Sha256_192;
+
+    #[test]
+    fn sign_concurrently() {
+        let parameters = [
+            HssParameter::<H>::new(LmotsAlgorithm::LmotsW4, LmsAlgorithm::LmsH2),
+            HssParameter::<H>::new(LmotsAlgorithm::LmotsW4, LmsAlgorithm::LmsH2),
+        ];
+        let (signing_key, verifying_key) =
+            hss_keygen::<H>(&parameters, &Seed::default(), None).unwrap();
+        let range = signing_key.signature_range().unwrap();
+
+        let stored = Arc::new(Mutex::new(Vec::new()));
+        let signer = {
+            let stored = Arc::clone(&stored);
+            Arc::new(
+                SharedSigner::new(signing_key, move |private_key: &[u8]| {
+                    *stored.lock().unwrap() = private_key.to_vec();
+                    Ok(())
+                })
+                .unwrap(),
+            )
+        };
+
+        let handlers: Vec<_> = (0..4)
+            .map(|_| {
+                let signer = Arc::clone(&signer);
+                thread::spawn(move || {
+                    let mut signatures = Vec::new();
+                    while let Ok(signature) = signer.try_sign(b"message") {
+                        signatures.push(signature);
+                    }
+                    signatures
+                })
+            })
+            .collect();
+
+        let mut counters = BTreeSet::new();
+        for handler in handlers {
+            for signature in handler.join().unwrap() {
+                assert!(
+                    hss_verify::<H>(b"message", signature.as_ref(), verifying_key.as_slice())
+                        .is_ok()
+                );
+
+                let info = SignatureInfo::<H>::parse(signature.as_ref()).unwrap();
+                assert!(counters.insert(info.signature_counter().unwrap()));
+            }
+        }
+
+        assert_eq!(
+            counters.into_iter().collect::<Vec<_>>(),
+            range.collect::<Vec<_>>()
+        );
+        assert_eq!(signer.remaining(), 0);
+
+        // The stored private key has used all leafs.
+        let mut stored = SigningKey::<H>::from_bytes(&stored.lock().unwrap()).unwrap();
+        assert!(stored.try_sign(b"message").is_err());
+    }
+
+    #[test]
+    fn withhold_signature_if_update_fails() {
+        let parameters = [HssParameter::<H>::new(
+            LmotsAlgorithm::LmotsW4,
+            LmsAlgorithm::LmsH2,
+        )];
+        let (signing_key, verifying_key) =
+            hss_keygen::<H>(&parameters, &Seed::default(), None).unwrap();
+        let start = signing_key.signature_range().unwrap().start;
+
+        let fail = AtomicBool::new(true);
+        let signer = SharedSigner::new(signing_key, |_: &[u8]| {
+            if fail.swap(false, Ordering::Relaxed) {
+                Err(())
+            } else {
+                Ok(())
+            }
+        })
+        .unwrap();
+
+        assert!(signer.try_sign(b"message").is_err());
+
+        // The leaf of the failed signature is not used again.
+        let signature = signer.try_sign(b"message").unwrap();
+        assert!(hss_verify::<H>(b"message", signature.as_ref(), verifying_key.as_slice()).is_ok());
+
+        let info = SignatureInfo::<H>::parse(signature.as_ref()).unwrap();
+        assert_eq!(info.signature_counter(), Some(start + 1));
+    }
+}
@@ -0,0 +1,313 @@
+use core::{convert::TryInto, marker::PhantomData};
+use tinyvec::ArrayVec;
+
+use crate::{
+    constants::{LmsTreeIdentifier, MAX_ALLOWED_HSS_LEVELS},
+    hasher::HashChain,
+    lm_ots::parameters::LmotsAlgorithm,
+    lms::parameters::LmsAlgorithm,
+    signature::Error,
+    util::helper::read_and_advance,
+};
+
+/**
+ * Read-only view of an encoded HSS signature for auditing.
+ *
+ * The signature is only parsed, none of its hashes are checked. Thus, a parsed signature is not
+ * necessarily valid, see [`crate::verify`].
+ *
+ * ```
+ * use hbs_lms::{keygen, HssParameter, LmotsAlgorithm, LmsAlgorithm, Seed, Sha256_256};
+ * use hbs_lms::{signature::SignerMut, SignatureInfo};
+ *
+ * let parameters = [
+ *     HssParameter::<Sha256_256>::new(LmotsAlgorithm::LmotsW4, LmsAlgorithm::LmsH5),
+ *     HssParameter::<Sha256_256>::new(LmotsAlgorithm::LmotsW4, LmsAlgorithm::LmsH5),
+ * ];
+ * let (mut signing_key, _) = keygen::<Sha256_256>(&parameters, &Seed::default(), None).unwrap();
+ *
+ * let counter = signing_key.signature_range().unwrap().start;
+ * let signature = signing_key.try_sign(b"message").unwrap();
+ *
+ * let info = SignatureInfo::<Sha256_256>::parse(signature.as_ref()).unwrap();
+ * assert_eq!(info.levels().len(), 2);
+ * assert_eq!(u64::from(info.levels()[1].lms_leaf_identifier), counter);
+ * assert_eq!(info.signature_counter(), Some(counter));
+ * ```
+ */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignatureInfo<'a, H: HashChain> {
+    levels: ArrayVec<[LevelSignatureInfo<'a>; MAX_ALLOWED_HSS_LEVELS]>,
+    phantom_data: PhantomData<H>,
+}
+
+/**
+ * The LMS signature of a single HSS level, starting with the top level.
+ */
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LevelSignatureInfo<'a> {
+    /// The identifier `I` of the signing tree, which is taken from the public key signed by the
+    /// previous level. `None` for the top level, whose identifier is part of the HSS public key.
+    pub lms_tree_identifier: Option<&'a [u8]>,
+    /// The leaf `q` of the signing tree.
+    pub lms_leaf_identifier: u32,
+    pub lms_type: u32,
+    pub lmots_type: u32,
+    /// The randomizer `C` of the LM-OTS signature.
+    pub signature_randomizer: &'a [u8],
+    /// The complete encoded `lmots_signature`.
+    pub lmots_signature: &'a [u8],
+    /// The public key of the next level, which is signed on this level. `None` for the last level,
+    /// which signs the message.
+    pub child_public_key: Option<PublicKeyInfo<'a>>,
+}
+
+/**
+ * An LMS public key embedded into an HSS signature.
+ */
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PublicKeyInfo<'a> {
+    pub lms_type: u32,
+    pub lmots_type: u32,
+    pub lms_tree_identifier: &'a [u8],
+    pub key: &'a [u8],
+    /// The complete encoded `lms_public_key`.
+    pub bytes: &'a [u8],
+}
+
+impl<'a> LevelSignatureInfo<'a> {
+    pub fn lms_algorithm(&self) -> LmsAlgorithm {
+        LmsAlgorithm::from(self.lms_type)
+    }
+
+    pub fn lmots_algorithm(&self) -> LmotsAlgorithm {
+        LmotsAlgorithm::from(self.lmots_type)
+    }
+}
+
+impl<'a, H: HashChain> SignatureInfo<'a, H> {
+    /**
+     * Parses an encoded HSS signature.
+     *
+     * Returns an error, if the signature is truncated or has trailing data, a type code is not
+     * supported by `H` or a leaf identifier is out of the range of its tree.
+     */
+    pub fn parse(data: &'a [u8]) -> Result<Self, Error> {
+        let mut index = 0;
+
+        let signed_public_keys = u32::from_be_bytes(take(data, 4, &mut index)?.try_into().unwrap());
+        if signed_public_keys as usize >= MAX_ALLOWED_HSS_LEVELS {
+            return Err(Error::new());
+        }
+
+        let mut levels: ArrayVec<[LevelSignatureInfo<'a>; MAX_ALLOWED_HSS_LEVELS]> =
+            ArrayVec::new();
+        let mut lms_tree_identifier = None;
+
+        for level in 0..=signed_public_keys {
+            let mut level_info = parse_lms_signature::<H>(data, &mut index)?;
+            level_info.lms_tree_identifier = lms_tree_identifier;
+
+            if level < signed_public_keys {
+                let public_key = parse_lms_public_key::<H>(data, &mut index)?;
+                lms_tree_identifier = Some(public_key.lms_tree_identifier);
+                level_info.child_public_key = Some(public_key);
+            }
+
+            levels.push(level_info);
+        }
+
+        if index != data.len() {
+            return Err(Error::new());
+        }
+
+        Ok(Self {
+            levels,
+            phantom_data: PhantomData,
+        })
+    }
+
+    /// The signatures of all levels, starting with the top level.
+    pub fn levels(&self) -> &[LevelSignatureInfo<'a>] {
+        self.levels.as_slice()
+    }
+
+    /**
+     * The global index of the signature, i.e. the number of signatures generated before with the
+     * same key, if the key has been advanced one signature at a time. It is derived from the leaf
+     * identifiers and tree heights of all levels.
+     *
+     * Returns `None`, if the index doesn't fit into an `u64`.
+     */
+    pub fn signature_counter(&self) -> Option<u64> {
+        self.levels.iter().try_fold(0u64, |counter, level| {
+            let tree_height = LmsAlgorithm::get_from_type::<H>(level.lms_type)?.get_tree_height();
+
+            counter
+                .checked_mul(1u64.checked_shl(tree_height as u32)?)?
+                .checked_add(level.lms_leaf_identifier as u64)
+        })
+    }
+}
+
+fn take<'a>(data: &'a [u8], length: usize, index: &mut usize) -> Result<&'a [u8], Error> {
+    if data.len() - *index < length {
+        return Err(Error::new());
+    }
+
+    Ok(read_and_advance(data, length, index))
+}
+
+fn parse_lms_signature<'a, H: HashChain>(
+    data: &'a [u8],
+    index: &mut usize,
+) -> Result<LevelSignatureInfo<'a>, Error> {
+    let n = H::OUTPUT_SIZE as usize;
+
+    let lms_leaf_identifier = u32::from_be_bytes(take(data, 4, index)?.try_into().unwrap());
+
+    let lmots_signature_start = *index;
+    let lmots_type = u32::from_be_bytes(take(data, 4, index)?.try_into().unwrap());
+    let lmots_parameter = LmotsAlgorithm::get_from_type::<H>(lmots_type).ok_or_else(Error::new)?;
+    let signature_randomizer = take(data, n, index)?;
+    take(
+        data,
+        n * lmots_parameter.get_hash_chain_count() as usize,
+        index,
+    )?;
+    let lmots_signature = &data[lmots_signature_start..*index];
+
+    let lms_type = u32::from_be_bytes(take(data, 4, index)?.try_into().unwrap());
+    let lms_parameter = LmsAlgorithm::get_from_type::<H>(lms_type).ok_or_else(Error::new)?;
+    take(data, n * lms_parameter.get_tree_height() as usize, index)?;
+
+    if lms_leaf_identifier as usize >= lms_parameter.number_of_lm_ots_keys() {
+        return Err(Error::new());
+    }
+
+    Ok(LevelSignatureInfo {
+        lms_tree_identifier: None,
+        lms_leaf_identifier,
+        lms_type,
+        lmots_type,
+        signature_randomizer,
+        lmots_signature,
+        child_public_key: None,
+    })
+}
+
+fn parse_lms_public_key<'a, H: HashChain>(
+    data: &'a [u8],
+    index: &mut usize,
+) -> Result<PublicKeyInfo<'a>, Error> {
+    let start = *index;
+
+    let lms_type = u32::from_be_bytes(take(data, 4, index)?.try_into().unwrap());
+    LmsAlgorithm::get_from_type::<H>(lms_type).ok_or_else(Error::new)?;
+    let lmots_type = u32::from_be_bytes(take(data, 4, index)?.try_into().unwrap());
+    LmotsAlgorithm::get_from_type::<H>(lmots_type).ok_or_else(Error::new)?;
+
+    let lms_tree_identifier = take(data, core::mem::size_of::<LmsTreeIdentifier>(), index)?;
+    let key = take(data, H::OUTPUT_SIZE as usize, index)?;
+
+    Ok(PublicKeyInfo {
+        lms_type,
+        lmots_type,
+        lms_tree_identifier,
+        key,
+        bytes: &data[start..*index],
+    })
+}
+
+#[cfg(all(test, feature = "sign", feature = "sha256"))]
+mod tests {
+    use super::SignatureInfo;
+
+    use crate::{
+        hss::{hss_keygen, reference_impl_private_key::Seed},
+        signature::SignerMut,
+        HssParameter, LmotsAlgorithm, LmsAlgorithm, Sha256_192, Sha256_256,
+    };
+
+    #[test]
+    fn levels_and_counter() {
+        type H = Sha256_192;
+        let parameters = [
+            HssParameter::<H>::new(LmotsAlgorithm::LmotsW4, LmsAlgorithm::LmsH5),
+            HssParameter::<H>::new(LmotsAlgorithm::LmotsW2, LmsAlgorithm::LmsH5),
+        ];
+        let (mut signing_key, verifying_key) =
+            hss_keygen::<H>(&parameters, &Seed::default(), None).unwrap();
+        let start = signing_key.signature_range().unwrap().start;
+
+        for counter in start..start + 34 {
+            let signature = signing_key.try_sign(b"message").unwrap();
+            let info = SignatureInfo::<H>::parse(signature.as_ref()).unwrap();
+
+            assert_eq!(info.signature_counter(), Some(counter));
+
+            let levels = info.levels();
+            assert_eq!(levels.len(), 2);
+            assert_eq!(levels[0].lms_leaf_identifier as u64, counter / 32);
+            assert_eq!(levels[1].lms_leaf_identifier as u64, counter % 32);
+            assert_eq!(levels[0].lms_algorithm(), LmsAlgorithm::LmsH5);
+            assert_eq!(levels[0].lmots_algorithm(), LmotsAlgorithm::LmotsW4);
+            assert_eq!(levels[1].lmots_type, 2);
+            assert_eq!(levels[1].signature_randomizer.len(), 24);
+            assert_eq!(levels[1].lmots_signature.len(), 4 + 24 * (1 + 101));
+            assert_eq!(
+                &levels[1].lmots_signature[4..28],
+                levels[1].signature_randomizer
+            );
+
+            assert_eq!(levels[0].lms_tree_identifier, None);
+            let child_public_key = levels[0].child_public_key.unwrap();
+            assert_eq!(
+                levels[1].lms_tree_identifier,
+                Some(child_public_key.lms_tree_identifier)
+            );
+            assert_eq!(child_public_key.lmots_type, 2);
+            assert_eq!(child_public_key.key.len(), 24);
+            assert!(levels[1].child_public_key.is_none());
+
+            // The top tree identifier differs from the one of the child tree.
+            assert_ne!(
+                &verifying_key.as_slice()[12..28],
+                child_public_key.lms_tree_identifier
+            );
+        }
+    }
+
+    #[test]
+    fn reject_malformed_signatures() {
+        type H = Sha256_256;
+        let parameters = [HssParameter::<H>::new(
+            LmotsAlgorithm::LmotsW4,
+            LmsAlgorithm::LmsH5,
+        )];
+        let (mut signing_key, _) = hss_keygen::<H>(&parameters, &Seed::default(), None).unwrap();
+        let signature = signing_key.try_sign(b"message").unwrap();
+        let signature = signature.as_ref();
+
+        assert!(SignatureInfo::<H>::parse(signature).is_ok());
+        assert!(SignatureInfo::<Sha256_192>::parse(signature).is_err());
+
+        for length in 0..signature.len() {
+            assert!(SignatureInfo::<H>::parse(&signature[..length]).is_err());
+        }
+
+        let mut extended = [0u8; 3000];
+        extended[..signature.len()].copy_from_slice(signature);
+        assert!(SignatureInfo::<H>::parse(&extended[..signature.len() + 1]).is_err());
+
+        // Leaf identifier out of range
+        let mut modified = extended;
+        modified[4..8].copy_from_slice(&32u32.to_be_bytes());
+        assert!(SignatureInfo::<H>::parse(&modified[..signature.len()]).is_err());
+
+        // Number of signed public keys exceeds the maximum number of levels
+        let mut modified = extended;
+        modified[..4].copy_from_slice(&u32::MAX.to_be_bytes());
+        assert!(SignatureInfo::<H>::parse(&modified[..signature.len()]).is_err());
+    }
+}
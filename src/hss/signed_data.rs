@@ -0,0 +1,248 @@
+use cms::{
+    content_info::ContentInfo,
+    signed_data::{SignedAttributes, SignedData, SignerInfo},
+};
+use digest::Digest;
+use spki::{
+    der::{
+        asn1::OctetString,
+        oid::{
+            db::rfc5911::{ID_CONTENT_TYPE, ID_MESSAGE_DIGEST, ID_SIGNED_DATA},
+            AssociatedOid,
+        },
+        Any, Encode,
+    },
+    ObjectIdentifier,
+};
+#[cfg(feature = "sign")]
+use {
+    cms::{
+        cert::x509::attr::Attribute,
+        content_info::CmsVersion,
+        signed_data::{EncapsulatedContentInfo, SignerIdentifier, SignerInfos},
+    },
+    spki::{
+        der::{asn1::SetOfVec, oid::db::rfc5911::ID_DATA},
+        AlgorithmIdentifierOwned,
+    },
+};
+
+use crate::{
+    hasher::HashChain,
+    hss::{hss_verify, subject_public_key_info::ID_ALG_HSS_LMS_HASHSIG, VerifyingKey},
+};
+#[cfg(feature = "sign")]
+use crate::{hss::SigningKey, signature::SignerMut};
+
+/**
+ * Signs `content` as id-data and returns the SignedData wrapped into a ContentInfo.
+ *
+ * The content type and the message digest are placed into the signed attributes and the HSS
+ * signature is computed over their DER encoding (RFC 5652, Section 5.4).
+ */
+#[cfg(feature = "sign")]
+pub fn sign<H: HashChain, D: Digest + AssociatedOid>(
+    signing_key: &mut SigningKey<H>,
+    signer_identifier: SignerIdentifier,
+    content: &[u8],
+    detached: bool,
+) -> Result<ContentInfo, ()> {
+    let signed_attributes = build_signed_attributes::<D>(content).map_err(|_| ())?;
+
+    let signature = signing_key
+        .try_sign(&signed_attributes.to_der().map_err(|_| ())?)
+        .map_err(|_| ())?;
+
+    build_content_info::<D>(
+        signer_identifier,
+        signed_attributes,
+        signature.as_ref(),
+        content,
+        detached,
+    )
+    .map_err(|_| ())
+}
+
+#[cfg(feature = "sign")]
+fn build_signed_attributes<D: Digest>(content: &[u8]) -> spki::der::Result<SignedAttributes> {
+    let attribute = |oid, value| -> spki::der::Result<Attribute> {
+        let mut values = SetOfVec::new();
+        values.insert(value)?;
+        Ok(Attribute { oid, values })
+    };
+
+    let mut signed_attributes = SignedAttributes::new();
+    signed_attributes.insert(attribute(ID_CONTENT_TYPE, Any::encode_from(&ID_DATA)?)?)?;
+    signed_attributes.insert(attribute(
+        ID_MESSAGE_DIGEST,
+        Any::encode_from(&OctetString::new(D::digest(content).as_slice())?)?,
+    )?)?;
+
+    Ok(signed_attributes)
+}
+
+#[cfg(feature = "sign")]
+fn build_content_info<D: AssociatedOid>(
+    signer_identifier: SignerIdentifier,
+    signed_attributes: SignedAttributes,
+    signature: &[u8],
+    content: &[u8],
+    detached: bool,
+) -> spki::der::Result<ContentInfo> {
+    // The versions depend on the choice of the signer identifier (RFC 5652, Section 5.1 and 5.3).
+    let version = match signer_identifier {
+        SignerIdentifier::IssuerAndSerialNumber(_) => CmsVersion::V1,
+        SignerIdentifier::SubjectKeyIdentifier(_) => CmsVersion::V3,
+    };
+
+    let digest_algorithm = AlgorithmIdentifierOwned {
+        oid: D::OID,
+        parameters: None,
+    };
+
+    let mut signer_infos = SetOfVec::new();
+    signer_infos.insert(SignerInfo {
+        version,
+        sid: signer_identifier,
+        digest_alg: digest_algorithm.clone(),
+        signed_attrs: Some(signed_attributes),
+        signature_algorithm: AlgorithmIdentifierOwned {
+            oid: ID_ALG_HSS_LMS_HASHSIG,
+            parameters: None,
+        },
+        signature: OctetString::new(signature)?,
+        unsigned_attrs: None,
+    })?;
+
+    let mut digest_algorithms = SetOfVec::new();
+    digest_algorithms.insert(digest_algorithm)?;
+
+    let econtent = if detached {
+        None
+    } else {
+        Some(Any::encode_from(&OctetString::new(content)?)?)
+    };
+
+    let signed_data = SignedData {
+        version,
+        digest_algorithms,
+        encap_content_info: EncapsulatedContentInfo {
+            econtent_type: ID_DATA,
+            econtent,
+        },
+        certificates: None,
+        crls: None,
+        signer_infos: SignerInfos(signer_infos),
+    };
+
+    Ok(ContentInfo {
+        content_type: ID_SIGNED_DATA,
+        content: Any::encode_from(&signed_data)?,
+    })
+}
+
+/**
+ * Verifies a SignedData wrapped into a ContentInfo. Signer infos of other signature algorithms are
+ * skipped, at least one HSS signer info must verify with `verifying_key`.
+ */
+pub fn verify<H: HashChain, D: Digest + AssociatedOid>(
+    verifying_key: &VerifyingKey<H>,
+    content_info: &ContentInfo,
+    detached_content: Option<&[u8]>,
+) -> Result<(), ()> {
+    if content_info.content_type != ID_SIGNED_DATA {
+        return Err(());
+    }
+
+    let signed_data: SignedData = content_info.content.decode_as().map_err(|_| ())?;
+    let encap_content_info = &signed_data.encap_content_info;
+
+    let econtent: Option<OctetString> = match encap_content_info.econtent {
+        Some(ref econtent) => Some(econtent.decode_as().map_err(|_| ())?),
+        None => None,
+    };
+    let content = match (&econtent, detached_content) {
+        (Some(econtent), None) => econtent.as_bytes(),
+        (None, Some(detached_content)) => detached_content,
+        _ => return Err(()),
+    };
+
+    let is_verified = signed_data
+        .signer_infos
+        .0
+        .iter()
+        .filter(|signer_info| signer_info.signature_algorithm.oid == ID_ALG_HSS_LMS_HASHSIG)
+        .any(|signer_info| {
+            verify_signer_info::<H, D>(
+                verifying_key,
+                signer_info,
+                &encap_content_info.econtent_type,
+                content,
+            )
+            .is_ok()
+        });
+
+    if is_verified {
+        Ok(())
+    } else {
+        Err(())
+    }
+}
+
+fn verify_signer_info<H: HashChain, D: Digest + AssociatedOid>(
+    verifying_key: &VerifyingKey<H>,
+    signer_info: &SignerInfo,
+    econtent_type: &ObjectIdentifier,
+    content: &[u8],
+) -> Result<(), ()> {
+    if signer_info.signature_algorithm.parameters.is_some() || signer_info.digest_alg.oid != D::OID
+    {
+        return Err(());
+    }
+
+    let signature = signer_info.signature.as_bytes();
+
+    // Without signed attributes, the signature is computed over the content itself.
+    let signed_attributes = match signer_info.signed_attrs {
+        Some(ref signed_attributes) => signed_attributes,
+        None => {
+            return hss_verify::<H>(content, signature, verifying_key.as_slice()).map_err(|_| ())
+        }
+    };
+
+    let content_type: ObjectIdentifier =
+        single_attribute_value(signed_attributes, ID_CONTENT_TYPE)?
+            .decode_as()
+            .map_err(|_| ())?;
+    let message_digest: OctetString = single_attribute_value(signed_attributes, ID_MESSAGE_DIGEST)?
+        .decode_as()
+        .map_err(|_| ())?;
+
+    if content_type != *econtent_type || message_digest.as_bytes() != D::digest(content).as_slice()
+    {
+        return Err(());
+    }
+
+    hss_verify::<H>(
+        &signed_attributes.to_der().map_err(|_| ())?,
+        signature,
+        verifying_key.as_slice(),
+    )
+    .map_err(|_| ())
+}
+
+/// The content-type and message-digest attributes must be present exactly once, with exactly one
+/// value (RFC 5652, Section 11).
+fn single_attribute_value(
+    signed_attributes: &SignedAttributes,
+    oid: ObjectIdentifier,
+) -> Result<&Any, ()> {
+    let mut attributes = signed_attributes
+        .iter()
+        .filter(|attribute| attribute.oid == oid);
+
+    match (attributes.next(), attributes.next()) {
+        (Some(attribute), None) if attribute.values.len() == 1 => attribute.values.get(0).ok_or(()),
+        _ => Err(()),
+    }
+}
@@ -1,26 +1,32 @@
+#[cfg(feature = "fault_protection")]
+use crate::lm_ots::{self, signing::InMemoryLmotsSignature, verify::VerifyScratch};
+use crate::{
+    constants::{lms_public_key_length, lms_signature_length},
+    lms::{
+        definitions::{InMemoryLmsPublicKey, LmsPublicKey},
+        signing::{InMemoryLmsSignature, LmsSignature},
+    },
+    HashChain,
+};
+#[cfg(feature = "sign")]
 use crate::{
     constants::{
-        lms_public_key_length, lms_signature_length, MAX_ALLOWED_HSS_LEVELS,
-        MAX_HSS_SIGNATURE_LENGTH, MAX_HSS_SIGNED_PUBLIC_KEY_LENGTH,
+        MAX_ALLOWED_HSS_LEVELS, MAX_HSS_SIGNATURE_LENGTH, MAX_HSS_SIGNED_PUBLIC_KEY_LENGTH,
     },
     hss::{
         aux::MutableExpandedAuxData,
+        definitions::HssPrivateKey,
         reference_impl_private_key::{generate_signature_randomizer, SeedAndLmsTreeIdentifier},
     },
-    lms::{
-        self,
-        definitions::{InMemoryLmsPublicKey, LmsPublicKey},
-        signing::{InMemoryLmsSignature, LmsSignature},
-    },
-    util::helper::read_and_advance,
-    HashChain,
+    lms,
 };
 
-use super::definitions::HssPrivateKey;
-
-use core::convert::TryInto;
+#[cfg(feature = "sign")]
 use tinyvec::ArrayVec;
+#[cfg(all(test, feature = "sign", feature = "sha256"))]
+use {crate::util::helper::read_and_advance, core::convert::TryInto};
 
+#[cfg(feature = "sign")]
 #[derive(PartialEq)]
 pub struct HssSignature<H: HashChain> {
     pub level: usize,
@@ -28,17 +34,17 @@ pub struct HssSignature<H: HashChain> {
     pub signature: LmsSignature<H>,
 }
 
+#[cfg(feature = "sign")]
 impl<H: HashChain> HssSignature<H> {
     pub fn sign(
         private_key: &mut HssPrivateKey<H>,
         message: Option<&[u8]>,
-        message_mut: Option<&mut [u8]>,
+        #[allow(unused_mut)] mut message_mut: Option<&mut [u8]>,
         aux_data: &mut Option<MutableExpandedAuxData>,
     ) -> Result<HssSignature<H>, ()> {
         let max_level = private_key.get_length();
 
         let prv = &mut private_key.private_key;
-        let public = &mut private_key.public_key;
         let sig = &mut private_key.signatures;
 
         // Raise error, if array already contains a signature at index max_level - 1.
@@ -49,18 +55,23 @@ impl<H: HashChain> HssSignature<H> {
         // Sign the message
         #[allow(unused_mut)]
         let mut signature_randomizer = generate_signature_randomizer::<H>(
-            &SeedAndLmsTreeIdentifier {
-                seed: prv[max_level - 1].seed,
-                lms_tree_identifier: prv[max_level - 1].lms_tree_identifier,
-            },
+            &SeedAndLmsTreeIdentifier::new(
+                &prv[max_level - 1].seed,
+                &prv[max_level - 1].lms_tree_identifier,
+            ),
             &prv[max_level - 1].used_leafs_index,
         );
         let new_signature = if cfg!(feature = "fast_verify") && message_mut.is_some() {
+            // The randomized message is verified again with the `fault_protection` feature.
             #[cfg(feature = "fast_verify")]
+            #[cfg_attr(
+                not(feature = "fault_protection"),
+                allow(clippy::needless_option_as_deref)
+            )]
             let lms_sig = lms::signing::LmsSignature::sign_fast_verify(
                 &mut prv[max_level - 1],
                 None,
-                message_mut,
+                message_mut.as_deref_mut(),
                 &mut signature_randomizer,
                 aux_data,
             );
@@ -77,6 +88,11 @@ impl<H: HashChain> HssSignature<H> {
         }?;
         sig.push(new_signature);
 
+        #[cfg(feature = "fault_protection")]
+        check_for_faults(private_key, message.or(message_mut.as_deref()).ok_or(())?)?;
+        let public = &private_key.public_key;
+        let sig = &private_key.signatures;
+
         // Create list of signed keys
         let mut signed_public_keys = ArrayVec::new();
         for i in 0..max_level - 1 {
@@ -106,8 +122,71 @@ impl<H: HashChain> HssSignature<H> {
     }
 }
 
+/**
+ * Checks a freshly generated HSS signature for faults before it is released, see the
+ * `fault_protection` feature.
+ *
+ * The roots of the child trees are computed twice, when the trees are generated, see
+ * [`HssPrivateKey::from`]. Here, the signature of every child root is verified against the parent
+ * tree and the signature of the message against the bottom tree. The root of the top tree is not
+ * part of the private key, so the one-time signature of the top tree is verified against the
+ * regenerated one-time public key instead.
+ */
+#[cfg(feature = "fault_protection")]
+fn check_for_faults<H: HashChain>(
+    private_key: &HssPrivateKey<H>,
+    message: &[u8],
+) -> Result<(), ()> {
+    let max_level = private_key.get_length();
+    let prv = &private_key.private_key;
+    let public = &private_key.public_key;
+    let sig = &private_key.signatures;
+
+    let top_signature = sig[0].lmots_signature.to_binary_representation();
+    let top_signature = InMemoryLmotsSignature::<H>::new(top_signature.as_slice()).ok_or(())?;
+    let top_public_key =
+        lm_ots::keygen::generate_public_key(&lm_ots::keygen::generate_private_key(
+            prv[0].lms_tree_identifier,
+            sig[0].lms_leaf_identifier,
+            &prv[0].seed,
+            prv[0].lmots_parameter,
+        ));
+    let top_message = public
+        .first()
+        .map(|public_key| public_key.to_binary_representation());
+    let top_message = top_message
+        .as_ref()
+        .map_or(message, |public_key| public_key.as_slice());
+    if !lm_ots::verify::verify_signature_inmemory(&top_signature, &top_public_key, top_message) {
+        return Err(());
+    }
+
+    let mut scratch = VerifyScratch::<H>::default();
+    for i in 1..max_level {
+        let signature = sig[i].to_binary_representation();
+        let parent_public_key = public[i - 1].to_binary_representation();
+        let child_public_key = public
+            .get(i)
+            .map(|public_key| public_key.to_binary_representation());
+        let signed_message = child_public_key
+            .as_ref()
+            .map_or(message, |public_key| public_key.as_slice());
+
+        lms::verify::verify(
+            &InMemoryLmsSignature::new(signature.as_slice()).ok_or(())?,
+            &InMemoryLmsPublicKey::new(parent_public_key.as_slice()).ok_or(())?,
+            signed_message,
+            &mut scratch,
+        )?;
+    }
+
+    Ok(())
+}
+
 /// To reduce memory footprint on verification we handle the signature in-memory using ```InMemoryHssSignature```.
 /// In order to reduce complexity we use ```HssSignature``` for key generation and signature generation.
+/// Verification parses the signature level by level instead, so this is only used by the tests.
+#[cfg(all(test, feature = "sign", feature = "sha256"))]
 pub struct InMemoryHssSignature<'a, H: HashChain> {
     pub level: usize,
     pub signed_public_keys:
@@ -115,6 +194,7 @@ pub struct InMemoryHssSignature<'a, H: HashChain> {
     pub signature: InMemoryLmsSignature<'a, H>,
 }
 
+#[cfg(all(test, feature = "sign", feature = "sha256"))]
 impl<'a, H: HashChain> PartialEq<HssSignature<H>> for InMemoryHssSignature<'a, H> {
     fn eq(&self, other: &HssSignature<H>) -> bool {
         let first_condition = self.level == other.level && self.signature == other.signature;
@@ -141,6 +221,7 @@ impl<'a, H: HashChain> PartialEq<HssSignature<H>> for InMemoryHssSignature<'a, H
     }
 }
 
+#[cfg(all(test, feature = "sign", feature = "sha256"))]
 impl<'a, H: HashChain> InMemoryHssSignature<'a, H> {
     pub fn new(data: &'a [u8]) -> Option<Self> {
         let mut index = 0;
@@ -156,10 +237,7 @@ impl<'a, H: HashChain> InMemoryHssSignature<'a, H> {
             signed_public_keys.push(Some(signed_public_key));
         }
 
-        let signature = match InMemoryLmsSignature::<'a, H>::new(&data[index..]) {
-            None => return None,
-            Some(x) => x,
-        };
+        let signature = InMemoryLmsSignature::<'a, H>::new(&data[index..])?;
 
         Some(Self {
             level,
@@ -187,6 +265,7 @@ impl<'a, H: HashChain> PartialEq<HssSignedPublicKey<H>> for InMemoryHssSignedPub
     }
 }
 
+#[cfg(feature = "sign")]
 impl<H: HashChain> HssSignedPublicKey<H> {
     pub fn new(signature: LmsSignature<H>, public_key: LmsPublicKey<H>) -> Self {
         Self {
@@ -207,10 +286,7 @@ impl<H: HashChain> HssSignedPublicKey<H> {
 
 impl<'a, H: HashChain> InMemoryHssSignedPublicKey<'a, H> {
     pub fn new(data: &'a [u8]) -> Option<Self> {
-        let sig = match InMemoryLmsSignature::new(data) {
-            None => return None,
-            Some(x) => x,
-        };
+        let sig = InMemoryLmsSignature::new(data)?;
 
         let sig_size = lms_signature_length(
             sig.lmots_signature
@@ -220,10 +296,7 @@ impl<'a, H: HashChain> InMemoryHssSignedPublicKey<'a, H> {
             sig.lms_parameter.get_tree_height() as usize,
         );
 
-        let public_key = match InMemoryLmsPublicKey::new(&data[sig_size..]) {
-            None => return None,
-            Some(x) => x,
-        };
+        let public_key = InMemoryLmsPublicKey::new(data.get(sig_size..)?)?;
 
         Some(Self { sig, public_key })
     }
@@ -244,7 +317,7 @@ impl<'a, H: HashChain> InMemoryHssSignedPublicKey<'a, H> {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "sign", feature = "sha256"))]
 mod tests {
     use crate::{
         hasher::sha256::Sha256_256,
@@ -356,4 +429,38 @@ mod tests {
 
         assert!(deserialized == signature);
     }
+
+    #[cfg(feature = "fault_protection")]
+    #[test]
+    fn fault_protection_rejects_faulty_trees() {
+        let seed = gen_random_seed::<Hasher>();
+        let private_key = ReferenceImplPrivateKey::<Hasher>::generate(
+            &[
+                HssParameter::construct_default_parameters(),
+                HssParameter::construct_default_parameters(),
+                HssParameter::construct_default_parameters(),
+            ],
+            &seed,
+        )
+        .unwrap();
+        let message = [2, 56, 123, 22, 42, 49, 22];
+
+        let mut hss_private_key = HssPrivateKey::from(&private_key, &mut None).unwrap();
+        assert!(HssSignature::sign(&mut hss_private_key, Some(&message), None, &mut None).is_ok());
+
+        // Faulty root of the middle tree, signed by the top tree
+        let mut hss_private_key = HssPrivateKey::from(&private_key, &mut None).unwrap();
+        hss_private_key.public_key[0].key[0] ^= 1;
+        assert!(HssSignature::sign(&mut hss_private_key, Some(&message), None, &mut None).is_err());
+
+        // Faulty hash chain of the top tree
+        let mut hss_private_key = HssPrivateKey::from(&private_key, &mut None).unwrap();
+        hss_private_key.signatures[0].lmots_signature.signature_data[0][0] ^= 1;
+        assert!(HssSignature::sign(&mut hss_private_key, Some(&message), None, &mut None).is_err());
+
+        // Faulty hash chain of the middle tree
+        let mut hss_private_key = HssPrivateKey::from(&private_key, &mut None).unwrap();
+        hss_private_key.signatures[1].lmots_signature.signature_data[0][0] ^= 1;
+        assert!(HssSignature::sign(&mut hss_private_key, Some(&message), None, &mut None).is_err());
+    }
 }
@@ -0,0 +1,169 @@
+use core::convert::TryFrom;
+
+use spki::{
+    der::{asn1::BitStringRef, AnyRef, Document},
+    AlgorithmIdentifier, AlgorithmIdentifierRef, AssociatedAlgorithmIdentifier, EncodePublicKey,
+    ObjectIdentifier, SubjectPublicKeyInfoRef,
+};
+
+use crate::{hasher::HashChain, hss::VerifyingKey};
+
+/**
+ * `id-alg-hss-lms-hashsig` as defined in RFC 8708 and RFC 9708.
+ */
+pub const ID_ALG_HSS_LMS_HASHSIG: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.16.3.17");
+
+impl<H: HashChain> AssociatedAlgorithmIdentifier for VerifyingKey<H> {
+    type Params = AnyRef<'static>;
+
+    // The parameters field must be absent (RFC 9708, Section 3).
+    const ALGORITHM_IDENTIFIER: AlgorithmIdentifierRef<'static> = AlgorithmIdentifier {
+        oid: ID_ALG_HSS_LMS_HASHSIG,
+        parameters: None,
+    };
+}
+
+/**
+ * The subjectPublicKey contains the raw HSS public key of RFC 8554, without an additional
+ * OCTET STRING wrapping (RFC 9708, Section 3).
+ */
+impl<H: HashChain> EncodePublicKey for VerifyingKey<H> {
+    fn to_public_key_der(&self) -> spki::Result<Document> {
+        let public_key_info = SubjectPublicKeyInfoRef {
+            algorithm: Self::ALGORITHM_IDENTIFIER,
+            subject_public_key: BitStringRef::from_bytes(self.as_slice())?,
+        };
+
+        Ok(Document::encode_msg(&public_key_info)?)
+    }
+}
+
+/**
+ * Provides [`spki::DecodePublicKey`] for [`VerifyingKey`].
+ */
+impl<'a, H: HashChain> TryFrom<SubjectPublicKeyInfoRef<'a>> for VerifyingKey<H> {
+    type Error = spki::Error;
+
+    fn try_from(public_key_info: SubjectPublicKeyInfoRef<'a>) -> spki::Result<Self> {
+        public_key_info
+            .algorithm
+            .assert_algorithm_oid(ID_ALG_HSS_LMS_HASHSIG)?;
+
+        if public_key_info.algorithm.parameters.is_some() {
+            return Err(spki::Error::KeyMalformed);
+        }
+
+        let public_key = public_key_info
+            .subject_public_key
+            .as_bytes()
+            .ok_or(spki::Error::KeyMalformed)?;
+
+        VerifyingKey::from_checked_bytes(public_key).map_err(|_| spki::Error::KeyMalformed)
+    }
+}
+
+#[cfg(all(test, feature = "sign", feature = "sha256"))]
+mod tests {
+    use super::ID_ALG_HSS_LMS_HASHSIG;
+
+    use spki::{
+        der::{asn1::BitStringRef, AnyRef, Encode},
+        AlgorithmIdentifier, DecodePublicKey, EncodePublicKey, SubjectPublicKeyInfoRef,
+    };
+
+    use crate::{
+        hasher::sha256::{Sha256_192, Sha256_256},
+        hss::{hss_keygen, VerifyingKey},
+        util::helper::test_helper::gen_random_seed,
+        HssParameter, LmotsAlgorithm, LmsAlgorithm,
+    };
+
+    type H = Sha256_256;
+
+    fn verifying_key() -> VerifyingKey<H> {
+        let seed = gen_random_seed::<H>();
+        let (_, verifying_key) = hss_keygen::<H>(
+            &[
+                HssParameter::new(LmotsAlgorithm::LmotsW8, LmsAlgorithm::LmsH5),
+                HssParameter::new(LmotsAlgorithm::LmotsW8, LmsAlgorithm::LmsH5),
+            ],
+            &seed,
+            None,
+        )
+        .unwrap();
+        verifying_key
+    }
+
+    #[test]
+    fn der_round_trip() {
+        let verifying_key = verifying_key();
+        let der = verifying_key.to_public_key_der().unwrap();
+
+        // SEQUENCE { SEQUENCE { OID 1.2.840.113549.1.9.16.3.17 } BIT STRING }
+        let header: [u8; 19] = [
+            0x30, 0x4e, 0x30, 0x0d, 0x06, 0x0b, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x09,
+            0x10, 0x03, 0x11, 0x03, 0x3d,
+        ];
+        assert_eq!(&der.as_bytes()[..header.len()], &header);
+        assert_eq!(der.as_bytes()[header.len()], 0x00);
+        assert_eq!(
+            &der.as_bytes()[header.len() + 1..],
+            verifying_key.as_slice()
+        );
+
+        let decoded = VerifyingKey::<H>::from_public_key_der(der.as_bytes()).unwrap();
+        assert_eq!(decoded, verifying_key);
+    }
+
+    #[test]
+    fn pem_round_trip() {
+        let verifying_key = verifying_key();
+        let pem = verifying_key
+            .to_public_key_pem(spki::der::pem::LineEnding::LF)
+            .unwrap();
+
+        assert!(pem.starts_with("-----BEGIN PUBLIC KEY-----\n"));
+
+        let decoded = VerifyingKey::<H>::from_public_key_pem(&pem).unwrap();
+        assert_eq!(decoded, verifying_key);
+    }
+
+    #[test]
+    fn reject_malformed_public_key_info() {
+        let verifying_key = verifying_key();
+        let public_key = verifying_key.as_slice();
+
+        let encode = |oid, parameters, public_key| {
+            SubjectPublicKeyInfoRef {
+                algorithm: AlgorithmIdentifier { oid, parameters },
+                subject_public_key: BitStringRef::from_bytes(public_key).unwrap(),
+            }
+            .to_der()
+            .unwrap()
+        };
+
+        let der = encode(ID_ALG_HSS_LMS_HASHSIG, None, public_key);
+        assert!(VerifyingKey::<H>::from_public_key_der(&der).is_ok());
+
+        let wrong_oid = spki::ObjectIdentifier::new_unwrap("1.3.101.112");
+        let der = encode(wrong_oid, None, public_key);
+        assert!(VerifyingKey::<H>::from_public_key_der(&der).is_err());
+
+        let der = encode(ID_ALG_HSS_LMS_HASHSIG, Some(AnyRef::NULL), public_key);
+        assert!(VerifyingKey::<H>::from_public_key_der(&der).is_err());
+
+        let der = encode(
+            ID_ALG_HSS_LMS_HASHSIG,
+            None,
+            &public_key[..public_key.len() - 1],
+        );
+        assert!(VerifyingKey::<H>::from_public_key_der(&der).is_err());
+
+        let der = encode(ID_ALG_HSS_LMS_HASHSIG, None, &[0u8; 4]);
+        assert!(VerifyingKey::<H>::from_public_key_der(&der).is_err());
+
+        let der = encode(ID_ALG_HSS_LMS_HASHSIG, None, public_key);
+        assert!(VerifyingKey::<Sha256_192>::from_public_key_der(&der).is_err());
+    }
+}
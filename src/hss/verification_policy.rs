@@ -0,0 +1,276 @@
+use core::convert::TryInto;
+use tinyvec::ArrayVec;
+
+use crate::{
+    constants::{lms_public_key_length, MAX_ALLOWED_HSS_LEVELS},
+    hasher::{HashChain, HashFamily},
+    hss::{
+        cost::{HashConstruction, ParameterSetCost},
+        signature_info::SignatureInfo,
+    },
+    signature::Error,
+    LmotsAlgorithm, LmsAlgorithm,
+};
+
+/**
+ * Restricts the parameters of the signatures accepted by [`crate::verify_with_policy`].
+ *
+ * The policy is checked on the encoded signature and public key before any hash chain is
+ * computed, so signatures with expensive parameters (e.g. eight levels of W8) are rejected at the
+ * cost of parsing them. [`VerificationPolicy::check`] can be called before the other
+ * verification functions, e.g. [`crate::verify_with_scratch`].
+ *
+ * ```
+ * use hbs_lms::{keygen, HssParameter, LmotsAlgorithm, LmsAlgorithm, Seed, Sha256_256};
+ * use hbs_lms::{signature::SignerMut, VerificationPolicy};
+ *
+ * let parameters = [HssParameter::new(LmotsAlgorithm::LmotsW8, LmsAlgorithm::LmsH5)];
+ * let (mut signing_key, verifying_key) =
+ *     keygen::<Sha256_256>(&parameters, &Seed::default(), None).unwrap();
+ * let signature = signing_key.try_sign(b"message").unwrap();
+ *
+ * let policy = VerificationPolicy {
+ *     lmots_algorithms: &[LmotsAlgorithm::LmotsW2, LmotsAlgorithm::LmotsW4],
+ *     ..VerificationPolicy::SP_800_208
+ * };
+ *
+ * assert!(verifying_key
+ *     .verify_with_policy(b"message", signature.as_ref(), &VerificationPolicy::SP_800_208)
+ *     .is_ok());
+ * assert!(verifying_key
+ *     .verify_with_policy(b"message", signature.as_ref(), &policy)
+ *     .is_err());
+ * ```
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VerificationPolicy<'a> {
+    pub hash_families: &'a [HashFamily],
+    pub lms_algorithms: &'a [LmsAlgorithm],
+    pub lmots_algorithms: &'a [LmotsAlgorithm],
+    /// Maximum number of HSS levels of the public key.
+    pub max_levels: usize,
+    /// Budget for the worst case verification of the parameter set, see [`ParameterSetCost`].
+    pub max_verify_hash_compressions: u64,
+    /// Minimum security level in bits, i.e. eight times the output size of the hash function.
+    pub min_security_bits: u16,
+}
+
+impl VerificationPolicy<'static> {
+    /**
+     * The parameter sets approved by NIST SP 800-208: the SHA-256 and SHAKE256 hash functions
+     * with an output of 192 or 256 bits, the LMS and LM-OTS types of these hash functions and up
+     * to eight levels.
+     *
+     * The verification costs are not limited, as they depend on the deployment. Use the struct
+     * update syntax to tighten `max_levels` and `max_verify_hash_compressions`.
+     */
+    pub const SP_800_208: VerificationPolicy<'static> = VerificationPolicy {
+        hash_families: &HashFamily::ALL,
+        lms_algorithms: &[
+            LmsAlgorithm::LmsH5,
+            LmsAlgorithm::LmsH10,
+            LmsAlgorithm::LmsH15,
+            LmsAlgorithm::LmsH20,
+            LmsAlgorithm::LmsH25,
+        ],
+        lmots_algorithms: &[
+            LmotsAlgorithm::LmotsW1,
+            LmotsAlgorithm::LmotsW2,
+            LmotsAlgorithm::LmotsW4,
+            LmotsAlgorithm::LmotsW8,
+        ],
+        max_levels: MAX_ALLOWED_HSS_LEVELS,
+        max_verify_hash_compressions: u64::MAX,
+        min_security_bits: 192,
+    };
+}
+
+impl<'a> VerificationPolicy<'a> {
+    /**
+     * Checks the hash function, the type codes of all levels, the number of levels and the worst
+     * case verification costs of `signature` and `public_key` against the policy.
+     *
+     * Only the encodings are parsed, the signature itself is not verified.
+     *
+     * # Arguments
+     * * `HashChain` - The hasher implementation that should be used. ```Sha256``` is a standard software implementation.
+     * * `signature` - The signature that should be checked.
+     * * `public_key` - The public key that should be checked.
+     */
+    pub fn check<H: HashChain>(&self, signature: &[u8], public_key: &[u8]) -> Result<(), Error> {
+        let hash_family = HashFamily::of::<H>().ok_or_else(Error::new)?;
+        if !self.hash_families.contains(&hash_family) || 8 * H::OUTPUT_SIZE < self.min_security_bits
+        {
+            return Err(Error::new());
+        }
+
+        if public_key.len() != 4 + lms_public_key_length(H::OUTPUT_SIZE as usize) {
+            return Err(Error::new());
+        }
+
+        let levels = u32::from_be_bytes(public_key[..4].try_into().unwrap()) as usize;
+        let lms_type = u32::from_be_bytes(public_key[4..8].try_into().unwrap());
+        let lmots_type = u32::from_be_bytes(public_key[8..12].try_into().unwrap());
+        if levels > self.max_levels || !self.allows(lms_type, lmots_type) {
+            return Err(Error::new());
+        }
+
+        let info = SignatureInfo::<H>::parse(signature)?;
+        if info.levels().len() != levels {
+            return Err(Error::new());
+        }
+
+        let mut algorithms: ArrayVec<[(LmotsAlgorithm, LmsAlgorithm); MAX_ALLOWED_HSS_LEVELS]> =
+            ArrayVec::new();
+
+        for level in info.levels() {
+            if !self.allows(level.lms_type, level.lmots_type) {
+                return Err(Error::new());
+            }
+
+            if let Some(child_public_key) = level.child_public_key {
+                if !self.allows(child_public_key.lms_type, child_public_key.lmots_type) {
+                    return Err(Error::new());
+                }
+            }
+
+            algorithms.push((level.lmots_algorithm(), level.lms_algorithm()));
+        }
+
+        let cost = ParameterSetCost::new(
+            H::OUTPUT_SIZE,
+            HashConstruction::of::<H>(),
+            algorithms.as_slice(),
+        )
+        .ok_or_else(Error::new)?;
+        if cost.verify_worst_hash_compressions > self.max_verify_hash_compressions {
+            return Err(Error::new());
+        }
+
+        Ok(())
+    }
+
+    fn allows(&self, lms_type: u32, lmots_type: u32) -> bool {
+        let lms_algorithm = LmsAlgorithm::from(lms_type);
+        let lmots_algorithm = LmotsAlgorithm::from(lmots_type);
+
+        lms_algorithm != LmsAlgorithm::LmsReserved
+            && lmots_algorithm != LmotsAlgorithm::LmotsReserved
+            && self.lms_algorithms.contains(&lms_algorithm)
+            && self.lmots_algorithms.contains(&lmots_algorithm)
+    }
+}
+
+#[cfg(all(test, feature = "sign", feature = "sha256"))]
+mod tests {
+    use super::VerificationPolicy;
+
+    use crate::{
+        hss::{hss_keygen, hss_verify_with_policy, reference_impl_private_key::Seed},
+        signature::SignerMut,
+        HashChain, HashFamily, HssParameter, LmotsAlgorithm, LmsAlgorithm, ParameterSetCost,
+        Sha256_128, Sha256_192,
+    };
+
+    type H = Sha256_192;
+
+    #[test]
+    fn check_parameters() {
+        let parameters = [
+            HssParameter::<H>::new(LmotsAlgorithm::LmotsW4, LmsAlgorithm::LmsH5),
+            HssParameter::<H>::new(LmotsAlgorithm::LmotsW2, LmsAlgorithm::LmsH5),
+        ];
+        let (mut signing_key, verifying_key) =
+            hss_keygen::<H>(&parameters, &Seed::default(), None).unwrap();
+        let signature = signing_key.try_sign(b"message").unwrap();
+        let signature = signature.as_ref();
+        let public_key = verifying_key.as_slice();
+
+        let policy = VerificationPolicy::SP_800_208;
+        assert!(policy.check::<H>(signature, public_key).is_ok());
+        assert!(hss_verify_with_policy::<H>(b"message", signature, public_key, &policy).is_ok());
+        assert!(hss_verify_with_policy::<H>(b"massage", signature, public_key, &policy).is_err());
+
+        let rejected = [
+            VerificationPolicy {
+                hash_families: &[HashFamily::Sha256_256, HashFamily::Shake256_192],
+                ..policy
+            },
+            VerificationPolicy {
+                min_security_bits: 256,
+                ..policy
+            },
+            VerificationPolicy {
+                lms_algorithms: &[LmsAlgorithm::LmsH10],
+                ..policy
+            },
+            // Only used on the second level
+            VerificationPolicy {
+                lmots_algorithms: &[LmotsAlgorithm::LmotsW4],
+                ..policy
+            },
+            VerificationPolicy {
+                max_levels: 1,
+                ..policy
+            },
+        ];
+        for policy in rejected.iter() {
+            assert!(policy.check::<H>(signature, public_key).is_err());
+            assert!(
+                hss_verify_with_policy::<H>(b"message", signature, public_key, policy).is_err()
+            );
+        }
+
+        let cost = ParameterSetCost::from_parameters(&parameters).unwrap();
+        let within_budget = VerificationPolicy {
+            max_verify_hash_compressions: cost.verify_worst_hash_compressions,
+            ..policy
+        };
+        let over_budget = VerificationPolicy {
+            max_verify_hash_compressions: cost.verify_worst_hash_compressions - 1,
+            ..policy
+        };
+        assert!(within_budget.check::<H>(signature, public_key).is_ok());
+        assert!(over_budget.check::<H>(signature, public_key).is_err());
+
+        // Malformed encodings are rejected without panicking.
+        assert!(policy
+            .check::<H>(&signature[..signature.len() - 1], public_key)
+            .is_err());
+        assert!(policy.check::<H>(signature, &public_key[..11]).is_err());
+        assert!(policy.check::<H>(&[], public_key).is_err());
+    }
+
+    #[test]
+    fn reject_unapproved_hash_function() {
+        type H = Sha256_128;
+        let parameters = [HssParameter::<H>::new(
+            LmotsAlgorithm::LmotsW4,
+            LmsAlgorithm::LmsH5,
+        )];
+        let (mut signing_key, verifying_key) =
+            hss_keygen::<H>(&parameters, &Seed::default(), None).unwrap();
+        let signature = signing_key.try_sign(b"message").unwrap();
+
+        assert_eq!(8 * H::OUTPUT_SIZE, 128);
+        assert!(VerificationPolicy::SP_800_208
+            .check::<H>(signature.as_ref(), verifying_key.as_slice())
+            .is_err());
+    }
+
+    #[test]
+    fn reject_test_parameters() {
+        type H = Sha256_192;
+        let parameters = [HssParameter::<H>::new(
+            LmotsAlgorithm::LmotsW4,
+            LmsAlgorithm::LmsH2,
+        )];
+        let (mut signing_key, verifying_key) =
+            hss_keygen::<H>(&parameters, &Seed::default(), None).unwrap();
+        let signature = signing_key.try_sign(b"message").unwrap();
+
+        assert!(VerificationPolicy::SP_800_208
+            .check::<H>(signature.as_ref(), verifying_key.as_slice())
+            .is_err());
+    }
+}
@@ -1,48 +1,82 @@
+use core::convert::TryInto;
+
 use crate::{
     hasher::HashChain,
+    lm_ots::verify::VerifyScratch,
     lms::{self},
 };
 
-use super::{definitions::InMemoryHssPublicKey, signing::InMemoryHssSignature};
+use super::{definitions::InMemoryHssPublicKey, signing::InMemoryHssSignedPublicKey};
 
-pub fn verify<'a, H: HashChain>(
-    signature: &InMemoryHssSignature<'a, H>,
+/// Verifies a binary HSS signature while parsing it level by level.
+///
+/// The signed public keys are not collected into an `InMemoryHssSignature` first.
+/// Only the signed public key of the current level is held in memory, and all hashers are
+/// borrowed from `scratch`.
+pub fn verify_with_scratch<'a, H: HashChain>(
+    signature: &'a [u8],
     public_key: &InMemoryHssPublicKey<'a, H>,
     message: &[u8],
+    scratch: &mut VerifyScratch<H>,
 ) -> Result<(), ()> {
-    if signature.level + 1 != public_key.level {
+    if signature.len() < 4 {
         return Err(());
     }
 
-    let mut key = &public_key.public_key;
-    for i in 0..public_key.level - 1 {
-        let sig = &signature.signed_public_keys[i].as_ref().unwrap().sig;
-        let msg = &signature.signed_public_keys[i].as_ref().unwrap().public_key;
+    let (level, mut signature) = signature.split_at(4);
+    let level = u32::from_be_bytes(level.try_into().unwrap()) as usize;
 
-        if lms::verify::verify(sig, key, msg.as_slice()).is_err() {
-            return Err(());
-        }
-        key = msg;
+    if level + 1 != public_key.level {
+        return Err(());
+    }
+
+    let mut signed_public_key: Option<InMemoryHssSignedPublicKey<'a, H>> = None;
+    for _ in 0..level {
+        let next = InMemoryHssSignedPublicKey::<'a, H>::new(signature).ok_or(())?;
+        signature = &signature[next.len()..];
+
+        let key = signed_public_key
+            .as_ref()
+            .map_or(&public_key.public_key, |parent| &parent.public_key);
+        lms::verify::verify(&next.sig, key, next.public_key.as_slice(), scratch)?;
+
+        signed_public_key = Some(next);
     }
 
-    lms::verify::verify(&signature.signature, key, message)
+    let lms_signature = lms::signing::InMemoryLmsSignature::<'a, H>::new(signature).ok_or(())?;
+    let key = signed_public_key
+        .as_ref()
+        .map_or(&public_key.public_key, |parent| &parent.public_key);
+
+    lms::verify::verify(&lms_signature, key, message, scratch)
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "sign", feature = "sha256"))]
 mod tests {
     use crate::{
-        hasher::{sha256::Sha256_256, HashChain},
+        constants::{MAX_HASH_SIZE, VERIFY_MAX_STACK_USAGE},
+        hasher::{
+            sha256::{Sha256_192, Sha256_256},
+            HashChain,
+        },
         hss::{
             definitions::{HssPrivateKey, HssPublicKey, InMemoryHssPublicKey},
+            hss_keygen, hss_verify_with_scratch,
             reference_impl_private_key::ReferenceImplPrivateKey,
-            signing::{HssSignature, InMemoryHssSignature},
-            verify::verify,
+            signing::HssSignature,
+            verify::verify_with_scratch,
         },
-        HssParameter,
+        lm_ots::verify::VerifyScratch,
+        signature::SignerMut,
+        HssParameter, LmotsAlgorithm, LmsAlgorithm,
     };
 
     use crate::util::helper::test_helper::gen_random_seed;
 
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use digest::{FixedOutput, Output, OutputSizeUser, Update};
+    use tinyvec::ArrayVec;
+
     #[test]
     fn test_hss_verify() {
         type H = Sha256_256;
@@ -80,15 +114,169 @@ mod tests {
         };
 
         let mem_sig = signature.to_binary_representation();
-        let mem_sig = InMemoryHssSignature::<H>::new(mem_sig.as_slice()).unwrap();
 
         let mem_pub = public_key.to_binary_representation();
-        let mem_pub = InMemoryHssPublicKey::new(mem_pub.as_slice()).unwrap();
+        let mem_pub = InMemoryHssPublicKey::<H>::new(mem_pub.as_slice()).unwrap();
+
+        let mut scratch = VerifyScratch::default();
 
-        assert!(verify(&mem_sig, &mem_pub, message).is_ok());
+        assert!(verify_with_scratch(mem_sig.as_slice(), &mem_pub, message, &mut scratch).is_ok());
 
         message[0] = !message[0];
 
-        assert!(verify(&mem_sig, &mem_pub, message).is_err());
+        assert!(verify_with_scratch(mem_sig.as_slice(), &mem_pub, message, &mut scratch).is_err());
+    }
+
+    #[test]
+    fn reject_truncated_signatures() {
+        type H = Sha256_192;
+        let parameter = HssParameter::<H>::new(LmotsAlgorithm::LmotsW4, LmsAlgorithm::LmsH2);
+        let seed = gen_random_seed::<H>();
+        let (mut signing_key, verifying_key) =
+            hss_keygen(&[parameter, parameter], &seed, None).expect("Should generate HSS keys");
+
+        let message = [42u8, 57, 20, 59, 33, 1, 49, 3, 99, 130, 50, 20];
+        let signature = signing_key.try_sign(&message).unwrap();
+        let signature = signature.as_ref();
+        let public_key = verifying_key.as_slice();
+
+        let mut scratch = VerifyScratch::default();
+
+        for length in 0..signature.len() {
+            assert!(hss_verify_with_scratch::<H>(
+                &message,
+                &signature[..length],
+                public_key,
+                &mut scratch
+            )
+            .is_err());
+        }
+
+        for length in 0..public_key.len() {
+            assert!(hss_verify_with_scratch::<H>(
+                &message,
+                signature,
+                &public_key[..length],
+                &mut scratch
+            )
+            .is_err());
+        }
+
+        // The scratch space stays usable after failed verifications.
+        assert!(
+            hss_verify_with_scratch::<H>(&message, signature, public_key, &mut scratch).is_ok()
+        );
+    }
+
+    static LOWEST_STACK_ADDRESS: AtomicUsize = AtomicUsize::new(usize::MAX);
+
+    fn current_stack_address() -> usize {
+        let marker = 0u8;
+        &marker as *const u8 as usize
+    }
+
+    /// Wraps a hasher and records the deepest stack address it was called from.
+    #[derive(Debug, Default, Clone, PartialEq)]
+    struct StackProbe<H: HashChain>(H);
+
+    impl<H: HashChain> StackProbe<H> {
+        fn probe(&self) {
+            let address = current_stack_address();
+            LOWEST_STACK_ADDRESS.fetch_min(address, Ordering::Relaxed);
+        }
+    }
+
+    impl<H: HashChain> HashChain for StackProbe<H> {
+        const OUTPUT_SIZE: u16 = H::OUTPUT_SIZE;
+        const BLOCK_SIZE: u16 = H::BLOCK_SIZE;
+        const IANA_NAME: &'static str = H::IANA_NAME;
+
+        fn finalize(self) -> ArrayVec<[u8; MAX_HASH_SIZE]> {
+            self.probe();
+            self.0.finalize()
+        }
+
+        fn finalize_reset(&mut self) -> ArrayVec<[u8; MAX_HASH_SIZE]> {
+            self.probe();
+            self.0.finalize_reset()
+        }
+    }
+
+    impl<H: HashChain> OutputSizeUser for StackProbe<H> {
+        type OutputSize = <H as OutputSizeUser>::OutputSize;
+    }
+
+    impl<H: HashChain> FixedOutput for StackProbe<H> {
+        fn finalize_into(self, out: &mut Output<Self>) {
+            self.probe();
+            self.0.finalize_into(out);
+        }
+    }
+
+    impl<H: HashChain> Update for StackProbe<H> {
+        fn update(&mut self, data: &[u8]) {
+            self.probe();
+            self.0.update(data);
+        }
+    }
+
+    fn measure_verify_stack_usage<H: HashChain>(lmots: LmotsAlgorithm) -> usize {
+        let parameters = [
+            HssParameter::<StackProbe<H>>::new(lmots, LmsAlgorithm::LmsH5),
+            HssParameter::<StackProbe<H>>::new(lmots, LmsAlgorithm::LmsH5),
+        ];
+        let seed = gen_random_seed::<StackProbe<H>>();
+        let (mut signing_key, verifying_key) =
+            hss_keygen(&parameters, &seed, None).expect("Should generate HSS keys");
+
+        let message = [42u8, 57, 20, 59, 33, 1, 49, 3, 99, 130, 50, 20];
+        let signature = signing_key.try_sign(&message).unwrap();
+
+        let mut scratch = VerifyScratch::default();
+
+        LOWEST_STACK_ADDRESS.store(usize::MAX, Ordering::Relaxed);
+        let stack_start = current_stack_address();
+        hss_verify_with_scratch::<StackProbe<H>>(
+            &message,
+            signature.as_ref(),
+            verifying_key.as_slice(),
+            &mut scratch,
+        )
+        .expect("Signature should be valid");
+
+        stack_start - LOWEST_STACK_ADDRESS.load(Ordering::Relaxed)
+    }
+
+    // Unoptimized builds use considerably more stack, so the bound is only checked by release
+    // builds, e.g. `cargo test --release verify_stack_usage_is_bounded`.
+    #[test]
+    #[cfg_attr(debug_assertions, ignore)]
+    fn verify_stack_usage_is_bounded() {
+        for lmots in [
+            LmotsAlgorithm::LmotsW1,
+            LmotsAlgorithm::LmotsW2,
+            LmotsAlgorithm::LmotsW4,
+            LmotsAlgorithm::LmotsW8,
+        ] {
+            let sha256 = measure_verify_stack_usage::<Sha256_256>(lmots);
+            assert!(
+                sha256 < VERIFY_MAX_STACK_USAGE,
+                "{} >= {}",
+                sha256,
+                VERIFY_MAX_STACK_USAGE
+            );
+
+            #[cfg(feature = "shake256")]
+            {
+                let shake256 =
+                    measure_verify_stack_usage::<crate::hasher::shake256::Shake256_256>(lmots);
+                assert!(
+                    shake256 < VERIFY_MAX_STACK_USAGE,
+                    "{} >= {}",
+                    shake256,
+                    VERIFY_MAX_STACK_USAGE
+                );
+            }
+        }
     }
 }
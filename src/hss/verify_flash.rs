@@ -0,0 +1,472 @@
+use core::{marker::PhantomData, ops::Range};
+
+use embedded_storage::nor_flash::ReadNorFlash;
+use tinyvec::ArrayVec;
+
+use crate::{
+    constants::{
+        lms_public_key_length, D_INTR, D_LEAF, D_MESG, D_PBLC, MAX_HASH_SIZE,
+        MAX_HSS_PUBLIC_KEY_LENGTH, MAX_LMS_PUBLIC_KEY_LENGTH,
+    },
+    hasher::HashChain,
+    lm_ots::{parameters::LmotsAlgorithm, verify::VerifyScratch},
+    lms::{definitions::InMemoryLmsPublicKey, parameters::LmsAlgorithm},
+    util::{coef::coef, helper::is_odd},
+};
+
+use super::definitions::InMemoryHssPublicKey;
+
+const READ_BLOCK_SIZE: usize = 64;
+
+/// Sequential reader for a region of a NOR flash.
+///
+/// The flash is read in blocks aligned to [`ReadNorFlash::READ_SIZE`], so that the unaligned fields
+/// of a signature can be read from flashes with a read size larger than one byte.
+struct FlashReader<F: ReadNorFlash> {
+    offset: u32,
+    end: u32,
+    block: [u8; READ_BLOCK_SIZE],
+    block_start: u32,
+    block_len: usize,
+    phantom_data: PhantomData<F>,
+}
+
+impl<F: ReadNorFlash> FlashReader<F> {
+    fn new(flash: &F, region: Range<u32>) -> Result<Self, ()> {
+        if F::READ_SIZE == 0
+            || READ_BLOCK_SIZE % F::READ_SIZE != 0
+            || region.start > region.end
+            || region.end as usize > flash.capacity()
+        {
+            return Err(());
+        }
+
+        Ok(Self {
+            offset: region.start,
+            end: region.end,
+            block: [0u8; READ_BLOCK_SIZE],
+            block_start: 0,
+            block_len: 0,
+            phantom_data: PhantomData,
+        })
+    }
+
+    fn remaining(&self) -> usize {
+        (self.end - self.offset) as usize
+    }
+
+    fn seek(&mut self, offset: u32) -> Result<(), ()> {
+        if offset > self.end {
+            return Err(());
+        }
+        self.offset = offset;
+        Ok(())
+    }
+
+    fn skip(&mut self, len: usize) -> Result<(), ()> {
+        if len > self.remaining() {
+            return Err(());
+        }
+        self.offset += len as u32;
+        Ok(())
+    }
+
+    /// Returns the next bytes of the region, but not more than the rest of the current block.
+    fn next_chunk(&mut self, flash: &mut F, max_len: usize) -> Result<&[u8], ()> {
+        if max_len > self.remaining() {
+            return Err(());
+        }
+
+        let is_cached = self.offset >= self.block_start
+            && ((self.offset - self.block_start) as usize) < self.block_len;
+
+        if !is_cached {
+            let block_start = self.offset - self.offset % F::READ_SIZE as u32;
+            let available = flash.capacity() - block_start as usize;
+            let block_len = READ_BLOCK_SIZE.min(available - available % F::READ_SIZE);
+
+            flash
+                .read(block_start, &mut self.block[..block_len])
+                .map_err(|_| ())?;
+            self.block_start = block_start;
+            self.block_len = block_len;
+        }
+
+        let start = (self.offset - self.block_start) as usize;
+        let len = max_len.min(self.block_len - start);
+        self.offset += len as u32;
+
+        Ok(&self.block[start..start + len])
+    }
+
+    fn read_exact(&mut self, flash: &mut F, buffer: &mut [u8]) -> Result<(), ()> {
+        let mut index = 0;
+
+        while index < buffer.len() {
+            let chunk = self.next_chunk(flash, buffer.len() - index)?;
+            buffer[index..index + chunk.len()].copy_from_slice(chunk);
+            index += chunk.len();
+        }
+
+        Ok(())
+    }
+
+    fn read_u32(&mut self, flash: &mut F) -> Result<u32, ()> {
+        let mut buffer = [0u8; 4];
+        self.read_exact(flash, &mut buffer)?;
+        Ok(u32::from_be_bytes(buffer))
+    }
+
+    fn read_hash(
+        &mut self,
+        flash: &mut F,
+        len: usize,
+    ) -> Result<ArrayVec<[u8; MAX_HASH_SIZE]>, ()> {
+        let mut hash = ArrayVec::from_array_len([0u8; MAX_HASH_SIZE], len);
+        self.read_exact(flash, &mut hash)?;
+        Ok(hash)
+    }
+
+    fn update_hasher<H: HashChain>(&mut self, flash: &mut F, hasher: &mut H) -> Result<(), ()> {
+        while self.remaining() > 0 {
+            let chunk = self.next_chunk(flash, self.remaining())?;
+            hasher.update(chunk);
+        }
+
+        Ok(())
+    }
+}
+
+/// Verifies a binary HSS signature, with the message, the signature and the public key residing
+/// in a NOR flash.
+///
+/// Only the public key and the signed public keys of the intermediate levels are copied into
+/// memory. The message, the signature data and the authentication paths are read from the flash
+/// in small blocks, when they are needed.
+pub fn verify<F: ReadNorFlash, H: HashChain>(
+    flash: &mut F,
+    message: Range<u32>,
+    signature: Range<u32>,
+    public_key: Range<u32>,
+    scratch: &mut VerifyScratch<H>,
+) -> Result<(), ()> {
+    scratch.reset();
+
+    let mut public_key_reader = FlashReader::new(flash, public_key)?;
+    if public_key_reader.remaining() > MAX_HSS_PUBLIC_KEY_LENGTH {
+        return Err(());
+    }
+    let mut public_key_data: ArrayVec<[u8; MAX_HSS_PUBLIC_KEY_LENGTH]> = ArrayVec::from_array_len(
+        [0u8; MAX_HSS_PUBLIC_KEY_LENGTH],
+        public_key_reader.remaining(),
+    );
+    public_key_reader.read_exact(flash, &mut public_key_data)?;
+
+    let public_key = InMemoryHssPublicKey::<H>::new(&public_key_data).ok_or(())?;
+    let mut lms_public_key: ArrayVec<[u8; MAX_LMS_PUBLIC_KEY_LENGTH]> = ArrayVec::new();
+    lms_public_key.extend_from_slice(public_key.public_key.as_slice());
+
+    let mut reader = FlashReader::new(flash, signature)?;
+    let level = reader.read_u32(flash)? as usize;
+
+    if level + 1 != public_key.level {
+        return Err(());
+    }
+
+    for _ in 0..level {
+        lms_public_key = verify_lms_signature(flash, &mut reader, &lms_public_key, None, scratch)?;
+    }
+
+    let mut message_reader = FlashReader::new(flash, message)?;
+    verify_lms_signature(
+        flash,
+        &mut reader,
+        &lms_public_key,
+        Some(&mut message_reader),
+        scratch,
+    )?;
+
+    Ok(())
+}
+
+/// Verifies the LMS signature at the current position of `reader`.
+///
+/// Without a `message`, the signed public key following the signature is verified (as done for
+/// the intermediate levels) and returned afterwards.
+fn verify_lms_signature<F: ReadNorFlash, H: HashChain>(
+    flash: &mut F,
+    reader: &mut FlashReader<F>,
+    public_key: &[u8],
+    message: Option<&mut FlashReader<F>>,
+    scratch: &mut VerifyScratch<H>,
+) -> Result<ArrayVec<[u8; MAX_LMS_PUBLIC_KEY_LENGTH]>, ()> {
+    let public_key = InMemoryLmsPublicKey::<H>::new(public_key).ok_or(())?;
+    let lms_tree_identifier = public_key.lms_tree_identifier;
+
+    // Parse the fixed size fields and remember the positions of the variable size fields.
+    let lms_leaf_identifier = reader.read_u32(flash)?;
+    let lmots_parameter = LmotsAlgorithm::get_from_type::<H>(reader.read_u32(flash)?).ok_or(())?;
+    let signature_randomizer =
+        reader.read_hash(flash, lmots_parameter.get_hash_function_output_size())?;
+
+    let signature_data_offset = reader.offset;
+    reader.skip(
+        lmots_parameter.get_hash_function_output_size()
+            * lmots_parameter.get_hash_chain_count() as usize,
+    )?;
+
+    let lms_parameter = LmsAlgorithm::get_from_type::<H>(reader.read_u32(flash)?).ok_or(())?;
+    let authentication_path_offset = reader.offset;
+    reader.skip(
+        lms_parameter.get_hash_function_output_size() * lms_parameter.get_tree_height() as usize,
+    )?;
+
+    let leafs = lms_parameter.number_of_lm_ots_keys() as u32;
+    if lmots_parameter != public_key.lmots_parameter
+        || lms_parameter != public_key.lms_parameter
+        || lms_leaf_identifier >= leafs
+    {
+        return Err(());
+    }
+
+    let VerifyScratch {
+        hasher,
+        public_key_hasher,
+        hash_chain_data,
+    } = scratch;
+    let lms_leaf_identifier_bytes = lms_leaf_identifier.to_be_bytes();
+
+    hasher.update(lms_tree_identifier);
+    hasher.update(&lms_leaf_identifier_bytes);
+    hasher.update(&D_MESG);
+    hasher.update(signature_randomizer.as_slice());
+
+    let mut signed_public_key = ArrayVec::new();
+    if let Some(message) = message {
+        message.update_hasher(flash, hasher)?;
+    } else {
+        signed_public_key.set_len(lms_public_key_length(
+            lms_parameter.get_hash_function_output_size(),
+        ));
+        reader.read_exact(flash, &mut signed_public_key)?;
+        hasher.update(signed_public_key.as_slice());
+    }
+    let end_offset = reader.offset;
+
+    let message_hash = hasher.finalize_reset();
+    let message_hash_with_checksum = lmots_parameter.append_checksum_to(message_hash.as_slice());
+
+    public_key_hasher.update(lms_tree_identifier);
+    public_key_hasher.update(&lms_leaf_identifier_bytes);
+    public_key_hasher.update(&D_PBLC);
+
+    *hash_chain_data = H::prepare_hash_chain_data(lms_tree_identifier, &lms_leaf_identifier_bytes);
+    let max_w = 2usize.pow(lmots_parameter.get_winternitz() as u32) - 1;
+
+    reader.seek(signature_data_offset)?;
+    for i in 0..lmots_parameter.get_hash_chain_count() {
+        let a = coef(
+            message_hash_with_checksum.as_slice(),
+            i,
+            lmots_parameter.get_winternitz(),
+        ) as usize;
+
+        let initial = reader.read_hash(flash, lmots_parameter.get_hash_function_output_size())?;
+        let result = hasher.do_hash_chain(hash_chain_data, i, initial.as_slice(), a, max_w);
+
+        public_key_hasher.update(result.as_slice());
+    }
+    let ots_public_key_candidate = public_key_hasher.finalize_reset();
+
+    let mut node_num = leafs + lms_leaf_identifier;
+
+    hasher.update(lms_tree_identifier);
+    hasher.update(&node_num.to_be_bytes());
+    hasher.update(&D_LEAF);
+    hasher.update(ots_public_key_candidate.as_slice());
+    let mut node = hasher.finalize_reset();
+
+    reader.seek(authentication_path_offset)?;
+    while node_num > 1 {
+        let path = reader.read_hash(flash, lms_parameter.get_hash_function_output_size())?;
+        let nodes = if is_odd(node_num as usize) {
+            [path.as_slice(), node.as_slice()]
+        } else {
+            [node.as_slice(), path.as_slice()]
+        };
+
+        node_num /= 2;
+
+        hasher.update(lms_tree_identifier);
+        hasher.update(&node_num.to_be_bytes());
+        hasher.update(&D_INTR);
+        hasher.update(nodes[0]);
+        hasher.update(nodes[1]);
+        node = hasher.finalize_reset();
+    }
+
+    if node.as_slice() != public_key.key {
+        return Err(());
+    }
+
+    reader.seek(end_offset)?;
+
+    Ok(signed_public_key)
+}
+
+#[cfg(all(test, feature = "sign", feature = "sha256"))]
+mod tests {
+    use embedded_storage::nor_flash::{check_read, ErrorType, NorFlashErrorKind, ReadNorFlash};
+
+    use crate::{
+        hasher::{sha256::Sha256_256, HashChain},
+        hss::{hss_keygen, hss_verify, hss_verify_flash, signing::InMemoryHssSignedPublicKey},
+        lm_ots::verify::VerifyScratch,
+        signature::SignerMut,
+        util::helper::test_helper::gen_random_seed,
+        HssParameter, LmotsAlgorithm, LmsAlgorithm,
+    };
+
+    const FLASH_SIZE: usize = 16 * 1024;
+
+    struct RamFlash<const READ_SIZE: usize> {
+        data: [u8; FLASH_SIZE],
+        reads: usize,
+    }
+
+    impl<const READ_SIZE: usize> ErrorType for RamFlash<READ_SIZE> {
+        type Error = NorFlashErrorKind;
+    }
+
+    impl<const READ_SIZE: usize> ReadNorFlash for RamFlash<READ_SIZE> {
+        const READ_SIZE: usize = READ_SIZE;
+
+        fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+            check_read(self, offset, bytes.len())?;
+            self.reads += 1;
+            bytes.copy_from_slice(&self.data[offset as usize..offset as usize + bytes.len()]);
+            Ok(())
+        }
+
+        fn capacity(&self) -> usize {
+            FLASH_SIZE
+        }
+    }
+
+    fn test_verify_flash<H: HashChain, const READ_SIZE: usize>() {
+        let seed = gen_random_seed::<H>();
+        let (mut signing_key, verifying_key) = hss_keygen::<H>(
+            &[
+                HssParameter::new(LmotsAlgorithm::LmotsW4, LmsAlgorithm::LmsH5),
+                HssParameter::new(LmotsAlgorithm::LmotsW2, LmsAlgorithm::LmsH5),
+            ],
+            &seed,
+            None,
+        )
+        .unwrap();
+
+        let message = [0x5au8; 1000];
+        let signature = signing_key.try_sign(&message).unwrap();
+        let signature = signature.as_ref();
+        let public_key = verifying_key.as_slice();
+
+        // Place all regions at unaligned offsets.
+        let message_region = 3..3 + message.len() as u32;
+        let signature_start = message_region.end + 5;
+        let signature_region = signature_start..signature_start + signature.len() as u32;
+        let public_key_start = signature_region.end + 7;
+        let public_key_region = public_key_start..public_key_start + public_key.len() as u32;
+
+        let mut flash = RamFlash::<READ_SIZE> {
+            data: [0xff; FLASH_SIZE],
+            reads: 0,
+        };
+        for (region, data) in [
+            (&message_region, &message[..]),
+            (&signature_region, signature),
+            (&public_key_region, public_key),
+        ] {
+            flash.data[region.start as usize..region.end as usize].copy_from_slice(data);
+        }
+
+        let verify = |flash: &mut RamFlash<READ_SIZE>| {
+            hss_verify_flash::<_, H>(
+                flash,
+                message_region.clone(),
+                signature_region.clone(),
+                public_key_region.clone(),
+                &mut VerifyScratch::default(),
+            )
+        };
+
+        assert!(hss_verify::<H>(&message, signature, public_key).is_ok());
+        assert!(verify(&mut flash).is_ok());
+        assert!(flash.reads > 0);
+
+        for index in [
+            message_region.start + 500,
+            signature_region.start + 100,
+            signature_region.end - 1,
+            public_key_region.end - 1,
+        ] {
+            flash.data[index as usize] ^= 1;
+            assert!(verify(&mut flash).is_err());
+            flash.data[index as usize] ^= 1;
+        }
+        assert!(verify(&mut flash).is_ok());
+
+        assert!(hss_verify_flash::<_, H>(
+            &mut flash,
+            message_region.clone(),
+            signature_region.start..signature_region.end - 1,
+            public_key_region.clone(),
+            &mut VerifyScratch::default(),
+        )
+        .is_err());
+        assert!(hss_verify_flash::<_, H>(
+            &mut flash,
+            message_region.clone(),
+            signature_region.clone(),
+            public_key_region.start..FLASH_SIZE as u32 + 1,
+            &mut VerifyScratch::default(),
+        )
+        .is_err());
+
+        // A failed verification doesn't affect the next one with the same scratch space.
+        let signed_public_key = InMemoryHssSignedPublicKey::<H>::new(&signature[4..]).unwrap();
+        let mut scratch = VerifyScratch::default();
+        for end in [
+            signature_region.start + 4 + signed_public_key.len() as u32 - 1,
+            signature_region.end - 1,
+        ] {
+            assert!(hss_verify_flash::<_, H>(
+                &mut flash,
+                message_region.clone(),
+                signature_region.start..end,
+                public_key_region.clone(),
+                &mut scratch,
+            )
+            .is_err());
+            assert!(hss_verify_flash::<_, H>(
+                &mut flash,
+                message_region.clone(),
+                signature_region.clone(),
+                public_key_region.clone(),
+                &mut scratch,
+            )
+            .is_ok());
+        }
+    }
+
+    #[test]
+    fn verify_flash_byte_readable() {
+        test_verify_flash::<Sha256_256, 1>();
+    }
+
+    #[test]
+    fn verify_flash_word_readable() {
+        test_verify_flash::<Sha256_256, 4>();
+        #[cfg(feature = "shake256")]
+        test_verify_flash::<crate::hasher::shake256::Shake256_256, 16>();
+    }
+}
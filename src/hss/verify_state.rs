@@ -0,0 +1,467 @@
+use core::{convert::TryInto, task::Poll};
+use tinyvec::ArrayVec;
+
+use crate::{
+    constants::{winternitz_chain::ITER_PREV, D_INTR, D_LEAF, D_MESG, D_PBLC, MAX_HASH_SIZE},
+    hasher::HashChain,
+    lm_ots::{signing::InMemoryLmotsSignature, verify::VerifyScratch},
+    lms::{definitions::InMemoryLmsPublicKey, signing::InMemoryLmsSignature},
+    signature::Error,
+    util::{coef::coef, helper::is_odd},
+};
+
+use super::{definitions::InMemoryHssPublicKey, signing::InMemoryHssSignedPublicKey};
+
+#[derive(Clone, Copy)]
+enum Phase {
+    Parse,
+    MessageHash { offset: usize },
+    HashChain { index: u16, position: usize },
+    PublicKeyCandidate,
+    Leaf,
+    AuthenticationPath { node_num: u32, index: usize },
+    Done(bool),
+}
+
+/**
+ * Resumable HSS signature verification.
+ *
+ * In contrast to [`crate::verify`], the verification is split into slices, whose size is given in
+ * hash computations. Each call of [`VerifyState::step`] advances the message hash, the hash chains
+ * and the authentication path by at most the given budget and returns [`Poll::Pending`] until the
+ * verification is finished. This allows to verify signatures with large Winternitz parameters
+ * without blocking a cooperative scheduler. The message is hashed in slices of one hash block per
+ * unit of the budget. The result is the same as the one of [`crate::verify`].
+ *
+ * Each signed public key of the signature is parsed only once, when its tree is reached.
+ */
+pub struct VerifyState<'a, H: HashChain> {
+    scratch: VerifyScratch<H>,
+    message: &'a [u8],
+    signature: &'a [u8],
+    remaining_levels: usize,
+    public_key: InMemoryLmsPublicKey<'a, H>,
+    next_public_key: Option<InMemoryLmsPublicKey<'a, H>>,
+    lms_signature: Option<InMemoryLmsSignature<'a, H>>,
+    message_hash_with_checksum: ArrayVec<[u8; MAX_HASH_SIZE + 2]>,
+    node: ArrayVec<[u8; MAX_HASH_SIZE]>,
+    phase: Phase,
+}
+
+impl<'a, H: HashChain> VerifyState<'a, H> {
+    /**
+     * Prepares the verification of `signature` over `message` with the HSS `public_key`.
+     *
+     * Returns an error, if the public key can't be parsed or the number of levels of the
+     * signature doesn't match the public key. No hashes are computed yet.
+     */
+    pub fn new(
+        message: &'a [u8],
+        signature: &'a [u8],
+        public_key: &'a [u8],
+    ) -> Result<Self, Error> {
+        let public_key = InMemoryHssPublicKey::<H>::new(public_key).ok_or_else(Error::new)?;
+
+        if signature.len() < 4 {
+            return Err(Error::new());
+        }
+
+        let (level, signature) = signature.split_at(4);
+        let level = u32::from_be_bytes(level.try_into().unwrap()) as usize;
+
+        if level + 1 != public_key.level {
+            return Err(Error::new());
+        }
+
+        Ok(Self {
+            scratch: VerifyScratch::default(),
+            message,
+            signature,
+            remaining_levels: level,
+            public_key: public_key.public_key,
+            next_public_key: None,
+            lms_signature: None,
+            message_hash_with_checksum: ArrayVec::new(),
+            node: ArrayVec::new(),
+            phase: Phase::Parse,
+        })
+    }
+
+    /**
+     * Advances the verification by at most `budget_in_hashes` hash computations.
+     *
+     * Returns [`Poll::Pending`] as long as the verification isn't finished. Afterwards, the result
+     * of the verification is returned for each further call.
+     */
+    pub fn step(&mut self, budget_in_hashes: usize) -> Poll<Result<(), Error>> {
+        let mut budget = budget_in_hashes;
+
+        loop {
+            match self.phase {
+                Phase::Done(valid) => {
+                    return Poll::Ready(if valid { Ok(()) } else { Err(Error::new()) });
+                }
+                Phase::Parse => {
+                    self.phase = match self.parse_next_level() {
+                        Some(()) => Phase::MessageHash { offset: 0 },
+                        None => Phase::Done(false),
+                    };
+                    continue;
+                }
+                _ => {}
+            }
+
+            if budget == 0 {
+                return Poll::Pending;
+            }
+
+            budget -= self.advance(budget);
+        }
+    }
+
+    fn parse_next_level(&mut self) -> Option<()> {
+        let lms_signature = if self.remaining_levels > 0 {
+            let signed_public_key = InMemoryHssSignedPublicKey::<'a, H>::new(self.signature)?;
+            self.signature = self.signature.get(signed_public_key.len()..)?;
+            self.remaining_levels -= 1;
+            self.next_public_key = Some(signed_public_key.public_key);
+            signed_public_key.sig
+        } else {
+            self.next_public_key = None;
+            InMemoryLmsSignature::<'a, H>::new(self.signature)?
+        };
+
+        if lms_signature.lmots_signature.lmots_parameter != self.public_key.lmots_parameter
+            || lms_signature.lms_parameter != self.public_key.lms_parameter
+            || lms_signature.lms_leaf_identifier
+                >= lms_signature.lms_parameter.number_of_lm_ots_keys() as u32
+        {
+            return None;
+        }
+
+        let hasher = &mut self.scratch.hasher;
+        hasher.update(self.public_key.lms_tree_identifier);
+        hasher.update(&lms_signature.lms_leaf_identifier.to_be_bytes());
+        hasher.update(&D_MESG);
+        hasher.update(lms_signature.lmots_signature.signature_randomizer);
+
+        self.lms_signature = Some(lms_signature);
+
+        Some(())
+    }
+
+    /// Performs the work of the current phase and returns the number of computed hashes.
+    fn advance(&mut self, budget: usize) -> usize {
+        let lms_signature = match self.lms_signature.clone() {
+            Some(lms_signature) => lms_signature,
+            None => {
+                self.phase = Phase::Done(false);
+                return 0;
+            }
+        };
+        let lmots_signature = &lms_signature.lmots_signature;
+        let lmots_parameter = &lmots_signature.lmots_parameter;
+        let lms_tree_identifier = self.public_key.lms_tree_identifier;
+        let lms_leaf_identifier = lms_signature.lms_leaf_identifier.to_be_bytes();
+
+        let VerifyScratch {
+            hasher,
+            public_key_hasher,
+            hash_chain_data,
+        } = &mut self.scratch;
+
+        match self.phase {
+            Phase::MessageHash { offset } => {
+                let message = self
+                    .next_public_key
+                    .as_ref()
+                    .map_or(self.message, |public_key| public_key.as_slice());
+
+                let block_size = H::BLOCK_SIZE as usize;
+                let blocks = budget.min((message.len() - offset) / block_size + 1);
+                let end = message.len().min(offset + blocks * block_size);
+
+                hasher.update(&message[offset..end]);
+
+                if end < message.len() {
+                    self.phase = Phase::MessageHash { offset: end };
+                    return blocks;
+                }
+
+                let message_hash = hasher.finalize_reset();
+                self.message_hash_with_checksum =
+                    lmots_parameter.append_checksum_to(message_hash.as_slice());
+
+                public_key_hasher.update(lms_tree_identifier);
+                public_key_hasher.update(&lms_leaf_identifier);
+                public_key_hasher.update(&D_PBLC);
+
+                *hash_chain_data =
+                    H::prepare_hash_chain_data(lms_tree_identifier, &lms_leaf_identifier);
+
+                self.phase = self.start_hash_chain(lmots_signature, 0);
+                blocks
+            }
+            Phase::HashChain { index, position } => {
+                let max_w = 2usize.pow(lmots_parameter.get_winternitz() as u32) - 1;
+                let end = max_w.min(position.saturating_add(budget));
+
+                hasher.do_actual_hash_chain(hash_chain_data, position, end);
+
+                self.phase = if end < max_w {
+                    Phase::HashChain {
+                        index,
+                        position: end,
+                    }
+                } else {
+                    public_key_hasher.update(&hash_chain_data[ITER_PREV..]);
+                    self.start_hash_chain(lmots_signature, index + 1)
+                };
+                end - position
+            }
+            Phase::PublicKeyCandidate => {
+                self.node = public_key_hasher.finalize_reset();
+
+                self.phase = Phase::Leaf;
+                1
+            }
+            Phase::Leaf => {
+                let node_num = lms_signature.lms_parameter.number_of_lm_ots_keys() as u32
+                    + lms_signature.lms_leaf_identifier;
+
+                hasher.update(lms_tree_identifier);
+                hasher.update(&node_num.to_be_bytes());
+                hasher.update(&D_LEAF);
+                hasher.update(self.node.as_slice());
+                self.node = hasher.finalize_reset();
+
+                self.phase = self.next_node(node_num, 0);
+                1
+            }
+            Phase::AuthenticationPath { node_num, index } => {
+                let path = lms_signature.get_path(index);
+                let nodes = if is_odd(node_num as usize) {
+                    [path, self.node.as_slice()]
+                } else {
+                    [self.node.as_slice(), path]
+                };
+
+                hasher.update(lms_tree_identifier);
+                hasher.update(&(node_num / 2).to_be_bytes());
+                hasher.update(&D_INTR);
+                hasher.update(nodes[0]);
+                hasher.update(nodes[1]);
+                self.node = hasher.finalize_reset();
+
+                self.phase = self.next_node(node_num / 2, index + 1);
+                1
+            }
+            Phase::Parse | Phase::Done(_) => unreachable!(),
+        }
+    }
+
+    fn start_hash_chain(
+        &mut self,
+        lmots_signature: &InMemoryLmotsSignature<'a, H>,
+        index: u16,
+    ) -> Phase {
+        let lmots_parameter = &lmots_signature.lmots_parameter;
+
+        if index == lmots_parameter.get_hash_chain_count() {
+            return Phase::PublicKeyCandidate;
+        }
+
+        let position = coef(
+            self.message_hash_with_checksum.as_slice(),
+            index,
+            lmots_parameter.get_winternitz(),
+        ) as usize;
+
+        // Loads the chain index and the initial value without computing a hash.
+        let VerifyScratch {
+            hasher,
+            hash_chain_data,
+            ..
+        } = &mut self.scratch;
+        hasher.do_hash_chain(
+            hash_chain_data,
+            index,
+            lmots_signature.get_signature_data(index as usize),
+            position,
+            position,
+        );
+
+        Phase::HashChain { index, position }
+    }
+
+    fn next_node(&mut self, node_num: u32, index: usize) -> Phase {
+        if node_num > 1 {
+            return Phase::AuthenticationPath { node_num, index };
+        }
+
+        if self.node.as_slice() != self.public_key.key {
+            return Phase::Done(false);
+        }
+
+        match self.next_public_key.take() {
+            Some(public_key) => {
+                self.public_key = public_key;
+                Phase::Parse
+            }
+            None => Phase::Done(true),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "sign", feature = "sha256"))]
+mod tests {
+    use core::task::Poll;
+
+    use crate::{
+        hasher::{sha256::Sha256_256, HashChain},
+        hss::{hss_keygen, hss_verify},
+        signature::SignerMut,
+        util::helper::test_helper::gen_random_seed,
+        HssParameter, LmotsAlgorithm, LmsAlgorithm,
+    };
+
+    use super::{Phase, VerifyState};
+
+    fn run_to_completion<H: HashChain>(
+        message: &[u8],
+        signature: &[u8],
+        public_key: &[u8],
+        budget: usize,
+    ) -> (bool, usize) {
+        let mut state = VerifyState::<H>::new(message, signature, public_key).unwrap();
+        let mut steps = 1;
+
+        loop {
+            match state.step(budget) {
+                Poll::Pending => steps += 1,
+                Poll::Ready(result) => return (result.is_ok(), steps),
+            }
+        }
+    }
+
+    fn test_step_wise_verification<H: HashChain>() {
+        let seed = gen_random_seed::<H>();
+        let (mut signing_key, verifying_key) = hss_keygen::<H>(
+            &[
+                HssParameter::new(LmotsAlgorithm::LmotsW4, LmsAlgorithm::LmsH5),
+                HssParameter::new(LmotsAlgorithm::LmotsW2, LmsAlgorithm::LmsH5),
+            ],
+            &seed,
+            None,
+        )
+        .unwrap();
+
+        let mut message = [0x42u8; 64];
+        let signature = signing_key.try_sign(&message).unwrap();
+        let mut signature = signature.as_ref().to_vec();
+        let public_key = verifying_key.as_slice();
+
+        let (valid, steps_unbounded) =
+            run_to_completion::<H>(&message, &signature, public_key, usize::MAX);
+        assert!(valid);
+        assert_eq!(steps_unbounded, 1);
+
+        let (valid, steps_bounded) = run_to_completion::<H>(&message, &signature, public_key, 7);
+        assert!(valid);
+        assert!(steps_bounded > 20);
+
+        let mut state = verifying_key.verify_state(&message, &signature).unwrap();
+        assert!(state.step(0).is_pending());
+        assert!(state.step(1).is_pending());
+
+        message[0] ^= 1;
+        assert!(!run_to_completion::<H>(&message, &signature, public_key, 13).0);
+        assert!(hss_verify::<H>(&message, &signature, public_key).is_err());
+        message[0] ^= 1;
+
+        let last = signature.len() - 1;
+        signature[last] ^= 1;
+        assert!(!run_to_completion::<H>(&message, &signature, public_key, 1).0);
+        assert!(hss_verify::<H>(&message, &signature, public_key).is_err());
+        signature[last] ^= 1;
+
+        signature[100] ^= 1;
+        assert!(!run_to_completion::<H>(&message, &signature, public_key, 100).0);
+        assert!(hss_verify::<H>(&message, &signature, public_key).is_err());
+
+        assert!(VerifyState::<H>::new(&message, &signature[..3], public_key).is_err());
+    }
+
+    #[test]
+    fn reject_truncated_signatures() {
+        type H = Sha256_256;
+
+        let (mut signing_key, verifying_key) = hss_keygen::<H>(
+            &[
+                HssParameter::new(LmotsAlgorithm::LmotsW4, LmsAlgorithm::LmsH2),
+                HssParameter::new(LmotsAlgorithm::LmotsW4, LmsAlgorithm::LmsH2),
+            ],
+            &gen_random_seed::<H>(),
+            None,
+        )
+        .unwrap();
+
+        let message = [0x42u8; 64];
+        let signature = signing_key.try_sign(&message).unwrap();
+        let signature = signature.as_ref();
+        let public_key = verifying_key.as_slice();
+
+        for length in 4..signature.len() {
+            if let Ok(mut state) = VerifyState::<H>::new(&message, &signature[..length], public_key)
+            {
+                assert!(matches!(state.step(usize::MAX), Poll::Ready(Err(_))));
+            }
+        }
+
+        assert!(run_to_completion::<H>(&message, signature, public_key, usize::MAX).0);
+    }
+
+    #[test]
+    fn hash_message_in_blocks() {
+        type H = Sha256_256;
+
+        let (mut signing_key, verifying_key) = hss_keygen::<H>(
+            &[HssParameter::new(
+                LmotsAlgorithm::LmotsW1,
+                LmsAlgorithm::LmsH2,
+            )],
+            &gen_random_seed::<H>(),
+            None,
+        )
+        .unwrap();
+
+        let block_size = H::BLOCK_SIZE as usize;
+        let message = [0x42u8; 64 * 100];
+        let signature = signing_key.try_sign(&message).unwrap();
+
+        let mut state =
+            VerifyState::<H>::new(&message, signature.as_ref(), verifying_key.as_slice()).unwrap();
+
+        for blocks in 1..message.len() / block_size {
+            assert!(state.step(1).is_pending());
+            assert!(
+                matches!(state.phase, Phase::MessageHash { offset } if offset == blocks * block_size)
+            );
+        }
+
+        assert!(
+            run_to_completion::<H>(&message, signature.as_ref(), verifying_key.as_slice(), 1).0
+        );
+    }
+
+    #[test]
+    fn step_wise_verification_sha256() {
+        test_step_wise_verification::<Sha256_256>();
+    }
+
+    #[cfg(feature = "shake256")]
+    #[test]
+    fn step_wise_verification_shake256() {
+        test_step_wise_verification::<crate::hasher::shake256::Shake256_256>();
+    }
+}
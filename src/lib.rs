@@ -84,37 +84,112 @@
 
 extern crate core;
 
+#[cfg(any(test, feature = "std", feature = "coset", feature = "x509-cert"))]
+extern crate alloc;
+
 mod constants;
 mod hasher;
 mod hss;
-mod lm_ots;
-mod lms;
+pub mod lm_ots;
+pub mod lms;
+mod self_test;
+#[cfg(feature = "serde")]
+mod serialization;
 mod util;
 
 // Re-export the `signature` crate
 pub use signature::{self};
+// Re-export the `spki` crate
+#[cfg(feature = "spki")]
+pub use spki::{self};
+// Re-export the `cms` crate
+#[cfg(all(feature = "cms", feature = "spki"))]
+pub use cms::{self};
+
+// Re-export the `coset` crate
+#[cfg(feature = "coset")]
+pub use coset::{self};
+
+// Re-export the `x509-cert` crate
+#[cfg(all(feature = "x509-cert", feature = "spki"))]
+pub use x509_cert::{self};
+
+#[cfg(all(feature = "cms", not(feature = "spki")))]
+compile_error!("The `cms` feature requires the `spki` feature.");
+#[cfg(all(feature = "x509-cert", not(feature = "spki")))]
+compile_error!("The `x509-cert` feature requires the `spki` feature.");
 
 #[doc(hidden)]
 pub use crate::constants::MAX_HASH_SIZE;
+pub use crate::constants::VERIFY_MAX_STACK_USAGE;
+#[cfg(feature = "sign")]
 #[doc(hidden)]
 pub use crate::hss::reference_impl_private_key::Seed;
 
-pub use crate::hasher::{
-    sha256::{Sha256_128, Sha256_192, Sha256_256},
-    shake256::{Shake256_128, Shake256_192, Shake256_256},
-    HashChain, HashChainData,
-};
+#[cfg(feature = "sha256")]
+pub use crate::hasher::sha256::{Sha256_128, Sha256_192, Sha256_256};
+#[cfg(feature = "shake256")]
+pub use crate::hasher::shake256::{Shake256_128, Shake256_192, Shake256_256};
+pub use crate::hasher::{HashChain, HashChainData, HashFamily};
 
+pub use crate::hss::cost::{HashConstruction, ParameterSetCost};
+#[cfg(feature = "sign")]
+pub use crate::hss::delegation::{DelegatedSigningKey, Delegation};
+#[cfg(feature = "sign")]
+pub use crate::hss::exclusive_signing_key::ExclusiveSigningKey;
+#[cfg(all(feature = "sign", feature = "embedded-storage"))]
+pub use crate::hss::flash_key_store::FlashKeyStore;
+#[cfg(feature = "std")]
+pub use crate::hss::leaf_reuse::{LeafReuse, LeafReuseDetector, ReuseKind};
 pub use crate::hss::parameter::HssParameter;
+#[cfg(feature = "std")]
+pub use crate::hss::recommend::{
+    recommend_parameters, DeploymentConstraints, Objective, Recommendation,
+};
+#[cfg(all(feature = "sign", feature = "std"))]
+pub use crate::hss::shared_signer::SharedSigner;
+pub use crate::hss::signature_info::{LevelSignatureInfo, PublicKeyInfo, SignatureInfo};
+#[cfg(feature = "spki")]
+pub use crate::hss::subject_public_key_info::ID_ALG_HSS_LMS_HASHSIG;
+pub use crate::hss::verification_policy::VerificationPolicy;
 pub use crate::lm_ots::parameters::LmotsAlgorithm;
 pub use crate::lms::parameters::LmsAlgorithm;
+pub use crate::self_test::{self_test, self_test_hash_chain};
 
+#[cfg(feature = "sign")]
 pub use crate::hss::hss_keygen as keygen;
+#[cfg(feature = "sign")]
 pub use crate::hss::hss_sign as sign;
+#[cfg(all(feature = "x509-cert", feature = "spki", feature = "sign"))]
+pub use crate::hss::hss_sign_certificate as sign_certificate;
+#[cfg(all(feature = "x509-cert", feature = "spki", feature = "sign"))]
+pub use crate::hss::hss_sign_certificate_request as sign_certificate_request;
+#[cfg(all(feature = "coset", feature = "sign"))]
+pub use crate::hss::hss_sign_cose_sign1 as sign_cose_sign1;
 #[cfg(feature = "fast_verify")]
 pub use crate::hss::hss_sign_mut as sign_mut;
+#[cfg(all(feature = "x509-cert", feature = "spki", feature = "sign"))]
+pub use crate::hss::hss_sign_self_signed_certificate as sign_self_signed_certificate;
+#[cfg(all(feature = "cms", feature = "spki", feature = "sign"))]
+pub use crate::hss::hss_sign_signed_data as sign_signed_data;
 pub use crate::hss::hss_verify as verify;
-pub use crate::hss::{SigningKey, VerifyingKey};
+#[cfg(all(feature = "x509-cert", feature = "spki"))]
+pub use crate::hss::hss_verify_certificate as verify_certificate;
+#[cfg(all(feature = "x509-cert", feature = "spki"))]
+pub use crate::hss::hss_verify_certificate_request as verify_certificate_request;
+#[cfg(feature = "coset")]
+pub use crate::hss::hss_verify_cose_sign1 as verify_cose_sign1;
+#[cfg(feature = "embedded-storage")]
+pub use crate::hss::hss_verify_flash as verify_flash;
+#[cfg(all(feature = "cms", feature = "spki"))]
+pub use crate::hss::hss_verify_signed_data as verify_signed_data;
+pub use crate::hss::hss_verify_with_policy as verify_with_policy;
+pub use crate::hss::hss_verify_with_scratch as verify_with_scratch;
+pub use crate::hss::verify_state::VerifyState;
+#[cfg(feature = "sign")]
+pub use crate::hss::SigningKey;
+pub use crate::hss::VerifyingKey;
+pub use crate::lm_ots::verify::VerifyScratch;
 
 use core::convert::TryFrom;
 use signature::Error;
@@ -184,7 +259,7 @@ impl<'a> signature::Signature for VerifierSignature<'a> {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "sign", feature = "sha256"))]
 mod tests {
     use crate::{keygen, HssParameter, LmotsAlgorithm, LmsAlgorithm, Sha256_256};
     use crate::{
@@ -1,22 +1,37 @@
+#[cfg(feature = "sign")]
+use core::fmt;
 use tinyvec::ArrayVec;
 
+#[cfg(feature = "sign")]
+use crate::constants::MAX_HASH_CHAIN_COUNT;
 use crate::{
-    constants::{LmsLeafIdentifier, LmsTreeIdentifier, MAX_HASH_CHAIN_COUNT, MAX_HASH_SIZE},
+    constants::{LmsLeafIdentifier, LmsTreeIdentifier, MAX_HASH_SIZE},
     hasher::HashChain,
 };
 
+#[cfg(all(feature = "sign", feature = "zeroize"))]
+use crate::util::helper::zeroize_array_vec;
+
+#[cfg(feature = "sign")]
+use super::parameters::LmotsAlgorithm;
 use super::parameters::LmotsParameter;
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+/**
+ * One-time private key, i.e. the start of each hash chain. The hash chains are redacted in the
+ * `Debug` output and wiped on drop with the `zeroize` feature.
+ */
+#[cfg(feature = "sign")]
+#[derive(PartialEq, Eq)]
 pub struct LmotsPrivateKey<H: HashChain> {
-    pub lms_tree_identifier: LmsTreeIdentifier,
-    pub lms_leaf_identifier: LmsLeafIdentifier,
-    pub key: ArrayVec<[ArrayVec<[u8; MAX_HASH_SIZE]>; MAX_HASH_CHAIN_COUNT]>, // [[0u8; n]; p];
-    pub lmots_parameter: LmotsParameter<H>,
+    pub(crate) lms_tree_identifier: LmsTreeIdentifier,
+    pub(crate) lms_leaf_identifier: LmsLeafIdentifier,
+    pub(crate) key: ArrayVec<[ArrayVec<[u8; MAX_HASH_SIZE]>; MAX_HASH_CHAIN_COUNT]>, // [[0u8; n]; p];
+    pub(crate) lmots_parameter: LmotsParameter<H>,
 }
 
+#[cfg(feature = "sign")]
 impl<H: HashChain> LmotsPrivateKey<H> {
-    pub fn new(
+    pub(crate) fn new(
         lms_tree_identifier: LmsTreeIdentifier,
         lms_leaf_identifier: LmsLeafIdentifier,
         key: ArrayVec<[ArrayVec<[u8; MAX_HASH_SIZE]>; MAX_HASH_CHAIN_COUNT]>,
@@ -29,8 +44,46 @@ impl<H: HashChain> LmotsPrivateKey<H> {
             lmots_parameter,
         }
     }
+
+    /// Identifier `I` of the LMS tree of the key.
+    pub fn tree_identifier(&self) -> &LmsTreeIdentifier {
+        &self.lms_tree_identifier
+    }
+
+    /// Leaf `q` of the key in the LMS tree.
+    pub fn leaf_identifier(&self) -> u32 {
+        u32::from_be_bytes(self.lms_leaf_identifier)
+    }
+
+    pub fn lmots_algorithm(&self) -> LmotsAlgorithm {
+        LmotsAlgorithm::from(self.lmots_parameter.get_type_id())
+    }
+}
+
+#[cfg(feature = "sign")]
+impl<H: HashChain> fmt::Debug for LmotsPrivateKey<H> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LmotsPrivateKey")
+            .field("lms_tree_identifier", &self.lms_tree_identifier)
+            .field("lms_leaf_identifier", &self.lms_leaf_identifier)
+            .field("lmots_parameter", &self.lmots_parameter)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(all(feature = "sign", feature = "zeroize"))]
+impl<H: HashChain> Drop for LmotsPrivateKey<H> {
+    fn drop(&mut self) {
+        for hash_chain in self.key.iter_mut() {
+            zeroize_array_vec(hash_chain);
+        }
+    }
 }
 
+/**
+ * One-time public key, i.e. the hash of the ends of all hash chains.
+ */
+#[derive(Debug, PartialEq, Eq, Clone)]
 pub struct LmotsPublicKey<H: HashChain> {
     pub lms_tree_identifier: LmsTreeIdentifier,
     pub lms_leaf_identifier: LmsLeafIdentifier,
@@ -38,6 +91,7 @@ pub struct LmotsPublicKey<H: HashChain> {
     pub lmots_parameter: LmotsParameter<H>,
 }
 
+#[cfg(feature = "sign")]
 impl<H: HashChain> LmotsPublicKey<H> {
     pub fn new(
         lms_tree_identifier: LmsTreeIdentifier,
@@ -54,7 +108,7 @@ impl<H: HashChain> LmotsPublicKey<H> {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "sha256"))]
 mod tests {
     use crate::hasher::sha256::{Sha256_128, Sha256_192, Sha256_256};
     use crate::lm_ots::parameters;
@@ -90,7 +144,7 @@ mod tests {
         24,
         1,
         200,
-        7,
+        8,
         1
     );
     generate_parameter_test!(
@@ -100,7 +154,7 @@ mod tests {
         16,
         1,
         136,
-        7,
+        8,
         1
     );
     generate_parameter_test!(
@@ -130,7 +184,7 @@ mod tests {
         16,
         2,
         68,
-        6,
+        8,
         2
     );
     generate_parameter_test!(
@@ -9,7 +9,7 @@ use tinyvec::ArrayVec;
 pub fn generate_private_key<H: HashChain>(
     lms_tree_identifier: LmsTreeIdentifier,
     lms_leaf_identifier: LmsLeafIdentifier,
-    seed: Seed<H>,
+    seed: &Seed<H>,
     lmots_parameter: LmotsParameter<H>,
 ) -> LmotsPrivateKey<H> {
     let mut key = ArrayVec::new();
@@ -19,13 +19,19 @@ pub fn generate_private_key<H: HashChain>(
     for index in 0..lmots_parameter.get_hash_chain_count() {
         hasher.update(&lms_tree_identifier);
         hasher.update(&lms_leaf_identifier);
-        hasher.update(&(index as u16).to_be_bytes());
+        hasher.update(&index.to_be_bytes());
         hasher.update(&[0xff]);
         hasher.update(seed.as_slice());
 
         key.push(hasher.finalize_reset());
     }
 
+    // Resetting the hasher only rewinds its block buffer, which still holds the seed. The input of
+    // a hash chain key is shorter than a block, so overwriting all but the last byte of the block
+    // wipes it.
+    #[cfg(feature = "zeroize")]
+    hasher.update(&[0u8; MAX_HASH_BLOCK_SIZE - 1]);
+
     LmotsPrivateKey::new(
         lms_tree_identifier,
         lms_leaf_identifier,
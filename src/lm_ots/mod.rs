@@ -1,5 +1,187 @@
-pub mod definitions;
-pub mod keygen;
-pub mod parameters;
-pub mod signing;
-pub mod verify;
+//! LM-OTS one-time signatures (RFC 8554, Section 4).
+//!
+//! Each private key must sign only a single message. The keys are derived like the leafs of an LMS
+//! tree, so the key of leaf `q` of an [`crate::lms::LmsPrivateKey`] is generated from its seed and
+//! tree identifier.
+//!
+//! ```
+//! use hbs_lms::{lm_ots, LmotsAlgorithm, Seed, Sha256_256};
+//! use rand::{rngs::OsRng, RngCore};
+//!
+//! let mut seed = Seed::default();
+//! OsRng.fill_bytes(seed.as_mut_slice());
+//!
+//! let (private_key, public_key) = lm_ots::generate_key_pair::<Sha256_256>(
+//!     LmotsAlgorithm::LmotsW4,
+//!     &seed,
+//!     &[42u8; 16],
+//!     0,
+//! )
+//! .unwrap();
+//!
+//! let signature = lm_ots::sign(private_key, b"message", &[0u8; 32]).unwrap();
+//!
+//! assert!(lm_ots::verify(
+//!     b"message",
+//!     signature.to_binary_representation().as_slice(),
+//!     &public_key,
+//! )
+//! .is_ok());
+//! ```
+
+#[cfg(feature = "sign")]
+use core::convert::TryFrom;
+use core::convert::TryInto;
+#[cfg(feature = "sign")]
+use tinyvec::ArrayVec;
+
+#[cfg(feature = "sign")]
+use crate::{constants::LmsTreeIdentifier, Seed};
+use crate::{hasher::HashChain, signature::Error, LmotsAlgorithm};
+
+use self::signing::InMemoryLmotsSignature;
+
+pub(crate) mod definitions;
+#[cfg(feature = "sign")]
+pub(crate) mod keygen;
+pub(crate) mod parameters;
+pub(crate) mod signing;
+pub(crate) mod verify;
+
+#[cfg(feature = "sign")]
+pub use self::definitions::LmotsPrivateKey;
+pub use self::definitions::LmotsPublicKey;
+pub use self::signing::LmotsSignature;
+
+/**
+ * Generate an LM-OTS key pair for the leaf `q` of the LMS tree `I`.
+ *
+ * # Arguments
+ * * `HashChain` - The hasher implementation that should be used. ```Sha256``` is a standard software implementation.
+ * * `lmots_algorithm` - The Winternitz parameter of the key.
+ * * `seed` - The seed, from which the private key is derived.
+ * * `lms_tree_identifier` - The identifier `I` of the tree.
+ * * `lms_leaf_identifier` - The leaf `q` of the key in the tree.
+ */
+#[cfg(feature = "sign")]
+pub fn generate_key_pair<H: HashChain>(
+    lmots_algorithm: LmotsAlgorithm,
+    seed: &Seed<H>,
+    lms_tree_identifier: &LmsTreeIdentifier,
+    lms_leaf_identifier: u32,
+) -> Result<(LmotsPrivateKey<H>, LmotsPublicKey<H>), Error> {
+    let lmots_parameter = lmots_algorithm
+        .construct_parameter()
+        .ok_or_else(Error::new)?;
+
+    let private_key = keygen::generate_private_key(
+        *lms_tree_identifier,
+        lms_leaf_identifier.to_be_bytes(),
+        seed,
+        lmots_parameter,
+    );
+    let public_key = keygen::generate_public_key(&private_key);
+
+    Ok((private_key, public_key))
+}
+
+/**
+ * Sign a message. The private key is consumed, as it must not sign a second message.
+ *
+ * # Arguments
+ * * `private_key` - The private key that should be used.
+ * * `message` - The message that should be signed.
+ * * `signature_randomizer` - The randomizer `C` of the output size of the hash function.
+ */
+#[cfg(feature = "sign")]
+pub fn sign<H: HashChain>(
+    private_key: LmotsPrivateKey<H>,
+    message: &[u8],
+    signature_randomizer: &[u8],
+) -> Result<LmotsSignature<H>, Error> {
+    if signature_randomizer.len() != H::OUTPUT_SIZE as usize {
+        return Err(Error::new());
+    }
+
+    let signature_randomizer =
+        ArrayVec::try_from(signature_randomizer).map_err(|_| Error::new())?;
+
+    Ok(LmotsSignature::sign(
+        &private_key,
+        &signature_randomizer,
+        message,
+    ))
+}
+
+/**
+ * Verify an LM-OTS signature.
+ *
+ * # Arguments
+ * * `HashChain` - The hasher implementation that should be used. ```Sha256``` is a standard software implementation.
+ * * `message` - The message that should be verified.
+ * * `signature` - The encoded `lmots_signature` that should be used for verification.
+ * * `public_key` - The public key that should be used for verification.
+ */
+pub fn verify<H: HashChain>(
+    message: &[u8],
+    signature: &[u8],
+    public_key: &LmotsPublicKey<H>,
+) -> Result<(), Error> {
+    let lmots_type = signature.get(..4).ok_or_else(Error::new)?;
+    let lmots_parameter =
+        LmotsAlgorithm::get_from_type::<H>(u32::from_be_bytes(lmots_type.try_into().unwrap()))
+            .ok_or_else(Error::new)?;
+
+    let n = H::OUTPUT_SIZE as usize;
+    if signature.len() != 4 + n * (1 + lmots_parameter.get_hash_chain_count() as usize) {
+        return Err(Error::new());
+    }
+
+    let signature = InMemoryLmotsSignature::<H>::new(signature).ok_or_else(Error::new)?;
+
+    if verify::verify_signature_inmemory(&signature, public_key, message) {
+        Ok(())
+    } else {
+        Err(Error::new())
+    }
+}
+
+#[cfg(all(test, feature = "sign", feature = "sha256"))]
+mod tests {
+    use crate::{LmotsAlgorithm, Sha256_192, Sha256_256};
+
+    use super::{generate_key_pair, sign, verify};
+
+    #[test]
+    fn sign_and_verify() {
+        type H = Sha256_192;
+        let (private_key, public_key) =
+            generate_key_pair::<H>(LmotsAlgorithm::LmotsW4, &Default::default(), &[3u8; 16], 7)
+                .unwrap();
+        assert_eq!(u32::from_be_bytes(public_key.lms_leaf_identifier), 7);
+
+        let signature = sign(private_key, b"message", &[1u8; 24]).unwrap();
+        let signature = signature.to_binary_representation();
+
+        assert!(verify(b"message", signature.as_slice(), &public_key).is_ok());
+        assert!(verify(b"massage", signature.as_slice(), &public_key).is_err());
+        assert!(verify(b"message", &signature[..signature.len() - 1], &public_key).is_err());
+        assert!(verify(b"message", &signature[..3], &public_key).is_err());
+
+        let (private_key, _) =
+            generate_key_pair::<H>(LmotsAlgorithm::LmotsW4, &Default::default(), &[3u8; 16], 8)
+                .unwrap();
+        assert!(sign(private_key, b"message", &[1u8; 32]).is_err());
+    }
+
+    #[test]
+    fn reject_reserved_algorithm() {
+        assert!(generate_key_pair::<Sha256_256>(
+            LmotsAlgorithm::LmotsReserved,
+            &Default::default(),
+            &[0u8; 16],
+            0
+        )
+        .is_err());
+    }
+}
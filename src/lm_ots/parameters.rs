@@ -1,18 +1,21 @@
-use core::marker::PhantomData;
+use core::{fmt, marker::PhantomData, str::FromStr};
 
 use tinyvec::ArrayVec;
 
-use crate::constants::get_hash_chain_count;
+use crate::constants::{get_checksum_left_shift, get_hash_chain_count};
 use crate::{
     constants::{FastVerifyCached, MAX_HASH_SIZE},
     hasher::HashChain,
     util::coef::coef,
 };
 
-use crate::util::coef::coef_helper;
+use crate::util::{
+    coef::coef_helper,
+    helper::{is_registered_hash, split_algorithm_name},
+};
 
 /// Specifies the used Winternitz parameter.
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum LmotsAlgorithm {
     LmotsReserved = 0,
     LmotsW1 = 1,
@@ -51,25 +54,25 @@ impl LmotsAlgorithm {
                 1,
                 1,
                 get_hash_chain_count(1, H::OUTPUT_SIZE as usize) as u16,
-                7,
+                get_checksum_left_shift(1, H::OUTPUT_SIZE as usize),
             )),
             LmotsAlgorithm::LmotsW2 => Some(LmotsParameter::new(
                 2,
                 2,
                 get_hash_chain_count(2, H::OUTPUT_SIZE as usize) as u16,
-                6,
+                get_checksum_left_shift(2, H::OUTPUT_SIZE as usize),
             )),
             LmotsAlgorithm::LmotsW4 => Some(LmotsParameter::new(
                 3,
                 4,
                 get_hash_chain_count(4, H::OUTPUT_SIZE as usize) as u16,
-                4,
+                get_checksum_left_shift(4, H::OUTPUT_SIZE as usize),
             )),
             LmotsAlgorithm::LmotsW8 => Some(LmotsParameter::new(
                 4,
                 8,
                 get_hash_chain_count(8, H::OUTPUT_SIZE as usize) as u16,
-                0,
+                get_checksum_left_shift(8, H::OUTPUT_SIZE as usize),
             )),
         }
     }
@@ -85,6 +88,72 @@ impl LmotsAlgorithm {
     }
 }
 
+impl LmotsAlgorithm {
+    /// Writes the IANA name for the given hash function, e.g. `LMOTS_SHA256_N32_W4`.
+    pub(crate) fn fmt_name(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        hash_name: &str,
+        output_size: u16,
+    ) -> fmt::Result {
+        let winternitz = match *self {
+            LmotsAlgorithm::LmotsReserved => return f.write_str("LMOTS_RESERVED"),
+            LmotsAlgorithm::LmotsW1 => 1,
+            LmotsAlgorithm::LmotsW2 => 2,
+            LmotsAlgorithm::LmotsW4 => 4,
+            LmotsAlgorithm::LmotsW8 => 8,
+        };
+
+        write!(f, "LMOTS_{}_N{}_W{}", hash_name, output_size, winternitz)
+    }
+
+    /// Parses an IANA name and returns the algorithm together with the name and the output size of
+    /// the hash function.
+    pub(crate) fn from_name(name: &str) -> Option<(Self, &str, u16)> {
+        let (hash_name, output_size, winternitz) = split_algorithm_name(name, "LMOTS", 'N')?;
+
+        let lmots_algorithm = match winternitz {
+            "W1" => LmotsAlgorithm::LmotsW1,
+            "W2" => LmotsAlgorithm::LmotsW2,
+            "W4" => LmotsAlgorithm::LmotsW4,
+            "W8" => LmotsAlgorithm::LmotsW8,
+            _ => return None,
+        };
+
+        Some((lmots_algorithm, hash_name, output_size))
+    }
+}
+
+/**
+ * Formats the name of the SHA-256/N32 parameter set (RFC 8554), e.g. `LMOTS_SHA256_N32_W4`, as the
+ * algorithm itself does not fix the hash function. Use [`crate::HssParameter`] for the names of
+ * the other hash functions.
+ */
+impl fmt::Display for LmotsAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_name(f, "SHA256", 32)
+    }
+}
+
+/**
+ * Parses the names of RFC 8554 and SP 800-208, e.g. `LMOTS_SHAKE_N24_W8`. The hash function is
+ * not kept, it is chosen by the [`HashChain`] the algorithm is used with.
+ */
+impl FromStr for LmotsAlgorithm {
+    type Err = signature::Error;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match LmotsAlgorithm::from_name(name) {
+            Some((lmots_algorithm, hash_name, output_size))
+                if is_registered_hash(hash_name, output_size) =>
+            {
+                Ok(lmots_algorithm)
+            }
+            _ => Err(signature::Error::new()),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct LmotsParameter<H: HashChain> {
     type_id: u32,
@@ -1,8 +1,13 @@
 use crate::{
-    constants::{D_MESG, MAX_HASH_CHAIN_COUNT, MAX_HASH_SIZE, MAX_LMOTS_SIGNATURE_LENGTH},
+    constants::{lmots_signature_length, MAX_HASH_CHAIN_COUNT, MAX_HASH_SIZE},
     hasher::HashChain,
     lm_ots::parameters::LmotsAlgorithm,
-    util::{coef::coef, helper::read_and_advance},
+    util::helper::read_and_advance,
+};
+#[cfg(feature = "sign")]
+use crate::{
+    constants::{D_MESG, MAX_LMOTS_SIGNATURE_LENGTH},
+    util::coef::coef,
 };
 
 use core::convert::TryInto;
@@ -18,6 +23,7 @@ use {
     rand::{rngs::OsRng, RngCore},
 };
 
+#[cfg(feature = "sign")]
 use super::definitions::LmotsPrivateKey;
 use super::parameters::LmotsParameter;
 
@@ -60,6 +66,7 @@ impl<'a, H: HashChain> PartialEq<LmotsSignature<H>> for InMemoryLmotsSignature<'
 }
 
 impl<H: HashChain> LmotsSignature<H> {
+    #[cfg(feature = "sign")]
     fn calculate_message_hash(
         private_key: &LmotsPrivateKey<H>,
         signature_randomizer: &ArrayVec<[u8; MAX_HASH_SIZE]>,
@@ -110,6 +117,7 @@ impl<H: HashChain> LmotsSignature<H> {
         hasher
     }
 
+    #[cfg(feature = "sign")]
     fn calculate_signature(
         private_key: &LmotsPrivateKey<H>,
         message_hash_with_checksum: &ArrayVec<[u8; MAX_HASH_SIZE + 2]>,
@@ -139,6 +147,7 @@ impl<H: HashChain> LmotsSignature<H> {
         signature_data
     }
 
+    #[cfg(feature = "sign")]
     pub fn sign(
         private_key: &LmotsPrivateKey<H>,
         signature_randomizer: &ArrayVec<[u8; MAX_HASH_SIZE]>,
@@ -165,6 +174,7 @@ impl<H: HashChain> LmotsSignature<H> {
         LmotsSignature::<H>::sign_core(private_key, &mut hasher, signature_randomizer)
     }
 
+    #[cfg(feature = "sign")]
     fn sign_core(
         private_key: &LmotsPrivateKey<H>,
         hasher: &mut H,
@@ -195,6 +205,7 @@ impl<H: HashChain> LmotsSignature<H> {
         }
     }
 
+    #[cfg(feature = "sign")]
     pub fn to_binary_representation(&self) -> ArrayVec<[u8; MAX_LMOTS_SIGNATURE_LENGTH]> {
         let mut result = ArrayVec::new();
 
@@ -220,9 +231,17 @@ impl<'a, H: HashChain> InMemoryLmotsSignature<'a, H> {
         let mut index = 0;
 
         let lmots_parameter = LmotsAlgorithm::get_from_type::<H>(u32::from_be_bytes(
-            read_and_advance(data, 4, &mut index).try_into().unwrap(),
-        ))
-        .unwrap();
+            data.get(..4)?.try_into().unwrap(),
+        ))?;
+
+        let length = lmots_signature_length(
+            H::OUTPUT_SIZE as usize,
+            lmots_parameter.get_hash_chain_count() as usize,
+        );
+        if data.len() < length {
+            return None;
+        }
+        index += 4;
 
         let signature_randomizer = read_and_advance(data, H::OUTPUT_SIZE as usize, &mut index);
 
@@ -333,16 +352,16 @@ fn thread_optimize_message_hash<H: HashChain>(
     (max_hash_iterations, randomizer)
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "sign"))]
 mod tests {
     use tinyvec::ArrayVec;
 
+    #[cfg(feature = "sha256")]
+    use crate::hasher::sha256::{Sha256_128, Sha256_192, Sha256_256};
+    #[cfg(feature = "shake256")]
+    use crate::hasher::shake256::{Shake256_128, Shake256_192, Shake256_256};
     use crate::{
         constants::{MAX_HASH_CHAIN_COUNT, MAX_HASH_SIZE},
-        hasher::{
-            sha256::{Sha256_128, Sha256_192, Sha256_256},
-            shake256::{Shake256_128, Shake256_192, Shake256_256},
-        },
         lm_ots::{
             parameters::LmotsAlgorithm, signing::InMemoryLmotsSignature, signing::LmotsSignature,
         },
@@ -392,15 +411,21 @@ mod tests {
         };
     }
 
+    #[cfg(feature = "sha256")]
     generate_test!(lmots_sha256_n32_binary_representation_test, Sha256_256);
 
+    #[cfg(feature = "sha256")]
     generate_test!(lmots_sha256_n24_binary_representation_test, Sha256_192);
 
+    #[cfg(feature = "sha256")]
     generate_test!(lmots_sha256_n16_binary_representation_test, Sha256_128);
 
+    #[cfg(feature = "shake256")]
     generate_test!(lmots_shake256_n32_binary_representation_test, Shake256_128);
 
+    #[cfg(feature = "shake256")]
     generate_test!(lmots_shake256_n24_binary_representation_test, Shake256_192);
 
+    #[cfg(feature = "shake256")]
     generate_test!(lmots_shake256_n16_binary_representation_test, Shake256_256);
 }
@@ -1,74 +1,36 @@
-use core::{marker::PhantomData, usize};
-
 use tinyvec::ArrayVec;
 
-use crate::lm_ots::parameters::LmotsParameter;
-use crate::{constants::*, hasher::HashChain, util::coef::coef, LmotsAlgorithm};
+use crate::{
+    constants::*,
+    hasher::{HashChain, HashChainData},
+    util::coef::coef,
+};
 
 use super::{definitions::LmotsPublicKey, signing::InMemoryLmotsSignature};
 
+/**
+ * Scratch space used during signature verification.
+ *
+ * The verifier borrows its hashers and the hash chain buffer from this struct instead of
+ * allocating them on its own stack. This allows to place the scratch space in static memory on
+ * targets with a small stack. A single instance can be reused for any number of verifications.
+ */
 #[derive(Default)]
-struct HashChainArray<H: HashChain> {
-    pub array_w1:
-        Option<ArrayVec<[ArrayVec<[u8; MAX_HASH_SIZE]>; get_hash_chain_count(1, MAX_HASH_SIZE)]>>,
-    pub array_w2:
-        Option<ArrayVec<[ArrayVec<[u8; MAX_HASH_SIZE]>; get_hash_chain_count(2, MAX_HASH_SIZE)]>>,
-    pub array_w4:
-        Option<ArrayVec<[ArrayVec<[u8; MAX_HASH_SIZE]>; get_hash_chain_count(4, MAX_HASH_SIZE)]>>,
-    pub array_w8:
-        Option<ArrayVec<[ArrayVec<[u8; MAX_HASH_SIZE]>; get_hash_chain_count(8, MAX_HASH_SIZE)]>>,
-    phantom_data: PhantomData<H>,
+pub struct VerifyScratch<H: HashChain> {
+    pub(crate) hasher: H,
+    pub(crate) public_key_hasher: H,
+    pub(crate) hash_chain_data: HashChainData,
 }
 
-impl<H: HashChain> HashChainArray<H> {
-    pub fn new(lmots_parameter: &LmotsParameter<H>) -> Self {
-        let mut hash_chain_array = HashChainArray::<H>::default();
-        if LmotsAlgorithm::from(lmots_parameter.get_type_id()) == LmotsAlgorithm::LmotsW8 {
-            hash_chain_array.array_w8 = Some(ArrayVec::<
-                [ArrayVec<[u8; MAX_HASH_SIZE]>; get_hash_chain_count(8, MAX_HASH_SIZE)],
-            >::default());
-        } else if LmotsAlgorithm::from(lmots_parameter.get_type_id()) == LmotsAlgorithm::LmotsW4 {
-            hash_chain_array.array_w4 = Some(ArrayVec::<
-                [ArrayVec<[u8; MAX_HASH_SIZE]>; get_hash_chain_count(4, MAX_HASH_SIZE)],
-            >::default());
-        } else if LmotsAlgorithm::from(lmots_parameter.get_type_id()) == LmotsAlgorithm::LmotsW2 {
-            hash_chain_array.array_w2 = Some(ArrayVec::<
-                [ArrayVec<[u8; MAX_HASH_SIZE]>; get_hash_chain_count(2, MAX_HASH_SIZE)],
-            >::default());
-        } else {
-            hash_chain_array.array_w1 = Some(ArrayVec::<
-                [ArrayVec<[u8; MAX_HASH_SIZE]>; get_hash_chain_count(1, MAX_HASH_SIZE)],
-            >::default());
-        }
-        hash_chain_array
-    }
-
-    pub fn push(&mut self, data: &ArrayVec<[u8; MAX_HASH_SIZE]>) {
-        if let Some(array_w8) = &mut self.array_w8 {
-            array_w8.push(*data);
-        } else if let Some(array_w4) = &mut self.array_w4 {
-            array_w4.push(*data);
-        } else if let Some(array_w2) = &mut self.array_w2 {
-            array_w2.push(*data);
-        } else if let Some(array_w1) = &mut self.array_w1 {
-            array_w1.push(*data);
-        }
-    }
-
-    pub fn as_slice(&mut self) -> &[ArrayVec<[u8; MAX_HASH_SIZE]>] {
-        if let Some(array_w8) = &self.array_w8 {
-            array_w8.as_slice()
-        } else if let Some(array_w4) = &self.array_w4 {
-            array_w4.as_slice()
-        } else if let Some(array_w2) = &self.array_w2 {
-            array_w2.as_slice()
-        } else {
-            return self.array_w1.as_ref().unwrap().as_slice();
-        }
+#[cfg(feature = "embedded-storage")]
+impl<H: HashChain> VerifyScratch<H> {
+    /// Discards the state of the hashers, which is left behind by an aborted verification.
+    pub(crate) fn reset(&mut self) {
+        self.hasher = H::default();
+        self.public_key_hasher = H::default();
     }
 }
 
-#[allow(dead_code)]
 pub fn verify_signature_inmemory<'a, H: HashChain>(
     signature: &InMemoryLmotsSignature<'a, H>,
     public_key: &LmotsPublicKey<H>,
@@ -83,6 +45,7 @@ pub fn verify_signature_inmemory<'a, H: HashChain>(
         &public_key.lms_tree_identifier,
         u32::from_be_bytes(public_key.lms_leaf_identifier),
         message,
+        &mut VerifyScratch::default(),
     );
 
     public_key_candidate == public_key.key
@@ -93,9 +56,14 @@ pub fn generate_public_key_candiate<'a, H: HashChain>(
     lms_tree_identifier: &[u8],
     lms_leaf_identifier: u32,
     message: &[u8],
+    scratch: &mut VerifyScratch<H>,
 ) -> ArrayVec<[u8; MAX_HASH_SIZE]> {
     let lmots_parameter = signature.lmots_parameter;
-    let mut hasher = lmots_parameter.get_hasher();
+    let VerifyScratch {
+        hasher,
+        public_key_hasher,
+        hash_chain_data,
+    } = scratch;
 
     let lms_leaf_identifier = lms_leaf_identifier.to_be_bytes();
 
@@ -108,7 +76,13 @@ pub fn generate_public_key_candiate<'a, H: HashChain>(
     let message_hash = hasher.finalize_reset();
     let message_hash_with_checksum = lmots_parameter.append_checksum_to(message_hash.as_slice());
 
-    let mut hash_chain_array = HashChainArray::new(&lmots_parameter);
+    // The end of each hash chain is directly fed into the public key hasher, so there is no need
+    // to keep all hash chain values in memory.
+    public_key_hasher.update(lms_tree_identifier);
+    public_key_hasher.update(&lms_leaf_identifier);
+    public_key_hasher.update(&D_PBLC);
+
+    *hash_chain_data = H::prepare_hash_chain_data(lms_tree_identifier, &lms_leaf_identifier);
     let max_w = 2usize.pow(lmots_parameter.get_winternitz() as u32) - 1;
 
     for i in 0..lmots_parameter.get_hash_chain_count() {
@@ -119,23 +93,15 @@ pub fn generate_public_key_candiate<'a, H: HashChain>(
         ) as usize;
 
         let initial = signature.get_signature_data(i as usize);
-        let mut hash_chain_data =
-            H::prepare_hash_chain_data(lms_tree_identifier, &lms_leaf_identifier);
-        let result = hasher.do_hash_chain(&mut hash_chain_data, i, initial, a, max_w);
+        let result = hasher.do_hash_chain(hash_chain_data, i, initial, a, max_w);
 
-        hash_chain_array.push(&result);
+        public_key_hasher.update(result.as_slice());
     }
 
-    hasher.update(lms_tree_identifier);
-    hasher.update(&lms_leaf_identifier);
-    hasher.update(&D_PBLC);
-    for hash_chain in hash_chain_array.as_slice() {
-        hasher.update(hash_chain.as_slice());
-    }
-    hasher.finalize()
+    public_key_hasher.finalize_reset()
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "sign", feature = "sha256"))]
 mod tests {
     use tinyvec::ArrayVec;
 
@@ -167,8 +133,12 @@ mod tests {
                 ]);
 
                 let parameter = $type.construct_parameter::<$hash_chain>().unwrap();
-                let private_key =
-                    generate_private_key(lms_tree_identifier, lms_leaf_identifier, seed, parameter);
+                let private_key = generate_private_key(
+                    lms_tree_identifier,
+                    lms_leaf_identifier,
+                    &seed,
+                    parameter,
+                );
                 let public_key: LmotsPublicKey<$hash_chain> = generate_public_key(&private_key);
 
                 let mut message = [1, 3, 5, 9, 0];
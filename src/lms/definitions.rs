@@ -1,19 +1,28 @@
 use crate::constants::*;
 use crate::hasher::HashChain;
-use crate::lm_ots::definitions::LmotsPrivateKey;
 use crate::lm_ots::parameters::{LmotsAlgorithm, LmotsParameter};
-use crate::lms::helper::get_tree_element;
 use crate::lms::parameters::LmsAlgorithm;
-use crate::lms::MutableExpandedAuxData;
 use crate::util::helper::read_and_advance;
-use crate::{lm_ots, Seed};
-
-use core::convert::TryInto;
+#[cfg(feature = "sign")]
+use crate::{
+    lm_ots::{self, definitions::LmotsPrivateKey},
+    lms::{helper::get_tree_element, MutableExpandedAuxData},
+    Seed,
+};
+use crate::{signature::Error, VerifyingKey};
+
+use core::convert::{TryFrom, TryInto};
+use core::mem::size_of;
 use tinyvec::ArrayVec;
 
 use super::parameters::LmsParameter;
 
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+/**
+ * Private key of a single LMS tree, e.g. of one HSS level. Every signature advances
+ * `used_leafs_index`. [`super::LmsPrivateKey`] wraps it for the single LMS trees of the API.
+ */
+#[cfg(feature = "sign")]
+#[derive(Debug, Default, PartialEq, Eq)]
 pub struct LmsPrivateKey<H: HashChain> {
     pub lms_tree_identifier: LmsTreeIdentifier,
     pub used_leafs_index: u32,
@@ -22,6 +31,7 @@ pub struct LmsPrivateKey<H: HashChain> {
     pub lms_parameter: LmsParameter<H>,
 }
 
+#[cfg(feature = "sign")]
 impl<H: HashChain> LmsPrivateKey<H> {
     pub fn new(
         seed: Seed<H>,
@@ -39,7 +49,7 @@ impl<H: HashChain> LmsPrivateKey<H> {
         }
     }
 
-    pub fn use_lmots_private_key(&mut self) -> Result<LmotsPrivateKey<H>, ()> {
+    pub(crate) fn use_lmots_private_key(&mut self) -> Result<LmotsPrivateKey<H>, ()> {
         let number_of_lm_ots_keys = self.lms_parameter.number_of_lm_ots_keys();
 
         if self.used_leafs_index as usize >= number_of_lm_ots_keys {
@@ -49,7 +59,7 @@ impl<H: HashChain> LmsPrivateKey<H> {
         let key = lm_ots::keygen::generate_private_key(
             self.lms_tree_identifier,
             self.used_leafs_index.to_be_bytes(),
-            self.seed,
+            &self.seed,
             self.lmots_parameter,
         );
         self.used_leafs_index += 1;
@@ -58,6 +68,9 @@ impl<H: HashChain> LmsPrivateKey<H> {
     }
 }
 
+/**
+ * Public key of a single LMS tree, i.e. the root of the tree together with its identifier.
+ */
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct LmsPublicKey<H: HashChain> {
     pub key: ArrayVec<[u8; MAX_HASH_SIZE]>,
@@ -67,7 +80,8 @@ pub struct LmsPublicKey<H: HashChain> {
 }
 
 impl<H: HashChain> LmsPublicKey<H> {
-    pub fn new(
+    #[cfg(feature = "sign")]
+    pub(crate) fn new(
         private_key: &LmsPrivateKey<H>,
         aux_data: &mut Option<MutableExpandedAuxData>,
     ) -> Self {
@@ -93,6 +107,56 @@ impl<H: HashChain> LmsPublicKey<H> {
 
         result
     }
+
+    pub fn from_binary_representation(data: &[u8]) -> Result<Self, Error> {
+        if data.len() != lms_public_key_length(H::OUTPUT_SIZE as usize) {
+            return Err(Error::new());
+        }
+
+        let public_key = InMemoryLmsPublicKey::<H>::new(data).ok_or_else(Error::new)?;
+
+        Ok(Self {
+            key: ArrayVec::try_from(public_key.key).map_err(|_| Error::new())?,
+            lms_tree_identifier: public_key
+                .lms_tree_identifier
+                .try_into()
+                .map_err(|_| Error::new())?,
+            lmots_parameter: public_key.lmots_parameter,
+            lms_parameter: public_key.lms_parameter,
+        })
+    }
+}
+
+/**
+ * Extracts the LMS public key of a single level HSS key.
+ */
+impl<H: HashChain> TryFrom<&VerifyingKey<H>> for LmsPublicKey<H> {
+    type Error = Error;
+
+    fn try_from(verifying_key: &VerifyingKey<H>) -> Result<Self, Self::Error> {
+        let (levels, public_key) = verifying_key
+            .as_slice()
+            .split_at(size_of::<u32>().min(verifying_key.as_slice().len()));
+
+        if levels != 1u32.to_be_bytes() {
+            return Err(Error::new());
+        }
+
+        Self::from_binary_representation(public_key)
+    }
+}
+
+/**
+ * Single level HSS key of the LMS public key.
+ */
+impl<H: HashChain> From<&LmsPublicKey<H>> for VerifyingKey<H> {
+    fn from(public_key: &LmsPublicKey<H>) -> Self {
+        let mut bytes: ArrayVec<[u8; MAX_HSS_PUBLIC_KEY_LENGTH]> = ArrayVec::new();
+        bytes.extend_from_slice(&1u32.to_be_bytes());
+        bytes.extend_from_slice(public_key.to_binary_representation().as_slice());
+
+        VerifyingKey::from_bytes(bytes.as_slice()).unwrap()
+    }
 }
 
 #[derive(Clone)]
@@ -116,6 +180,10 @@ impl<'a, H: HashChain> PartialEq<LmsPublicKey<H>> for InMemoryLmsPublicKey<'a, H
 
 impl<'a, H: HashChain> InMemoryLmsPublicKey<'a, H> {
     pub fn new(data: &'a [u8]) -> Option<Self> {
+        if data.len() < lms_public_key_length(H::OUTPUT_SIZE as usize) {
+            return None;
+        }
+
         // Parsing like desribed in 5.4.2
         let mut data_index = 0;
 
@@ -141,12 +209,12 @@ impl<'a, H: HashChain> InMemoryLmsPublicKey<'a, H> {
         })
     }
 
-    pub fn as_slice(&self) -> &[u8] {
+    pub fn as_slice(&self) -> &'a [u8] {
         self.complete_data
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "sign", feature = "sha256"))]
 mod tests {
     use crate::{
         lm_ots::parameters::LmotsAlgorithm,
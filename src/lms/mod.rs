@@ -1,22 +1,204 @@
-use crate::hasher::HashChain;
-use crate::hss::aux::MutableExpandedAuxData;
-use crate::hss::parameter::HssParameter;
-use crate::hss::reference_impl_private_key::SeedAndLmsTreeIdentifier;
-use crate::lms::definitions::LmsPrivateKey;
-use crate::lms::definitions::LmsPublicKey;
-
-pub mod definitions;
+//! Single LMS trees (RFC 8554, Section 5) without the HSS level prefix.
+//!
+//! The signatures and public keys are encoded as `lms_signature` and `lms_public_key` of the RFC.
+//! A single level HSS key can be converted into an LMS key and vice versa, e.g.
+//! `LmsPrivateKey::try_from(signing_key)`.
+//!
+//! ```
+//! use hbs_lms::{lms, HssParameter, LmotsAlgorithm, LmsAlgorithm, Seed, Sha256_256};
+//! use rand::{rngs::OsRng, RngCore};
+//!
+//! let mut seed = Seed::default();
+//! OsRng.fill_bytes(seed.as_mut_slice());
+//!
+//! let parameter = HssParameter::<Sha256_256>::new(LmotsAlgorithm::LmotsW4, LmsAlgorithm::LmsH5);
+//! let (mut private_key, public_key) = lms::keygen(&parameter, &seed, &[42u8; 16]);
+//!
+//! let signature = lms::sign(&mut private_key, b"message").unwrap();
+//!
+//! assert!(lms::verify::<Sha256_256>(
+//!     b"message",
+//!     signature.to_binary_representation().as_slice(),
+//!     public_key.to_binary_representation().as_slice(),
+//! )
+//! .is_ok());
+//! ```
+
+#[cfg(feature = "sign")]
+use core::convert::TryFrom;
+use core::convert::TryInto;
+
+#[cfg(feature = "sign")]
+use crate::{
+    constants::LmsTreeIdentifier,
+    hss::{
+        aux::MutableExpandedAuxData,
+        definitions::HssPrivateKey,
+        parameter::HssParameter,
+        reference_impl_private_key::{
+            generate_signature_randomizer, CompressedUsedLeafsIndexes, ReferenceImplPrivateKey,
+            Seed, SeedAndLmsTreeIdentifier,
+        },
+        SigningKey,
+    },
+};
+use crate::{
+    constants::{lms_public_key_length, lms_signature_length},
+    hasher::HashChain,
+    lm_ots::{parameters::LmotsAlgorithm, verify::VerifyScratch},
+    signature::Error,
+};
+
+use self::{
+    definitions::InMemoryLmsPublicKey, parameters::LmsAlgorithm, signing::InMemoryLmsSignature,
+};
+
+pub(crate) mod definitions;
+#[cfg(feature = "sign")]
 mod helper;
-pub mod parameters;
-pub mod signing;
-pub mod verify;
+pub(crate) mod parameters;
+pub(crate) mod signing;
+pub(crate) mod verify;
+
+pub use self::definitions::LmsPublicKey;
+pub use self::signing::LmsSignature;
+
+/**
+ * Private key of a single LMS tree. Every signature advances the key by one leaf, so the key can
+ * neither be cloned nor rewound.
+ *
+ * A key converted from a single level [`SigningKey`] keeps its seed and can be converted back,
+ * once it signed the messages, e.g. to store its state.
+ */
+#[cfg(feature = "sign")]
+#[derive(Debug, PartialEq, Eq)]
+pub struct LmsPrivateKey<H: HashChain> {
+    private_key: definitions::LmsPrivateKey<H>,
+    hss_seed: Option<Seed<H>>,
+}
+
+#[cfg(feature = "sign")]
+impl<H: HashChain> LmsPrivateKey<H> {
+    /**
+     * Creates the private key of an unused LMS tree.
+     *
+     * # Arguments
+     * * `lmots_algorithm` - The Winternitz parameter of the LM-OTS keys.
+     * * `lms_algorithm` - The height of the tree.
+     * * `seed` - The seed, from which the LM-OTS private keys are derived.
+     * * `lms_tree_identifier` - The identifier `I` of the tree.
+     */
+    pub fn new(
+        lmots_algorithm: LmotsAlgorithm,
+        lms_algorithm: LmsAlgorithm,
+        seed: &Seed<H>,
+        lms_tree_identifier: &LmsTreeIdentifier,
+    ) -> Result<Self, Error> {
+        let private_key = definitions::LmsPrivateKey::new(
+            seed.clone(),
+            *lms_tree_identifier,
+            0,
+            lmots_algorithm
+                .construct_parameter()
+                .ok_or_else(Error::new)?,
+            lms_algorithm.construct_parameter().ok_or_else(Error::new)?,
+        );
+
+        Ok(Self {
+            private_key,
+            hss_seed: None,
+        })
+    }
+
+    /// Number of signatures left to the key.
+    pub fn remaining(&self) -> u32 {
+        self.private_key.lms_parameter.number_of_lm_ots_keys() as u32
+            - self.private_key.used_leafs_index
+    }
+
+    /// Identifier `I` of the tree.
+    pub fn tree_identifier(&self) -> &LmsTreeIdentifier {
+        &self.private_key.lms_tree_identifier
+    }
+
+    pub fn lmots_algorithm(&self) -> LmotsAlgorithm {
+        LmotsAlgorithm::from(self.private_key.lmots_parameter.get_type_id())
+    }
+
+    pub fn lms_algorithm(&self) -> LmsAlgorithm {
+        LmsAlgorithm::from(self.private_key.lms_parameter.get_type_id())
+    }
+}
 
-pub struct LmsKeyPair<H: HashChain> {
-    pub private_key: LmsPrivateKey<H>,
+/**
+ * Extracts the LMS tree of a single level HSS key. The HSS key is consumed, as both keys would
+ * reuse the same leafs.
+ */
+#[cfg(feature = "sign")]
+impl<H: HashChain> TryFrom<SigningKey<H>> for LmsPrivateKey<H> {
+    type Error = Error;
+
+    fn try_from(signing_key: SigningKey<H>) -> Result<Self, Self::Error> {
+        let rfc_private_key =
+            ReferenceImplPrivateKey::<H>::from_binary_representation(signing_key.as_slice())
+                .map_err(|_| Error::new())?;
+
+        // A single LMS private key can't enforce the end of a signature range.
+        if rfc_private_key.signature_range.is_some()
+            || rfc_private_key
+                .compressed_parameter
+                .to::<H>()
+                .map_err(|_| Error::new())?
+                .len()
+                != 1
+        {
+            return Err(Error::new());
+        }
+
+        let mut hss_private_key =
+            HssPrivateKey::from(&rfc_private_key, &mut None).map_err(|_| Error::new())?;
+
+        Ok(Self {
+            private_key: hss_private_key.private_key.pop().ok_or_else(Error::new)?,
+            hss_seed: Some(rfc_private_key.seed.clone()),
+        })
+    }
+}
+
+/**
+ * Single level HSS key of an LMS private key, which was converted from a [`SigningKey`]. The seed
+ * of other keys is derived from the HSS seed, so they can't be converted.
+ */
+#[cfg(feature = "sign")]
+impl<H: HashChain> TryFrom<LmsPrivateKey<H>> for SigningKey<H> {
+    type Error = Error;
+
+    fn try_from(private_key: LmsPrivateKey<H>) -> Result<Self, Self::Error> {
+        let hss_seed = private_key.hss_seed.as_ref().ok_or_else(Error::new)?;
+
+        let mut rfc_private_key = ReferenceImplPrivateKey::generate(
+            &[HssParameter::new(
+                private_key.lmots_algorithm(),
+                private_key.lms_algorithm(),
+            )],
+            hss_seed,
+        )
+        .map_err(|_| Error::new())?;
+        rfc_private_key.compressed_used_leafs_indexes =
+            CompressedUsedLeafsIndexes::new(private_key.private_key.used_leafs_index.into());
+
+        SigningKey::from_private_key(&rfc_private_key)
+    }
+}
+
+#[cfg(feature = "sign")]
+pub(crate) struct LmsKeyPair<H: HashChain> {
+    pub private_key: definitions::LmsPrivateKey<H>,
     pub public_key: LmsPublicKey<H>,
 }
 
-pub fn generate_key_pair<H: HashChain>(
+#[cfg(feature = "sign")]
+pub(crate) fn generate_key_pair<H: HashChain>(
     seed: &SeedAndLmsTreeIdentifier<H>,
     parameter: &HssParameter<H>,
     used_leafs_index: &u32,
@@ -25,8 +207,8 @@ pub fn generate_key_pair<H: HashChain>(
     let lmots_parameter = parameter.get_lmots_parameter();
     let lms_parameter = parameter.get_lms_parameter();
 
-    let private_key = LmsPrivateKey::new(
-        seed.seed,
+    let private_key = definitions::LmsPrivateKey::new(
+        seed.seed.clone(),
         seed.lms_tree_identifier,
         *used_leafs_index,
         *lmots_parameter,
@@ -39,3 +221,225 @@ pub fn generate_key_pair<H: HashChain>(
         public_key,
     }
 }
+
+/**
+ * Generate a single LMS tree.
+ *
+ * # Arguments
+ * * `HashChain` - The hasher implementation that should be used. ```Sha256``` is a standard software implementation.
+ * * `parameter` - The LM-OTS and LMS parameter of the tree.
+ * * `seed` - The seed, from which the LM-OTS private keys are derived.
+ * * `lms_tree_identifier` - The identifier `I` of the tree.
+ */
+#[cfg(feature = "sign")]
+pub fn keygen<H: HashChain>(
+    parameter: &HssParameter<H>,
+    seed: &Seed<H>,
+    lms_tree_identifier: &LmsTreeIdentifier,
+) -> (LmsPrivateKey<H>, LmsPublicKey<H>) {
+    let key_pair = generate_key_pair(
+        &SeedAndLmsTreeIdentifier::new(seed, lms_tree_identifier),
+        parameter,
+        &0,
+        &mut None,
+    );
+
+    (
+        LmsPrivateKey {
+            private_key: key_pair.private_key,
+            hss_seed: None,
+        },
+        key_pair.public_key,
+    )
+}
+
+/**
+ * Sign a message with the next unused leaf of `private_key`.
+ *
+ * The randomizer `C` is derived from the seed like for the HSS signatures of this crate, so a
+ * key converted from a single level [`crate::SigningKey`] signs exactly like the HSS key.
+ *
+ * # Arguments
+ * * `private_key` - The private key that should be used. It is advanced by one leaf.
+ * * `message` - The message that should be signed.
+ */
+#[cfg(feature = "sign")]
+pub fn sign<H: HashChain>(
+    private_key: &mut LmsPrivateKey<H>,
+    message: &[u8],
+) -> Result<LmsSignature<H>, Error> {
+    let private_key = &mut private_key.private_key;
+    let signature_randomizer = generate_signature_randomizer::<H>(
+        &SeedAndLmsTreeIdentifier::new(&private_key.seed, &private_key.lms_tree_identifier),
+        &private_key.used_leafs_index,
+    );
+
+    LmsSignature::sign(private_key, message, &signature_randomizer, &mut None)
+        .map_err(|_| Error::new())
+}
+
+/**
+ * Verify an LMS signature.
+ *
+ * # Arguments
+ * * `HashChain` - The hasher implementation that should be used. ```Sha256``` is a standard software implementation.
+ * * `message` - The message that should be verified.
+ * * `signature` - The encoded `lms_signature` that should be used for verification.
+ * * `public_key` - The encoded `lms_public_key` that should be used for verification.
+ */
+pub fn verify<H: HashChain>(
+    message: &[u8],
+    signature: &[u8],
+    public_key: &[u8],
+) -> Result<(), Error> {
+    if signature_length::<H>(signature) != Some(signature.len()) {
+        return Err(Error::new());
+    }
+
+    if public_key.len() != lms_public_key_length(H::OUTPUT_SIZE as usize) {
+        return Err(Error::new());
+    }
+
+    let public_key = InMemoryLmsPublicKey::<H>::new(public_key).ok_or_else(Error::new)?;
+    let signature = InMemoryLmsSignature::<H>::new(signature).ok_or_else(Error::new)?;
+
+    verify::verify(
+        &signature,
+        &public_key,
+        message,
+        &mut VerifyScratch::default(),
+    )
+    .map_err(|_| Error::new())
+}
+
+/// Length of the signature according to its type codes, checked before parsing the signature.
+pub(crate) fn signature_length<H: HashChain>(signature: &[u8]) -> Option<usize> {
+    let n = H::OUTPUT_SIZE as usize;
+
+    let lmots_type = signature.get(4..8)?;
+    let lmots_parameter =
+        LmotsAlgorithm::get_from_type::<H>(u32::from_be_bytes(lmots_type.try_into().unwrap()))?;
+    let p = lmots_parameter.get_hash_chain_count() as usize;
+
+    let lms_type_start = 8 + n * (p + 1);
+    let lms_type = signature.get(lms_type_start..lms_type_start + 4)?;
+    let lms_parameter =
+        LmsAlgorithm::get_from_type::<H>(u32::from_be_bytes(lms_type.try_into().unwrap()))?;
+
+    Some(lms_signature_length(
+        n,
+        p,
+        lms_parameter.get_tree_height() as usize,
+    ))
+}
+
+#[cfg(all(test, feature = "sign", feature = "sha256"))]
+mod tests {
+    use core::convert::TryFrom;
+
+    use crate::{
+        hss::hss_keygen, util::helper::test_helper::gen_random_seed, HssParameter, LmotsAlgorithm,
+        LmsAlgorithm, Sha256_192, Sha256_256, SigningKey, VerifyingKey,
+    };
+
+    use super::{keygen, sign, verify, LmsPrivateKey, LmsPublicKey};
+
+    #[test]
+    fn sign_and_verify() {
+        type H = Sha256_192;
+        let parameter = HssParameter::<H>::new(LmotsAlgorithm::LmotsW2, LmsAlgorithm::LmsH5);
+        let (mut private_key, public_key) = keygen(&parameter, &gen_random_seed(), &[7u8; 16]);
+        let public_key = public_key.to_binary_representation();
+
+        let first = sign(&mut private_key, b"first").unwrap();
+        let second = sign(&mut private_key, b"second").unwrap();
+        assert_eq!(private_key.remaining(), 30);
+        assert_eq!(u32::from_be_bytes(second.lms_leaf_identifier), 1);
+
+        let first = first.to_binary_representation();
+        assert!(verify::<H>(b"first", first.as_slice(), public_key.as_slice()).is_ok());
+        assert!(verify::<H>(b"second", first.as_slice(), public_key.as_slice()).is_err());
+        assert!(verify::<H>(
+            b"second",
+            second.to_binary_representation().as_slice(),
+            public_key.as_slice()
+        )
+        .is_ok());
+
+        // Truncated or extended encodings are rejected without panicking.
+        assert!(verify::<H>(b"first", &first[..first.len() - 1], public_key.as_slice()).is_err());
+        assert!(verify::<H>(b"first", &first[..6], public_key.as_slice()).is_err());
+        assert!(verify::<H>(b"first", first.as_slice(), &public_key[1..]).is_err());
+
+        for _ in 2..32 {
+            sign(&mut private_key, b"message").unwrap();
+        }
+        assert!(sign(&mut private_key, b"message").is_err());
+    }
+
+    #[test]
+    fn convert_single_level_hss_keys() {
+        type H = Sha256_256;
+        let parameters = [HssParameter::<H>::new(
+            LmotsAlgorithm::LmotsW4,
+            LmsAlgorithm::LmsH5,
+        )];
+        let (mut signing_key, verifying_key) =
+            hss_keygen::<H>(&parameters, &gen_random_seed(), None).unwrap();
+
+        let public_key = LmsPublicKey::try_from(&verifying_key).unwrap();
+        assert_eq!(VerifyingKey::from(&public_key), verifying_key);
+        assert_eq!(
+            &verifying_key.as_slice()[4..],
+            public_key.to_binary_representation().as_slice()
+        );
+
+        let hss_signature = signing_key.try_sign_with_aux(b"first", None).unwrap();
+        let start = signing_key.signature_range().unwrap().start;
+        let mut private_key = LmsPrivateKey::try_from(signing_key.clone()).unwrap();
+        assert_eq!(u64::from(private_key.remaining()), 32 - start);
+        assert_eq!(
+            private_key.tree_identifier(),
+            &public_key.lms_tree_identifier
+        );
+        assert_eq!(private_key.lmots_algorithm(), LmotsAlgorithm::LmotsW4);
+        assert_eq!(private_key.lms_algorithm(), LmsAlgorithm::LmsH5);
+
+        // An HSS signature with a single level is the LMS signature prefixed with Nspk = 0.
+        assert!(verify::<H>(
+            b"first",
+            &hss_signature.as_ref()[4..],
+            public_key.to_binary_representation().as_slice()
+        )
+        .is_ok());
+
+        let signature = sign(&mut private_key, b"second").unwrap();
+        let hss_signature = signing_key.try_sign_with_aux(b"second", None).unwrap();
+        assert_eq!(
+            signature.to_binary_representation().as_slice(),
+            &hss_signature.as_ref()[4..]
+        );
+
+        // The converted key continues with the state of the LMS key.
+        let signing_key_of_private_key = SigningKey::try_from(private_key).unwrap();
+        assert_eq!(signing_key_of_private_key, signing_key);
+
+        let (two_levels, two_levels_public) =
+            hss_keygen::<H>(&[parameters[0], parameters[0]], &gen_random_seed(), None).unwrap();
+        assert!(LmsPrivateKey::try_from(two_levels).is_err());
+
+        let partitions = signing_key.partition(2).unwrap();
+        assert!(LmsPrivateKey::try_from(partitions[0].clone()).is_err());
+        assert!(LmsPublicKey::try_from(&two_levels_public).is_err());
+
+        // The seed of an LMS key can't be traced back to an HSS seed.
+        let stranger = LmsPrivateKey::<H>::new(
+            LmotsAlgorithm::LmotsW4,
+            LmsAlgorithm::LmsH5,
+            &gen_random_seed(),
+            &[7u8; 16],
+        )
+        .unwrap();
+        assert!(SigningKey::try_from(stranger).is_err());
+    }
+}
@@ -1,9 +1,12 @@
-use core::marker::PhantomData;
+use core::{fmt, marker::PhantomData, str::FromStr};
 
-use crate::hasher::{sha256::Sha256_256, HashChain};
+#[cfg(feature = "sha256")]
+use crate::hasher::sha256::Sha256_256;
+use crate::hasher::HashChain;
+use crate::util::helper::{is_registered_hash, split_algorithm_name};
 
 /// Specifies the used Tree height.
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum LmsAlgorithm {
     LmsReserved = 0,
     #[cfg(test)]
@@ -37,6 +40,7 @@ impl From<u32> for LmsAlgorithm {
 }
 
 impl LmsAlgorithm {
+    #[cfg(feature = "sha256")]
     pub fn construct_default_parameter() -> LmsParameter<Sha256_256> {
         LmsAlgorithm::LmsH5.construct_parameter().unwrap()
     }
@@ -68,6 +72,76 @@ impl LmsAlgorithm {
     }
 }
 
+impl LmsAlgorithm {
+    /// Writes the IANA name for the given hash function, e.g. `LMS_SHA256_M32_H10`.
+    pub(crate) fn fmt_name(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        hash_name: &str,
+        output_size: u16,
+    ) -> fmt::Result {
+        let tree_height = match *self {
+            LmsAlgorithm::LmsReserved => return f.write_str("LMS_RESERVED"),
+            #[cfg(test)]
+            LmsAlgorithm::LmsH2 => 2,
+            LmsAlgorithm::LmsH5 => 5,
+            LmsAlgorithm::LmsH10 => 10,
+            LmsAlgorithm::LmsH15 => 15,
+            LmsAlgorithm::LmsH20 => 20,
+            LmsAlgorithm::LmsH25 => 25,
+        };
+
+        write!(f, "LMS_{}_M{}_H{}", hash_name, output_size, tree_height)
+    }
+
+    /// Parses an IANA name and returns the algorithm together with the name and the output size of
+    /// the hash function.
+    pub(crate) fn from_name(name: &str) -> Option<(Self, &str, u16)> {
+        let (hash_name, output_size, tree_height) = split_algorithm_name(name, "LMS", 'M')?;
+
+        let lms_algorithm = match tree_height {
+            "H5" => LmsAlgorithm::LmsH5,
+            "H10" => LmsAlgorithm::LmsH10,
+            "H15" => LmsAlgorithm::LmsH15,
+            "H20" => LmsAlgorithm::LmsH20,
+            "H25" => LmsAlgorithm::LmsH25,
+            _ => return None,
+        };
+
+        Some((lms_algorithm, hash_name, output_size))
+    }
+}
+
+/**
+ * Formats the name of the SHA-256/M32 parameter set (RFC 8554), e.g. `LMS_SHA256_M32_H10`, as the
+ * algorithm itself does not fix the hash function. Use [`crate::HssParameter`] for the names of
+ * the other hash functions.
+ */
+impl fmt::Display for LmsAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_name(f, "SHA256", 32)
+    }
+}
+
+/**
+ * Parses the names of RFC 8554 and SP 800-208, e.g. `LMS_SHAKE_M24_H15`. The hash function is
+ * not kept, it is chosen by the [`HashChain`] the algorithm is used with.
+ */
+impl FromStr for LmsAlgorithm {
+    type Err = signature::Error;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match LmsAlgorithm::from_name(name) {
+            Some((lms_algorithm, hash_name, output_size))
+                if is_registered_hash(hash_name, output_size) =>
+            {
+                Ok(lms_algorithm)
+            }
+            _ => Err(signature::Error::new()),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct LmsParameter<H: HashChain> {
     type_id: u32,
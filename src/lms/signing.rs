@@ -1,20 +1,21 @@
-use crate::constants::{
-    LmsLeafIdentifier, MAX_HASH_SIZE, MAX_LMS_SIGNATURE_LENGTH, MAX_TREE_HEIGHT,
-};
+use crate::constants::{LmsLeafIdentifier, MAX_HASH_SIZE, MAX_TREE_HEIGHT};
 use crate::hasher::HashChain;
-use crate::hss::aux::MutableExpandedAuxData;
 use crate::lm_ots;
-use crate::lm_ots::definitions::LmotsPrivateKey;
 use crate::lm_ots::parameters::LmotsAlgorithm;
 use crate::lm_ots::signing::InMemoryLmotsSignature;
 use crate::lm_ots::signing::LmotsSignature;
-use crate::lms::definitions::LmsPrivateKey;
-use crate::lms::parameters::LmsAlgorithm;
+use crate::lms::{self, parameters::LmsAlgorithm};
 use crate::util::helper::{read, read_and_advance};
+#[cfg(feature = "sign")]
+use crate::{
+    constants::MAX_LMS_SIGNATURE_LENGTH, hss::aux::MutableExpandedAuxData,
+    lm_ots::definitions::LmotsPrivateKey, lms::definitions::LmsPrivateKey,
+};
 
 use core::convert::TryInto;
 use tinyvec::ArrayVec;
 
+#[cfg(feature = "sign")]
 use super::helper::get_tree_element;
 use super::parameters::LmsParameter;
 
@@ -61,6 +62,7 @@ impl<'a, H: HashChain> PartialEq<LmsSignature<H>> for InMemoryLmsSignature<'a, H
 }
 
 impl<H: HashChain> LmsSignature<H> {
+    #[cfg(feature = "sign")]
     fn build_authentication_path(
         lms_private_key: &mut LmsPrivateKey<H>,
         lm_ots_private_key: &LmotsPrivateKey<H>,
@@ -81,7 +83,7 @@ impl<H: HashChain> LmsSignature<H> {
     }
 
     #[cfg(feature = "fast_verify")]
-    pub fn sign_fast_verify(
+    pub(crate) fn sign_fast_verify(
         lms_private_key: &mut LmsPrivateKey<H>,
         message: Option<&[u8]>,
         message_mut: Option<&mut [u8]>,
@@ -113,7 +115,8 @@ impl<H: HashChain> LmsSignature<H> {
         Ok(signature)
     }
 
-    pub fn sign(
+    #[cfg(feature = "sign")]
+    pub(crate) fn sign(
         lms_private_key: &mut LmsPrivateKey<H>,
         message: &[u8],
         signature_randomizer: &ArrayVec<[u8; MAX_HASH_SIZE]>,
@@ -140,6 +143,7 @@ impl<H: HashChain> LmsSignature<H> {
         Ok(signature)
     }
 
+    #[cfg(feature = "sign")]
     pub fn to_binary_representation(&self) -> ArrayVec<[u8; MAX_LMS_SIGNATURE_LENGTH]> {
         let mut result = ArrayVec::new();
 
@@ -161,6 +165,11 @@ impl<H: HashChain> LmsSignature<H> {
 
 impl<'a, H: HashChain> InMemoryLmsSignature<'a, H> {
     pub fn new(data: &'a [u8]) -> Option<Self> {
+        // The length is checked against the type codes before the fields are read.
+        if data.len() < lms::signature_length::<H>(data)? {
+            return None;
+        }
+
         // Parsing like 5.4.2 Algorithm 6a
         let mut index = 0;
 
@@ -170,18 +179,16 @@ impl<'a, H: HashChain> InMemoryLmsSignature<'a, H> {
         // LMOTS Signature consists of LMOTS parameter, signature randomizer & signature data
         let lmots_parameter = LmotsAlgorithm::get_from_type::<H>(u32::from_be_bytes(
             read(data, 4, &index).try_into().unwrap(),
-        ))
-        .unwrap();
+        ))?;
         let lmots_signature = lm_ots::signing::InMemoryLmotsSignature::new(read_and_advance(
             data,
             (4 + H::OUTPUT_SIZE * (1 + lmots_parameter.get_hash_chain_count())) as usize,
             &mut index,
-        ))
-        .unwrap();
+        ))?;
 
         let _type = u32::from_be_bytes(read_and_advance(data, 4, &mut index).try_into().unwrap());
 
-        let lms_parameter = LmsAlgorithm::get_from_type(_type).unwrap();
+        let lms_parameter = LmsAlgorithm::get_from_type(_type)?;
         let authentication_path = read_and_advance(
             data,
             (H::OUTPUT_SIZE * lms_parameter.get_tree_height() as u16) as usize,
@@ -208,7 +215,7 @@ impl<'a, H: HashChain> InMemoryLmsSignature<'a, H> {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "sign", feature = "sha256"))]
 mod tests {
     use crate::{
         lm_ots::parameters::LmotsAlgorithm,
@@ -2,7 +2,7 @@ use tinyvec::ArrayVec;
 
 use crate::constants::{D_INTR, D_LEAF, MAX_HASH_SIZE};
 use crate::hasher::HashChain;
-use crate::lm_ots;
+use crate::lm_ots::{self, verify::VerifyScratch};
 use crate::util::helper::is_odd;
 
 use super::definitions::InMemoryLmsPublicKey;
@@ -12,6 +12,7 @@ pub fn verify<'a, H: HashChain>(
     signature: &InMemoryLmsSignature<'a, H>,
     public_key: &InMemoryLmsPublicKey<'a, H>,
     message: &[u8],
+    scratch: &mut VerifyScratch<H>,
 ) -> Result<(), ()> {
     if signature.lmots_signature.lmots_parameter != public_key.lmots_parameter
         || signature.lms_parameter != public_key.lms_parameter
@@ -19,7 +20,8 @@ pub fn verify<'a, H: HashChain>(
         return Err(());
     }
 
-    let public_key_canditate = generate_public_key_candiate(signature, public_key, message)?;
+    let public_key_canditate =
+        generate_public_key_candiate(signature, public_key, message, scratch)?;
 
     if public_key_canditate.as_slice() == public_key.key {
         Ok(())
@@ -32,6 +34,7 @@ fn generate_public_key_candiate<'a, H: HashChain>(
     signature: &InMemoryLmsSignature<'a, H>,
     public_key: &InMemoryLmsPublicKey<'a, H>,
     message: &[u8],
+    scratch: &mut VerifyScratch<H>,
 ) -> Result<ArrayVec<[u8; MAX_HASH_SIZE]>, ()> {
     let leafs = signature.lms_parameter.number_of_lm_ots_keys() as u32;
 
@@ -45,10 +48,11 @@ fn generate_public_key_candiate<'a, H: HashChain>(
         public_key.lms_tree_identifier,
         signature.lms_leaf_identifier,
         message,
+        scratch,
     );
 
     let mut node_num: u32 = leafs + signature.lms_leaf_identifier;
-    let mut hasher = H::default();
+    let hasher = &mut scratch.hasher;
 
     hasher.update(public_key.lms_tree_identifier);
     hasher.update(&node_num.to_be_bytes());
@@ -80,10 +84,10 @@ fn generate_public_key_candiate<'a, H: HashChain>(
     Ok(temp)
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "sign", feature = "sha256"))]
 mod tests {
     use crate::{
-        lm_ots::parameters::LmotsAlgorithm,
+        lm_ots::{parameters::LmotsAlgorithm, verify::VerifyScratch},
         lms::{
             definitions::{InMemoryLmsPublicKey, LmsPrivateKey, LmsPublicKey},
             parameters::LmsAlgorithm,
@@ -139,12 +143,28 @@ mod tests {
         let first_signature = InMemoryLmsSignature::new(first_signature.as_slice()).unwrap();
         let second_signature = InMemoryLmsSignature::new(second_signature.as_slice()).unwrap();
 
-        assert!(super::verify(&first_signature, &public_key, &first_message).is_ok());
+        let mut scratch = VerifyScratch::default();
+
+        assert!(super::verify(&first_signature, &public_key, &first_message, &mut scratch).is_ok());
         first_message[5] = 13;
-        assert!(super::verify(&first_signature, &public_key, &first_message).is_err());
+        assert!(
+            super::verify(&first_signature, &public_key, &first_message, &mut scratch).is_err()
+        );
 
-        assert!(super::verify(&second_signature, &public_key, &second_message).is_ok());
+        assert!(super::verify(
+            &second_signature,
+            &public_key,
+            &second_message,
+            &mut scratch
+        )
+        .is_ok());
         second_message[4] = 13;
-        assert!(super::verify(&second_signature, &public_key, &second_message).is_err());
+        assert!(super::verify(
+            &second_signature,
+            &public_key,
+            &second_message,
+            &mut scratch
+        )
+        .is_err());
     }
 }
@@ -0,0 +1,293 @@
+// Known answers for every hash family, computed with an implementation independent of this
+// crate. The inputs below are shared by all hash families, the seed and the randomizer are
+// truncated to the output size of the hash function.
+
+use crate::{hasher::HashFamily, LmotsAlgorithm};
+
+pub(super) const MESSAGE: &[u8] = b"hbs-lms power-on self-test";
+#[cfg(feature = "sign")]
+pub(super) const SEED: [u8; 32] = [
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+    0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f,
+];
+#[cfg(feature = "sign")]
+pub(super) const LMS_TREE_IDENTIFIER: [u8; 16] = [
+    0xa0, 0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7, 0xa8, 0xa9, 0xaa, 0xab, 0xac, 0xad, 0xae, 0xaf,
+];
+#[cfg(feature = "sign")]
+pub(super) const LMS_LEAF_IDENTIFIER: u32 = 5;
+#[cfg(feature = "sign")]
+pub(super) const SIGNATURE_RANDOMIZER: [u8; 32] = [
+    0x40, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4a, 0x4b, 0x4c, 0x4d, 0x4e, 0x4f,
+    0x50, 0x51, 0x52, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5a, 0x5b, 0x5c, 0x5d, 0x5e, 0x5f,
+];
+
+#[cfg_attr(not(feature = "sign"), allow(dead_code))]
+pub(super) struct LmotsKnownAnswer {
+    pub lmots_algorithm: LmotsAlgorithm,
+    /// The key `K` of leaf `LMS_LEAF_IDENTIFIER`.
+    pub public_key: &'static [u8],
+    /// Hash of the encoded signature of `MESSAGE`.
+    pub signature_hash: &'static [u8],
+}
+
+#[cfg_attr(not(feature = "sign"), allow(dead_code))]
+pub(super) struct KnownAnswers {
+    pub hash_family: HashFamily,
+    /// Hash of `MESSAGE`.
+    pub message_hash: &'static [u8],
+    pub lmots: [LmotsKnownAnswer; 4],
+    /// The root `T[1]` of a tree with the parameters LM-OTS W1 and LMS H5.
+    pub lms_public_key: &'static [u8],
+}
+
+pub(super) static KNOWN_ANSWERS: [KnownAnswers; 4] = [
+    KnownAnswers {
+        hash_family: HashFamily::Sha256_256,
+        message_hash: &[
+            0xea, 0xa8, 0x1a, 0xdf, 0x18, 0x5a, 0xe2, 0xca, 0x63, 0x72, 0x41, 0x73, 0xa8, 0xed,
+            0x5a, 0x40, 0xbb, 0x27, 0xcf, 0x91, 0x5b, 0x05, 0x53, 0x7d, 0xb0, 0x6e, 0xc1, 0x51,
+            0x14, 0xf4, 0x35, 0x9b,
+        ],
+        lmots: [
+            LmotsKnownAnswer {
+                lmots_algorithm: LmotsAlgorithm::LmotsW1,
+                public_key: &[
+                    0xd5, 0x0d, 0xd4, 0x3f, 0xdf, 0x70, 0xfe, 0x0c, 0x2b, 0xe6, 0xa8, 0x00, 0xf5,
+                    0x3a, 0x4e, 0xb2, 0x06, 0x26, 0x9c, 0x73, 0xe6, 0x64, 0x28, 0xb3, 0x72, 0x50,
+                    0x87, 0x44, 0xfe, 0xac, 0x3a, 0xaa,
+                ],
+                signature_hash: &[
+                    0xa4, 0xec, 0xa5, 0x35, 0x6b, 0x75, 0xcf, 0x6b, 0xe8, 0xc1, 0x58, 0x63, 0x52,
+                    0x9d, 0x8a, 0x15, 0x81, 0x87, 0xfc, 0x0d, 0x80, 0xfc, 0x42, 0x2f, 0xeb, 0xe5,
+                    0x15, 0x6b, 0xef, 0x42, 0x85, 0xcc,
+                ],
+            },
+            LmotsKnownAnswer {
+                lmots_algorithm: LmotsAlgorithm::LmotsW2,
+                public_key: &[
+                    0xce, 0x0d, 0xad, 0x46, 0x92, 0xbe, 0x9a, 0x49, 0x8a, 0x79, 0x99, 0xd7, 0xe4,
+                    0x48, 0x81, 0x1c, 0xbf, 0x4a, 0x24, 0xd7, 0xe9, 0xee, 0xd2, 0x38, 0x71, 0xb7,
+                    0x2f, 0x39, 0x52, 0x00, 0x1a, 0xf6,
+                ],
+                signature_hash: &[
+                    0xf5, 0xef, 0xd2, 0xd9, 0x55, 0xbd, 0x9f, 0xf7, 0x7b, 0xda, 0xb6, 0x49, 0x29,
+                    0xf1, 0xa4, 0xab, 0x78, 0xde, 0xd9, 0x56, 0x73, 0x2f, 0x5b, 0xb1, 0xa7, 0x22,
+                    0xe6, 0xd3, 0x33, 0x80, 0x02, 0x24,
+                ],
+            },
+            LmotsKnownAnswer {
+                lmots_algorithm: LmotsAlgorithm::LmotsW4,
+                public_key: &[
+                    0xed, 0x79, 0xb0, 0xce, 0x5e, 0x7a, 0xd3, 0xc4, 0x55, 0x92, 0x20, 0x8a, 0x98,
+                    0xd0, 0x25, 0xbd, 0x12, 0xcb, 0x52, 0xb9, 0x4b, 0x3c, 0x92, 0xd9, 0x51, 0x54,
+                    0xf9, 0xb1, 0x2f, 0xf3, 0x3b, 0xf9,
+                ],
+                signature_hash: &[
+                    0x03, 0x69, 0x32, 0x10, 0x85, 0xd3, 0x82, 0x54, 0x2d, 0xb3, 0x40, 0xba, 0x66,
+                    0xe0, 0x30, 0x50, 0x04, 0x52, 0xb6, 0xa6, 0x33, 0xb5, 0x13, 0xb4, 0x6c, 0x92,
+                    0xb8, 0xb0, 0x3a, 0x6a, 0x5d, 0x42,
+                ],
+            },
+            LmotsKnownAnswer {
+                lmots_algorithm: LmotsAlgorithm::LmotsW8,
+                public_key: &[
+                    0x20, 0xed, 0x38, 0x42, 0x47, 0x83, 0x18, 0xee, 0x34, 0x4a, 0x96, 0x76, 0x69,
+                    0x24, 0x0f, 0xa2, 0xfa, 0xf2, 0x03, 0xa4, 0xb0, 0xe1, 0x79, 0xc5, 0xb8, 0xa4,
+                    0x92, 0x42, 0x99, 0x41, 0x3e, 0x0d,
+                ],
+                signature_hash: &[
+                    0x2d, 0x75, 0xd3, 0x0a, 0x4f, 0x3e, 0xf2, 0x52, 0xb0, 0x13, 0x9a, 0x3c, 0x50,
+                    0xd2, 0xba, 0xb9, 0xa2, 0x04, 0x52, 0x1a, 0xe3, 0xcd, 0x79, 0x03, 0x5d, 0x68,
+                    0x45, 0x12, 0x1e, 0xf6, 0xa0, 0x79,
+                ],
+            },
+        ],
+        lms_public_key: &[
+            0xc7, 0x97, 0x00, 0xcd, 0xe1, 0x00, 0x13, 0x8b, 0x5d, 0x3e, 0x1b, 0x64, 0x90, 0x95,
+            0x50, 0xab, 0x3e, 0x8a, 0x3e, 0x9b, 0xc3, 0x7f, 0x39, 0x3f, 0x1f, 0x0f, 0x7f, 0x1e,
+            0x31, 0xa1, 0x95, 0xdb,
+        ],
+    },
+    KnownAnswers {
+        hash_family: HashFamily::Sha256_192,
+        message_hash: &[
+            0xea, 0xa8, 0x1a, 0xdf, 0x18, 0x5a, 0xe2, 0xca, 0x63, 0x72, 0x41, 0x73, 0xa8, 0xed,
+            0x5a, 0x40, 0xbb, 0x27, 0xcf, 0x91, 0x5b, 0x05, 0x53, 0x7d,
+        ],
+        lmots: [
+            LmotsKnownAnswer {
+                lmots_algorithm: LmotsAlgorithm::LmotsW1,
+                public_key: &[
+                    0xe7, 0x79, 0xe1, 0x22, 0xbb, 0xec, 0x79, 0x6a, 0x5f, 0xd7, 0x25, 0x4f, 0xdf,
+                    0x93, 0x04, 0x94, 0x17, 0xda, 0x43, 0xd8, 0x05, 0x9e, 0x76, 0x50,
+                ],
+                signature_hash: &[
+                    0xc0, 0x6c, 0x95, 0xda, 0x14, 0xd2, 0x3f, 0x69, 0xeb, 0x91, 0x7b, 0xc5, 0xd0,
+                    0x7d, 0x49, 0x54, 0xe6, 0x54, 0x73, 0x0d, 0x10, 0x8a, 0x77, 0xce,
+                ],
+            },
+            LmotsKnownAnswer {
+                lmots_algorithm: LmotsAlgorithm::LmotsW2,
+                public_key: &[
+                    0x19, 0xee, 0x98, 0xf3, 0xaa, 0x70, 0x9d, 0x49, 0x8f, 0xd7, 0xea, 0x5b, 0x17,
+                    0x5b, 0x63, 0x7b, 0x7d, 0x06, 0xb1, 0x7c, 0x3f, 0xaf, 0xb4, 0x57,
+                ],
+                signature_hash: &[
+                    0x4f, 0xcc, 0x98, 0x9c, 0xd7, 0xc2, 0xb8, 0x0d, 0x36, 0x63, 0x80, 0xe7, 0xf0,
+                    0xea, 0xf5, 0x35, 0x3a, 0xf3, 0x33, 0xdf, 0xa7, 0xf6, 0xd7, 0x1b,
+                ],
+            },
+            LmotsKnownAnswer {
+                lmots_algorithm: LmotsAlgorithm::LmotsW4,
+                public_key: &[
+                    0x6c, 0x92, 0x40, 0x8a, 0x63, 0x69, 0x28, 0xe8, 0x30, 0xaf, 0x0d, 0x19, 0xa0,
+                    0x41, 0xc2, 0x0d, 0x66, 0x80, 0x03, 0x56, 0x7b, 0x38, 0x88, 0xcc,
+                ],
+                signature_hash: &[
+                    0x5e, 0x76, 0x93, 0x9a, 0x77, 0x04, 0x58, 0x49, 0x60, 0x31, 0x8b, 0x60, 0xfc,
+                    0x6c, 0x8f, 0x93, 0x63, 0xc8, 0x60, 0x2c, 0x6d, 0xe2, 0x19, 0xd4,
+                ],
+            },
+            LmotsKnownAnswer {
+                lmots_algorithm: LmotsAlgorithm::LmotsW8,
+                public_key: &[
+                    0xee, 0x74, 0x0f, 0x15, 0x59, 0x2c, 0xf7, 0x3b, 0x73, 0xff, 0x91, 0xd9, 0x27,
+                    0x8c, 0x87, 0xac, 0xfc, 0xfb, 0xc8, 0x2d, 0x27, 0x79, 0x4d, 0x6b,
+                ],
+                signature_hash: &[
+                    0x02, 0x3f, 0x81, 0x21, 0x8e, 0xd8, 0x66, 0x58, 0xf5, 0xc7, 0x3f, 0x76, 0xf6,
+                    0xe8, 0x66, 0xbb, 0x6d, 0x17, 0xd1, 0x1b, 0xac, 0xde, 0x6d, 0xf3,
+                ],
+            },
+        ],
+        lms_public_key: &[
+            0x20, 0x88, 0x7c, 0xc8, 0x84, 0xad, 0x3b, 0xff, 0xb8, 0x68, 0xc6, 0xd8, 0x97, 0x6d,
+            0x31, 0xcc, 0x9a, 0x25, 0xc9, 0xf6, 0x3b, 0xb6, 0xb4, 0xbf,
+        ],
+    },
+    KnownAnswers {
+        hash_family: HashFamily::Shake256_256,
+        message_hash: &[
+            0x98, 0x5a, 0xa7, 0x86, 0x52, 0xe5, 0xf5, 0xb4, 0x04, 0xde, 0x43, 0xf8, 0x6b, 0x7a,
+            0xdc, 0xc1, 0x1e, 0x7c, 0x66, 0xb8, 0xa0, 0xb3, 0x75, 0xfa, 0xf4, 0x35, 0xae, 0xeb,
+            0xd9, 0x35, 0xcd, 0xf1,
+        ],
+        lmots: [
+            LmotsKnownAnswer {
+                lmots_algorithm: LmotsAlgorithm::LmotsW1,
+                public_key: &[
+                    0x61, 0xab, 0xc3, 0xfa, 0xe8, 0x81, 0x7e, 0xfb, 0x3d, 0xef, 0x93, 0xed, 0xdf,
+                    0x6e, 0xd8, 0x3c, 0x9b, 0x1f, 0x7f, 0x4a, 0xc4, 0xe5, 0xcb, 0x90, 0x38, 0x41,
+                    0xe9, 0x8f, 0xef, 0x96, 0x80, 0x1f,
+                ],
+                signature_hash: &[
+                    0x01, 0x7f, 0x80, 0x00, 0xf8, 0x8e, 0xdc, 0x19, 0xaf, 0x38, 0x63, 0xe0, 0x4c,
+                    0x7b, 0xfb, 0xab, 0x9c, 0x6a, 0xe4, 0xac, 0x81, 0x01, 0x65, 0x74, 0x89, 0x32,
+                    0xf3, 0x8a, 0x2b, 0x65, 0xd5, 0xd8,
+                ],
+            },
+            LmotsKnownAnswer {
+                lmots_algorithm: LmotsAlgorithm::LmotsW2,
+                public_key: &[
+                    0x33, 0xcd, 0xd0, 0xbb, 0x12, 0x42, 0x8c, 0x82, 0x9d, 0x06, 0x9b, 0xfb, 0x56,
+                    0x22, 0xcd, 0xad, 0xec, 0x4c, 0x1d, 0x2e, 0xc9, 0x55, 0x62, 0xbb, 0x65, 0xc9,
+                    0x56, 0x14, 0xd4, 0xe8, 0x3f, 0xdb,
+                ],
+                signature_hash: &[
+                    0x83, 0x05, 0x7d, 0x4a, 0x12, 0x3b, 0x9f, 0x2f, 0x60, 0x51, 0xa3, 0x0a, 0xdb,
+                    0x61, 0xfd, 0x95, 0xd3, 0x44, 0x68, 0xb5, 0x98, 0xf3, 0xfa, 0xe2, 0xc1, 0xd4,
+                    0xbe, 0x4a, 0x8d, 0x16, 0xe4, 0x14,
+                ],
+            },
+            LmotsKnownAnswer {
+                lmots_algorithm: LmotsAlgorithm::LmotsW4,
+                public_key: &[
+                    0x2c, 0x9e, 0x08, 0x2b, 0xa6, 0x74, 0xa4, 0xd2, 0x00, 0xc8, 0x18, 0x36, 0x0e,
+                    0xb2, 0x2e, 0xc0, 0x67, 0x6b, 0xaf, 0x53, 0x62, 0x52, 0x9e, 0xbf, 0x98, 0x10,
+                    0x44, 0x78, 0x62, 0xe9, 0x6b, 0x7a,
+                ],
+                signature_hash: &[
+                    0x0d, 0xc2, 0x2f, 0x73, 0xa2, 0xfc, 0xe0, 0x81, 0xbb, 0x37, 0x91, 0x82, 0x71,
+                    0x0a, 0xd6, 0xc3, 0x41, 0xf5, 0x4f, 0xf5, 0x8b, 0x6b, 0x2c, 0xf3, 0x9e, 0x8e,
+                    0x7a, 0x43, 0xbf, 0x6e, 0xf4, 0xa5,
+                ],
+            },
+            LmotsKnownAnswer {
+                lmots_algorithm: LmotsAlgorithm::LmotsW8,
+                public_key: &[
+                    0x41, 0xd8, 0x68, 0x6e, 0x54, 0xa2, 0x4c, 0x4c, 0x67, 0xb1, 0xf7, 0xa0, 0x56,
+                    0x7d, 0x67, 0xd0, 0xbf, 0xe7, 0xf9, 0x29, 0x68, 0xda, 0x43, 0x0f, 0xd7, 0xfe,
+                    0x87, 0x4d, 0x4e, 0x12, 0xd5, 0x24,
+                ],
+                signature_hash: &[
+                    0xb0, 0x59, 0xea, 0x46, 0x7d, 0x6e, 0xb7, 0xda, 0x1d, 0xa7, 0x15, 0x14, 0xe2,
+                    0xcb, 0x9b, 0x08, 0x79, 0x06, 0x79, 0xa3, 0xc1, 0x7e, 0x25, 0x7c, 0xd1, 0x80,
+                    0xe0, 0xce, 0x9d, 0x50, 0x92, 0xcf,
+                ],
+            },
+        ],
+        lms_public_key: &[
+            0x14, 0x75, 0x38, 0xea, 0xd3, 0x99, 0x66, 0xc6, 0x04, 0x8d, 0xb8, 0xfc, 0x61, 0x91,
+            0x7e, 0x5c, 0x34, 0x7d, 0xc2, 0x5f, 0x97, 0xf0, 0x1f, 0x33, 0x2b, 0x0c, 0xaa, 0xe3,
+            0xfd, 0x26, 0x96, 0x03,
+        ],
+    },
+    KnownAnswers {
+        hash_family: HashFamily::Shake256_192,
+        message_hash: &[
+            0x98, 0x5a, 0xa7, 0x86, 0x52, 0xe5, 0xf5, 0xb4, 0x04, 0xde, 0x43, 0xf8, 0x6b, 0x7a,
+            0xdc, 0xc1, 0x1e, 0x7c, 0x66, 0xb8, 0xa0, 0xb3, 0x75, 0xfa,
+        ],
+        lmots: [
+            LmotsKnownAnswer {
+                lmots_algorithm: LmotsAlgorithm::LmotsW1,
+                public_key: &[
+                    0xd6, 0xe4, 0x72, 0xa2, 0xd1, 0x58, 0x4a, 0xe5, 0x93, 0x3b, 0x97, 0xca, 0xc0,
+                    0x7c, 0xdb, 0x1a, 0xed, 0xa6, 0xf1, 0x2c, 0x1a, 0xb4, 0xad, 0xb0,
+                ],
+                signature_hash: &[
+                    0x8b, 0x01, 0x35, 0xf8, 0x55, 0xb9, 0xf8, 0x40, 0x7a, 0xfb, 0x2d, 0x05, 0xbc,
+                    0x41, 0xb1, 0x6f, 0x81, 0xb4, 0xa5, 0xde, 0x66, 0xd8, 0xbe, 0x9c,
+                ],
+            },
+            LmotsKnownAnswer {
+                lmots_algorithm: LmotsAlgorithm::LmotsW2,
+                public_key: &[
+                    0x2d, 0xa3, 0x40, 0xdb, 0xa8, 0xcd, 0x63, 0x9d, 0x08, 0x88, 0x5f, 0x1b, 0x3e,
+                    0xab, 0x58, 0xad, 0x66, 0x28, 0x74, 0xd9, 0xb3, 0xde, 0x1e, 0xd8,
+                ],
+                signature_hash: &[
+                    0xed, 0x8c, 0x76, 0xa3, 0x93, 0x6b, 0x9e, 0x53, 0xe2, 0xfd, 0xdd, 0x26, 0xbd,
+                    0xd0, 0xb1, 0xaa, 0x40, 0x9d, 0x72, 0xaf, 0xc6, 0xc4, 0xa1, 0x40,
+                ],
+            },
+            LmotsKnownAnswer {
+                lmots_algorithm: LmotsAlgorithm::LmotsW4,
+                public_key: &[
+                    0xce, 0xf5, 0x65, 0x44, 0x85, 0x1c, 0x47, 0xba, 0x3c, 0xf1, 0x73, 0xcb, 0xa2,
+                    0xa1, 0x23, 0x74, 0x00, 0x03, 0x2a, 0x27, 0xcc, 0xd4, 0xa1, 0x48,
+                ],
+                signature_hash: &[
+                    0x8e, 0x86, 0x8f, 0x55, 0xde, 0x41, 0xd5, 0x99, 0x11, 0x51, 0x04, 0xf4, 0x87,
+                    0xb0, 0x67, 0xea, 0xf9, 0x36, 0xac, 0x8f, 0x1c, 0xb9, 0xc2, 0xdf,
+                ],
+            },
+            LmotsKnownAnswer {
+                lmots_algorithm: LmotsAlgorithm::LmotsW8,
+                public_key: &[
+                    0xf5, 0xa7, 0x9e, 0xe6, 0xbe, 0x81, 0x36, 0xf1, 0xbe, 0xe7, 0xcc, 0x68, 0x02,
+                    0xff, 0x86, 0xb2, 0x2f, 0x3d, 0xad, 0x52, 0x30, 0xeb, 0x8f, 0xd3,
+                ],
+                signature_hash: &[
+                    0xdc, 0x4d, 0x7a, 0x13, 0x11, 0x1d, 0xb8, 0xad, 0x9f, 0xb3, 0x1b, 0xd4, 0xab,
+                    0xf0, 0x98, 0x71, 0x6a, 0x8f, 0x6d, 0x18, 0x58, 0x5d, 0x8a, 0x16,
+                ],
+            },
+        ],
+        lms_public_key: &[
+            0xe0, 0x26, 0x76, 0x87, 0x5c, 0x93, 0xa7, 0x37, 0x8a, 0x11, 0x6c, 0x24, 0x75, 0x75,
+            0x52, 0x94, 0xdf, 0x82, 0x05, 0xa9, 0x9c, 0x93, 0xd1, 0xe3,
+        ],
+    },
+];
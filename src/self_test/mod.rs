@@ -0,0 +1,157 @@
+use crate::{
+    hasher::{HashChain, HashFamily},
+    hss::hss_verify,
+    signature::Error,
+};
+#[cfg(feature = "sign")]
+use crate::{hss::parameter::HssParameter, lm_ots, lms, LmotsAlgorithm, LmsAlgorithm, Seed};
+
+#[cfg(feature = "sign")]
+use self::known_answers::{
+    KnownAnswers, LMS_LEAF_IDENTIFIER, LMS_TREE_IDENTIFIER, SEED, SIGNATURE_RANDOMIZER,
+};
+use self::known_answers::{KNOWN_ANSWERS, MESSAGE};
+
+mod known_answers;
+mod rfc8554_testcase1;
+mod rfc8554_testcase2;
+
+/**
+ * Runs the known-answer tests of all hash functions enabled by the features `sha256` and
+ * `shake256`, see [`self_test_hash_chain`]. Intended to be called once at power-on, before any
+ * key is used.
+ */
+pub fn self_test() -> Result<(), Error> {
+    #[cfg(feature = "sha256")]
+    {
+        self_test_hash_chain::<crate::Sha256_256>()?;
+        self_test_hash_chain::<crate::Sha256_192>()?;
+    }
+
+    #[cfg(feature = "shake256")]
+    {
+        self_test_hash_chain::<crate::Shake256_256>()?;
+        self_test_hash_chain::<crate::Shake256_192>()?;
+    }
+
+    Ok(())
+}
+
+/**
+ * Runs the known-answer tests of a single hash function, e.g. of a hardware accelerated
+ * [`HashChain`].
+ *
+ * The hash function itself and, with the `sign` feature, the LM-OTS key generation, signing and
+ * verification of every Winternitz parameter and the LMS key generation are tested. For SHA-256
+ * with an output of 256 bits, the two HSS signatures of RFC 8554 are verified additionally.
+ * The LMS tree heights only differ in the number of levels, so a tree of height 5 is tested.
+ *
+ * Returns an error, if a test fails or `H` does not belong to a [`HashFamily`].
+ *
+ * # Arguments
+ * * `HashChain` - The hasher implementation that should be tested.
+ */
+pub fn self_test_hash_chain<H: HashChain>() -> Result<(), Error> {
+    let hash_family = HashFamily::of::<H>().ok_or_else(Error::new)?;
+    let known_answers = KNOWN_ANSWERS
+        .iter()
+        .find(|known_answers| known_answers.hash_family == hash_family)
+        .ok_or_else(Error::new)?;
+
+    if H::default().chain(MESSAGE).finalize().as_slice() != known_answers.message_hash {
+        return Err(Error::new());
+    }
+
+    if hash_family == HashFamily::Sha256_256 {
+        hss_verify::<H>(
+            rfc8554_testcase1::MESSAGE,
+            rfc8554_testcase1::SIGNATURE,
+            rfc8554_testcase1::PUBLIC_KEY,
+        )?;
+        hss_verify::<H>(
+            rfc8554_testcase2::MESSAGE,
+            rfc8554_testcase2::SIGNATURE,
+            rfc8554_testcase2::PUBLIC_KEY,
+        )?;
+    }
+
+    #[cfg(feature = "sign")]
+    {
+        test_lm_ots::<H>(known_answers)?;
+        test_lms::<H>(known_answers)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "sign")]
+fn test_lm_ots<H: HashChain>(known_answers: &KnownAnswers) -> Result<(), Error> {
+    let n = H::OUTPUT_SIZE as usize;
+
+    for known_answer in known_answers.lmots.iter() {
+        let (private_key, public_key) = lm_ots::generate_key_pair::<H>(
+            known_answer.lmots_algorithm,
+            &Seed::from(SEED),
+            &LMS_TREE_IDENTIFIER,
+            LMS_LEAF_IDENTIFIER,
+        )?;
+        if public_key.key.as_slice() != known_answer.public_key {
+            return Err(Error::new());
+        }
+
+        let signature = lm_ots::sign(private_key, MESSAGE, &SIGNATURE_RANDOMIZER[..n])?
+            .to_binary_representation();
+        if H::default()
+            .chain(signature.as_slice())
+            .finalize()
+            .as_slice()
+            != known_answer.signature_hash
+        {
+            return Err(Error::new());
+        }
+
+        lm_ots::verify(MESSAGE, signature.as_slice(), &public_key)?;
+        if lm_ots::verify(&MESSAGE[1..], signature.as_slice(), &public_key).is_ok() {
+            return Err(Error::new());
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "sign")]
+fn test_lms<H: HashChain>(known_answers: &KnownAnswers) -> Result<(), Error> {
+    let (_, public_key) = lms::keygen(
+        &HssParameter::<H>::new(LmotsAlgorithm::LmotsW1, LmsAlgorithm::LmsH5),
+        &Seed::from(SEED),
+        &LMS_TREE_IDENTIFIER,
+    );
+
+    if public_key.key.as_slice() != known_answers.lms_public_key {
+        return Err(Error::new());
+    }
+
+    Ok(())
+}
+
+#[cfg(all(test, feature = "sign", feature = "sha256"))]
+mod tests {
+    use super::{self_test, self_test_hash_chain, test_lm_ots, test_lms, KNOWN_ANSWERS};
+
+    use crate::{HashFamily, Sha256_128, Sha256_192};
+
+    #[test]
+    fn known_answers() {
+        assert!(self_test().is_ok());
+        assert!(self_test_hash_chain::<Sha256_128>().is_err());
+    }
+
+    #[test]
+    fn reject_wrong_answers() {
+        // The answers of SHA-256/256 don't match SHA-256/192, which only differs in the output size.
+        assert_eq!(KNOWN_ANSWERS[1].hash_family, HashFamily::Sha256_192);
+        assert!(test_lm_ots::<Sha256_192>(&KNOWN_ANSWERS[0]).is_err());
+        assert!(test_lms::<Sha256_192>(&KNOWN_ANSWERS[0]).is_err());
+        assert!(test_lms::<Sha256_192>(&KNOWN_ANSWERS[1]).is_ok());
+    }
+}
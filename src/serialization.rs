@@ -0,0 +1,429 @@
+use core::{convert::TryFrom, fmt, marker::PhantomData};
+
+use serde::{
+    de::{self, SeqAccess, Unexpected, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use tinyvec::{Array, ArrayVec};
+
+#[cfg(all(feature = "sign", feature = "zeroize"))]
+use crate::util::helper::zeroize_array_vec;
+#[cfg(feature = "sign")]
+use crate::{
+    constants::MAX_PRIVATE_KEY_SIZE,
+    hss::{reference_impl_private_key::ReferenceImplPrivateKey, SigningKey},
+};
+use crate::{
+    constants::{MAX_ALLOWED_HSS_LEVELS, MAX_HSS_PUBLIC_KEY_LENGTH, MAX_HSS_SIGNATURE_LENGTH},
+    hasher::HashChain,
+    hss::{parameter::HssParameter, VerifyingKey},
+    LmotsAlgorithm, LmsAlgorithm, Signature,
+};
+
+/// Lowercase hex encoding, used for byte strings in human-readable formats.
+struct Hex<'a>(&'a [u8]);
+
+impl<'a> fmt::Display for Hex<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.iter().try_for_each(|byte| write!(f, "{:02x}", byte))
+    }
+}
+
+#[cfg(feature = "sign")]
+impl<'a> Hex<'a> {
+    /// Encodes into a buffer on the stack, which can be wiped afterwards unlike the temporary
+    /// `String` of `Serializer::collect_str`.
+    fn encode<A: Array<Item = u8>>(&self) -> ArrayVec<A> {
+        const DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+        let mut hex = ArrayVec::new();
+        for byte in self.0 {
+            hex.push(DIGITS[(byte >> 4) as usize]);
+            hex.push(DIGITS[(byte & 0x0f) as usize]);
+        }
+
+        hex
+    }
+}
+
+fn serialize_bytes<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        serializer.collect_str(&Hex(bytes))
+    } else {
+        serializer.serialize_bytes(bytes)
+    }
+}
+
+fn deserialize_bytes<'de, D: Deserializer<'de>, A: Array<Item = u8>>(
+    deserializer: D,
+) -> Result<ArrayVec<A>, D::Error> {
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(BytesVisitor(PhantomData))
+    } else {
+        deserializer.deserialize_bytes(BytesVisitor(PhantomData))
+    }
+}
+
+struct BytesVisitor<A: Array<Item = u8>>(PhantomData<A>);
+
+impl<'de, A: Array<Item = u8>> Visitor<'de> for BytesVisitor<A> {
+    type Value = ArrayVec<A>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "a hex string or a byte string of at most {} bytes",
+            A::CAPACITY
+        )
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        let invalid = || E::invalid_value(Unexpected::Str(value), &self);
+
+        let digits = value.as_bytes();
+        if digits.len() % 2 != 0
+            || digits.len() / 2 > A::CAPACITY
+            || !digits.iter().all(u8::is_ascii_hexdigit)
+        {
+            return Err(invalid());
+        }
+
+        let mut bytes = ArrayVec::new();
+        for pair in digits.chunks(2) {
+            let pair = core::str::from_utf8(pair).map_err(|_| invalid())?;
+            bytes.push(u8::from_str_radix(pair, 16).map_err(|_| invalid())?);
+        }
+
+        Ok(bytes)
+    }
+
+    fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Self::Value, E> {
+        ArrayVec::try_from(value).map_err(|_| E::invalid_length(value.len(), &self))
+    }
+
+    fn visit_seq<S: SeqAccess<'de>>(self, mut seq: S) -> Result<Self::Value, S::Error> {
+        let mut bytes = ArrayVec::new();
+        while let Some(byte) = seq.next_element()? {
+            if bytes.try_push(byte).is_some() {
+                return Err(de::Error::invalid_length(A::CAPACITY + 1, &self));
+            }
+        }
+
+        Ok(bytes)
+    }
+}
+
+/**
+ * Hex string in human-readable formats, byte string otherwise.
+ */
+impl<H: HashChain> Serialize for VerifyingKey<H> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_bytes(self.as_slice(), serializer)
+    }
+}
+
+/**
+ * Only accepts public keys with valid parameters and the length they imply.
+ */
+impl<'de, H: HashChain> Deserialize<'de> for VerifyingKey<H> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes: ArrayVec<[u8; MAX_HSS_PUBLIC_KEY_LENGTH]> = deserialize_bytes(deserializer)?;
+
+        VerifyingKey::from_checked_bytes(&bytes)
+            .map_err(|_| de::Error::invalid_value(Unexpected::Bytes(&bytes), &"an HSS public key"))
+    }
+}
+
+/**
+ * Hex string in human-readable formats, byte string otherwise.
+ */
+impl Serialize for Signature {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_bytes(self.as_ref(), serializer)
+    }
+}
+
+/**
+ * Only the number of signed public keys is checked, as the remaining layout depends on the
+ * hash function.
+ */
+impl<'de> Deserialize<'de> for Signature {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes: ArrayVec<[u8; MAX_HSS_SIGNATURE_LENGTH]> = deserialize_bytes(deserializer)?;
+
+        let signed_public_keys = bytes
+            .get(..4)
+            .map(|nspk| u32::from_be_bytes([nspk[0], nspk[1], nspk[2], nspk[3]]));
+
+        match signed_public_keys {
+            Some(nspk) if (nspk as usize) < MAX_ALLOWED_HSS_LEVELS => {
+                Signature::from_bytes_verbose(&bytes, 0)
+                    .map_err(|_| de::Error::custom("invalid HSS signature"))
+            }
+            _ => Err(de::Error::invalid_value(
+                Unexpected::Bytes(&bytes),
+                &"an HSS signature",
+            )),
+        }
+    }
+}
+
+/**
+ * Hex string in human-readable formats, byte string otherwise.
+ */
+#[cfg(feature = "sign")]
+impl<H: HashChain> Serialize for SigningKey<H> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if !serializer.is_human_readable() {
+            return serializer.serialize_bytes(self.as_slice());
+        }
+
+        #[allow(unused_mut)]
+        let mut hex: ArrayVec<[u8; 2 * MAX_PRIVATE_KEY_SIZE]> = Hex(self.as_slice()).encode();
+        let result = serializer.serialize_str(core::str::from_utf8(&hex).unwrap());
+
+        #[cfg(feature = "zeroize")]
+        zeroize_array_vec(&mut hex);
+
+        result
+    }
+}
+
+/**
+ * Only accepts private keys, whose length matches the hash function and whose parameters are
+ * valid.
+ */
+#[cfg(feature = "sign")]
+impl<'de, H: HashChain> Deserialize<'de> for SigningKey<H> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[allow(unused_mut)]
+        let mut bytes: ArrayVec<[u8; MAX_PRIVATE_KEY_SIZE]> = deserialize_bytes(deserializer)?;
+
+        let signing_key = ReferenceImplPrivateKey::<H>::from_binary_representation(&bytes)
+            .and_then(|private_key| private_key.compressed_parameter.to::<H>())
+            .ok()
+            .and_then(|_| SigningKey::from_bytes(&bytes).ok());
+
+        #[cfg(feature = "zeroize")]
+        zeroize_array_vec(&mut bytes);
+
+        signing_key.ok_or_else(|| de::Error::custom("invalid HSS private key"))
+    }
+}
+
+/**
+ * The type code of RFC 8554, e.g. 3 for [`LmotsAlgorithm::LmotsW4`].
+ */
+impl Serialize for LmotsAlgorithm {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u32(*self as u32)
+    }
+}
+
+impl<'de> Deserialize<'de> for LmotsAlgorithm {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let type_code = u32::deserialize(deserializer)?;
+
+        match LmotsAlgorithm::from(type_code) {
+            LmotsAlgorithm::LmotsReserved => Err(de::Error::invalid_value(
+                Unexpected::Unsigned(type_code.into()),
+                &"an LM-OTS type code",
+            )),
+            lmots_algorithm => Ok(lmots_algorithm),
+        }
+    }
+}
+
+/**
+ * The type code of RFC 8554, e.g. 6 for [`LmsAlgorithm::LmsH10`].
+ */
+impl Serialize for LmsAlgorithm {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u32(*self as u32)
+    }
+}
+
+impl<'de> Deserialize<'de> for LmsAlgorithm {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let type_code = u32::deserialize(deserializer)?;
+
+        match LmsAlgorithm::from(type_code) {
+            LmsAlgorithm::LmsReserved => Err(de::Error::invalid_value(
+                Unexpected::Unsigned(type_code.into()),
+                &"an LMS type code",
+            )),
+            lms_algorithm => Ok(lms_algorithm),
+        }
+    }
+}
+
+/**
+ * The tuple of [`LmotsAlgorithm`] and [`LmsAlgorithm`].
+ */
+impl<H: HashChain> Serialize for HssParameter<H> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.get_algorithms().serialize(serializer)
+    }
+}
+
+impl<'de, H: HashChain> Deserialize<'de> for HssParameter<H> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (lmots_algorithm, lms_algorithm) = Deserialize::deserialize(deserializer)?;
+
+        Ok(HssParameter::new(lmots_algorithm, lms_algorithm))
+    }
+}
+
+#[cfg(all(test, feature = "sign", feature = "sha256"))]
+mod tests {
+    use super::Hex;
+
+    use alloc::vec::Vec;
+    use core::fmt::Write;
+
+    use serde::de::{value::Error, value::StrDeserializer, Deserialize, IntoDeserializer};
+    use tinyvec::ArrayVec;
+
+    use crate::{
+        hasher::sha256::{Sha256_192, Sha256_256},
+        hss::{hss_keygen, SigningKey, VerifyingKey},
+        signature::{SignerMut, Verifier},
+        util::helper::test_helper::gen_random_seed,
+        HssParameter, LmotsAlgorithm, LmsAlgorithm, Signature,
+    };
+
+    type H = Sha256_256;
+
+    struct Buffer(ArrayVec<[u8; 256]>);
+
+    impl Write for Buffer {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            self.0.extend_from_slice(s.as_bytes());
+            Ok(())
+        }
+    }
+
+    struct ArrayVecBytes(ArrayVec<[u8; 8]>);
+
+    impl<'de> Deserialize<'de> for ArrayVecBytes {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            super::deserialize_bytes(deserializer).map(ArrayVecBytes)
+        }
+    }
+
+    struct ByteString<'a>(&'a [u8]);
+
+    impl<'a> serde::Serialize for ByteString<'a> {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_bytes(self.0)
+        }
+    }
+
+    fn to_cbor<T: serde::Serialize>(value: &T) -> Vec<u8> {
+        let mut encoded = Vec::new();
+        ciborium::ser::into_writer(value, &mut encoded).unwrap();
+        encoded
+    }
+
+    fn from_cbor<T: serde::de::DeserializeOwned>(encoded: &[u8]) -> Option<T> {
+        // The default scratch buffer is too small for HSS signatures
+        ciborium::de::from_reader_with_buffer(encoded, &mut [0u8; 16384]).ok()
+    }
+
+    fn from_hex<'de, T: Deserialize<'de>>(hex: &'de str) -> Option<T> {
+        let deserializer: StrDeserializer<Error> = hex.into_deserializer();
+        T::deserialize(deserializer).ok()
+    }
+
+    fn keygen() -> (SigningKey<H>, VerifyingKey<H>) {
+        let seed = gen_random_seed::<H>();
+        hss_keygen::<H>(
+            &[
+                HssParameter::new(LmotsAlgorithm::LmotsW4, LmsAlgorithm::LmsH5),
+                HssParameter::new(LmotsAlgorithm::LmotsW4, LmsAlgorithm::LmsH5),
+            ],
+            &seed,
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn hex_encoding() {
+        let mut buffer = Buffer(ArrayVec::new());
+        write!(buffer, "{}", Hex(&[0x00, 0x0f, 0xa5, 0xff])).unwrap();
+        assert_eq!(buffer.0.as_slice(), b"000fa5ff");
+        let encoded: ArrayVec<[u8; 8]> = Hex(&[0x00, 0x0f, 0xa5, 0xff]).encode();
+        assert_eq!(encoded.as_slice(), b"000fa5ff");
+
+        let decoded = from_hex::<ArrayVecBytes>("000fA5ff").unwrap();
+        assert_eq!(decoded.0.as_slice(), [0x00, 0x0f, 0xa5, 0xff]);
+        assert!(from_hex::<ArrayVecBytes>("000").is_none());
+        assert!(from_hex::<ArrayVecBytes>("0g").is_none());
+        assert!(from_hex::<ArrayVecBytes>("+f").is_none());
+        assert!(from_hex::<ArrayVecBytes>("00112233445566778899").is_none());
+    }
+
+    #[test]
+    fn keys_and_signature_round_trip() {
+        let (mut signing_key, verifying_key) = keygen();
+        let message = b"configuration";
+        let signature = signing_key.try_sign(message).unwrap();
+
+        let decoded: VerifyingKey<H> = from_cbor(&to_cbor(&verifying_key)).unwrap();
+        assert_eq!(decoded, verifying_key);
+
+        let decoded: Signature = from_cbor(&to_cbor(&signature)).unwrap();
+        assert_eq!(decoded.as_ref(), signature.as_ref());
+        assert!(verifying_key.verify(message, &decoded).is_ok());
+
+        let decoded: SigningKey<H> = from_cbor(&to_cbor(&signing_key)).unwrap();
+        assert_eq!(decoded, signing_key);
+
+        let mut hex = Buffer(ArrayVec::new());
+        write!(hex, "{}", Hex(verifying_key.as_slice())).unwrap();
+        let hex = core::str::from_utf8(hex.0.as_slice()).unwrap();
+        assert_eq!(from_hex::<VerifyingKey<H>>(hex), Some(verifying_key));
+    }
+
+    #[test]
+    fn reject_invalid_keys_and_signatures() {
+        let (signing_key, verifying_key) = keygen();
+
+        let public_key = verifying_key.as_slice();
+        assert!(from_cbor::<VerifyingKey<H>>(&to_cbor(&ByteString(public_key))).is_some());
+        assert!(from_cbor::<VerifyingKey<Sha256_192>>(&to_cbor(&ByteString(public_key))).is_none());
+        assert!(from_cbor::<VerifyingKey<H>>(&to_cbor(&ByteString(&public_key[1..]))).is_none());
+
+        let private_key = signing_key.as_slice();
+        assert!(from_cbor::<SigningKey<H>>(&to_cbor(&ByteString(private_key))).is_some());
+        assert!(from_cbor::<SigningKey<Sha256_192>>(&to_cbor(&ByteString(private_key))).is_none());
+
+        let mut invalid_parameters = private_key.to_vec();
+        invalid_parameters[8] = 0x00;
+        assert!(from_cbor::<SigningKey<H>>(&to_cbor(&ByteString(&invalid_parameters))).is_none());
+
+        assert!(from_cbor::<Signature>(&to_cbor(&ByteString(&[0, 0, 0, 8]))).is_none());
+        assert!(from_cbor::<Signature>(&to_cbor(&ByteString(&[0, 0, 1]))).is_none());
+    }
+
+    #[test]
+    fn parameters_round_trip() {
+        let parameters = [
+            HssParameter::<H>::new(LmotsAlgorithm::LmotsW8, LmsAlgorithm::LmsH10),
+            HssParameter::<H>::new(LmotsAlgorithm::LmotsW2, LmsAlgorithm::LmsH5),
+        ];
+
+        let encoded = to_cbor(&parameters);
+        // [[4, 6], [2, 5]]
+        assert_eq!(encoded, [0x82, 0x82, 0x04, 0x06, 0x82, 0x02, 0x05]);
+
+        let decoded: [HssParameter<H>; 2] = from_cbor(&encoded).unwrap();
+        assert!(decoded == parameters);
+
+        assert!(from_cbor::<LmotsAlgorithm>(&[0x00]).is_none());
+        assert!(from_cbor::<LmotsAlgorithm>(&[0x05]).is_none());
+        assert!(from_cbor::<LmsAlgorithm>(&[0x04]).is_none());
+        assert!(from_cbor::<HssParameter<H>>(&[0x82, 0x06, 0x04]).is_none());
+    }
+}
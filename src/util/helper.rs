@@ -1,3 +1,8 @@
+#[cfg(all(feature = "sign", feature = "zeroize"))]
+use tinyvec::{Array, ArrayVec};
+#[cfg(all(feature = "sign", feature = "zeroize"))]
+use zeroize::Zeroize;
+
 pub fn is_odd(x: usize) -> bool {
     x % 2 == 1
 }
@@ -12,7 +17,41 @@ pub fn read_and_advance<'a>(src: &'a [u8], length: usize, index: &mut usize) ->
     result
 }
 
-#[cfg(test)]
+/// Wipes the whole backing array, including the spare capacity beyond `len`. The vector is left
+/// filled with zeros up to its capacity.
+#[cfg(all(feature = "sign", feature = "zeroize"))]
+pub fn zeroize_array_vec<A: Array<Item = u8>>(data: &mut ArrayVec<A>) {
+    data.set_len(data.capacity());
+    data.as_mut_slice().zeroize();
+}
+
+/// Splits an IANA algorithm name like `LMS_SHA256_M32_H10` into the name of the hash function, the
+/// output size and the remaining parameter (`H10`).
+pub fn split_algorithm_name<'a>(
+    name: &'a str,
+    prefix: &str,
+    size_prefix: char,
+) -> Option<(&'a str, u16, &'a str)> {
+    let name = name.strip_prefix(prefix)?.strip_prefix('_')?;
+
+    let mut parts = name.splitn(3, '_');
+    let hash_name = parts.next()?;
+    let output_size = parts.next()?.strip_prefix(size_prefix)?.parse().ok()?;
+    let parameter = parts.next()?;
+
+    if parameter.contains('_') {
+        return None;
+    }
+
+    Some((hash_name, output_size, parameter))
+}
+
+/// Whether the hash function and output size are registered by RFC 8554 or SP 800-208.
+pub fn is_registered_hash(hash_name: &str, output_size: u16) -> bool {
+    matches!(hash_name, "SHA256" | "SHAKE") && matches!(output_size, 24 | 32)
+}
+
+#[cfg(all(test, feature = "sign", feature = "sha256"))]
 pub mod test_helper {
     use crate::{HashChain, Seed};
     use rand::{rngs::OsRng, RngCore};
@@ -23,3 +62,19 @@ pub mod test_helper {
         seed
     }
 }
+
+#[cfg(all(test, feature = "sign", feature = "zeroize"))]
+mod tests {
+    use super::zeroize_array_vec;
+
+    use tinyvec::ArrayVec;
+
+    #[test]
+    fn zeroize_spare_capacity() {
+        let mut data = ArrayVec::from([0xa5u8; 8]);
+        data.truncate(4);
+        zeroize_array_vec(&mut data);
+
+        assert_eq!(data.as_slice(), &[0u8; 8]);
+    }
+}
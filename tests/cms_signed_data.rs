@@ -0,0 +1,140 @@
+use hbs_lms::{
+    cms::{content_info::ContentInfo, signed_data::SignedData},
+    spki::{
+        der::{asn1::OctetString, Any, Decode, Encode},
+        DecodePublicKey,
+    },
+    Sha256_256, VerifyingKey,
+};
+use sha2::{Sha256, Sha512};
+use std::convert::TryInto;
+
+// The fixtures were generated with a key derived from TEST_SEED. The attached SignedData uses the
+// first and the detached SignedData the second leaf.
+
+static CONTENT: &[u8] = include_bytes!("fixtures/cms/content.txt");
+static PUBLIC_KEY: &[u8] = include_bytes!("fixtures/cms/public_key.der");
+static SIGNED_DATA: &[u8] = include_bytes!("fixtures/cms/signed_data.der");
+static SIGNED_DATA_DETACHED: &[u8] = include_bytes!("fixtures/cms/signed_data_detached.der");
+
+type H = Sha256_256;
+
+fn verifying_key() -> VerifyingKey<H> {
+    VerifyingKey::from_public_key_der(PUBLIC_KEY).unwrap()
+}
+
+fn verify(content_info: &ContentInfo, detached_content: Option<&[u8]>) -> bool {
+    hbs_lms::verify_signed_data::<H, Sha256>(&verifying_key(), content_info, detached_content)
+        .is_ok()
+}
+
+#[test]
+fn verify_fixtures() {
+    let content_info = ContentInfo::from_der(SIGNED_DATA).unwrap();
+    assert!(verify(&content_info, None));
+    assert!(!verify(&content_info, Some(CONTENT)));
+
+    let content_info = ContentInfo::from_der(SIGNED_DATA_DETACHED).unwrap();
+    assert!(verify(&content_info, Some(CONTENT)));
+    assert!(!verify(&content_info, None));
+    assert!(!verify(&content_info, Some(&CONTENT[1..])));
+
+    assert!(hbs_lms::verify_signed_data::<H, Sha512>(
+        &verifying_key(),
+        &content_info,
+        Some(CONTENT)
+    )
+    .is_err());
+}
+
+#[test]
+fn decode_encode_round_trip() {
+    for fixture in [SIGNED_DATA, SIGNED_DATA_DETACHED] {
+        let content_info = ContentInfo::from_der(fixture).unwrap();
+        assert_eq!(content_info.to_der().unwrap(), fixture);
+    }
+}
+
+#[test]
+fn reject_modified_signed_data() {
+    let content_info = ContentInfo::from_der(SIGNED_DATA).unwrap();
+    let signed_data: SignedData = content_info.content.decode_as().unwrap();
+
+    let reencode = |signed_data: &SignedData| ContentInfo {
+        content_type: content_info.content_type,
+        content: Any::encode_from(signed_data).unwrap(),
+    };
+    assert!(verify(&reencode(&signed_data), None));
+
+    let mut modified = signed_data.clone();
+    modified.encap_content_info.econtent =
+        Some(Any::encode_from(&OctetString::new(&CONTENT[1..]).unwrap()).unwrap());
+    assert!(!verify(&reencode(&modified), None));
+
+    let mut modified = signed_data.clone();
+    let mut signer_info = modified.signer_infos.0.get(0).unwrap().clone();
+    let mut signature = signer_info.signature.as_bytes().to_vec();
+    signature[100] ^= 1;
+    signer_info.signature = OctetString::new(signature).unwrap();
+    modified.signer_infos.0 = [signer_info].to_vec().try_into().unwrap();
+    assert!(!verify(&reencode(&modified), None));
+
+    let mut modified = signed_data;
+    let mut signer_info = modified.signer_infos.0.get(0).unwrap().clone();
+    signer_info.signed_attrs = None;
+    modified.signer_infos.0 = [signer_info].to_vec().try_into().unwrap();
+    assert!(!verify(&reencode(&modified), None));
+}
+
+// The pairwise consistency test of the key generation uses the first leaf, so the signatures differ
+// from the fixtures.
+#[cfg(not(feature = "pairwise_consistency"))]
+mod sign {
+    use super::*;
+    use hbs_lms::{
+        cms::{cert::x509::ext::pkix::SubjectKeyIdentifier, signed_data::SignerIdentifier},
+        spki::EncodePublicKey,
+        HssParameter, LmotsAlgorithm, LmsAlgorithm, Seed, SigningKey,
+    };
+
+    const TEST_SEED: [u8; 32] = [7u8; 32];
+
+    fn keygen() -> (SigningKey<H>, VerifyingKey<H>) {
+        hbs_lms::keygen::<H>(
+            &[HssParameter::new(
+                LmotsAlgorithm::LmotsW4,
+                LmsAlgorithm::LmsH5,
+            )],
+            &Seed::from(TEST_SEED),
+            None,
+        )
+        .unwrap()
+    }
+
+    fn signer_identifier(verifying_key: &VerifyingKey<H>) -> SignerIdentifier {
+        // The LMS tree identifier of the root tree
+        let key_identifier = OctetString::new(&verifying_key.as_slice()[12..28]).unwrap();
+        SignerIdentifier::SubjectKeyIdentifier(SubjectKeyIdentifier(key_identifier))
+    }
+
+    #[test]
+    fn sign_reproduces_fixtures() {
+        let (mut signing_key, verifying_key) = keygen();
+        assert_eq!(
+            verifying_key.to_public_key_der().unwrap().as_bytes(),
+            PUBLIC_KEY
+        );
+
+        for (fixture, detached) in [(SIGNED_DATA, false), (SIGNED_DATA_DETACHED, true)] {
+            let content_info = hbs_lms::sign_signed_data::<H, Sha256>(
+                &mut signing_key,
+                signer_identifier(&verifying_key),
+                CONTENT,
+                detached,
+            )
+            .unwrap();
+
+            assert_eq!(content_info.to_der().unwrap(), fixture);
+        }
+    }
+}